@@ -0,0 +1,152 @@
+//! RPC interface for the chainbridge pallet.
+//!
+//! Lets relayers fetch the records of outbound transfers straight from chain state,
+//! for instance to resync after downtime, and report relayer statistics.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use chainbridge_runtime_api::{
+	ChainBridgeApi as ChainBridgeRuntimeApi, ChainId, DepositNonce, DepositRecord, RelayerStats,
+};
+
+#[rpc(client, server)]
+pub trait ChainBridgeApi<BlockHash, AccountId, Balance, BlockNumber> {
+	/// Record of the deposit to a chain with the given nonce
+	#[method(name = "chainBridge_deposit")]
+	fn deposit(
+		&self,
+		dest_id: ChainId,
+		nonce: DepositNonce,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<DepositRecord<BlockNumber>>>;
+
+	/// Records of the deposits to a chain, for up to `count` nonces starting at `start`
+	#[method(name = "chainBridge_depositsByNonce")]
+	fn deposits_by_nonce(
+		&self,
+		dest_id: ChainId,
+		start: DepositNonce,
+		count: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(DepositNonce, DepositRecord<BlockNumber>)>>;
+
+	/// Records of the deposits to all chains made from block `from` up to block `to` included
+	#[method(name = "chainBridge_depositsByBlock")]
+	fn deposits_by_block(
+		&self,
+		from: BlockNumber,
+		to: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(ChainId, DepositNonce, DepositRecord<BlockNumber>)>>;
+
+	/// Bond and votes of a relayer, if it is in the relayer set
+	#[method(name = "chainBridge_relayerStats")]
+	fn relayer_stats(
+		&self,
+		relayer: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RelayerStats<Balance>>>;
+}
+
+/// Provides RPC methods to query the deposit records and relayers of the bridge.
+pub struct ChainBridge<C, P> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> ChainBridge<C, P> {
+	/// Creates a new instance of the ChainBridge RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Display) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the bridge state.",
+		Some(e.to_string()),
+	))
+	.into()
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber>
+	ChainBridgeApiServer<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber>
+	for ChainBridge<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: ChainBridgeRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn deposit(
+		&self,
+		dest_id: ChainId,
+		nonce: DepositNonce,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<DepositRecord<BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.deposit(&at, dest_id, nonce).map_err(runtime_error)
+	}
+
+	fn deposits_by_nonce(
+		&self,
+		dest_id: ChainId,
+		start: DepositNonce,
+		count: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(DepositNonce, DepositRecord<BlockNumber>)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.deposits_by_nonce(&at, dest_id, start, count).map_err(runtime_error)
+	}
+
+	fn deposits_by_block(
+		&self,
+		from: BlockNumber,
+		to: BlockNumber,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(ChainId, DepositNonce, DepositRecord<BlockNumber>)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.deposits_by_block(&at, from, to).map_err(runtime_error)
+	}
+
+	fn relayer_stats(
+		&self,
+		relayer: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<RelayerStats<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.relayer_stats(&at, relayer).map_err(runtime_error)
+	}
+}
@@ -0,0 +1,39 @@
+//! Runtime API definition for the chainbridge pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+pub use chainbridge::{ChainId, DepositData, DepositNonce, DepositRecord, RelayerStats, MAX_DEPOSIT_QUERY};
+
+sp_api::decl_runtime_apis! {
+    pub trait ChainBridgeApi<AccountId, Balance, BlockNumber> where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Record of the deposit to a chain with the given nonce
+        fn deposit(dest_id: ChainId, nonce: DepositNonce) -> Option<DepositRecord<BlockNumber>>;
+
+        /// Records of the deposits to a chain, for up to `count` nonces starting at `start`
+        ///
+        /// At most `MAX_DEPOSIT_QUERY` nonces are queried
+        fn deposits_by_nonce(
+            dest_id: ChainId,
+            start: DepositNonce,
+            count: u32,
+        ) -> Vec<(DepositNonce, DepositRecord<BlockNumber>)>;
+
+        /// Records of the deposits to all chains made from block `from` up to block `to` included
+        ///
+        /// At most `MAX_DEPOSIT_QUERY` blocks are queried
+        fn deposits_by_block(
+            from: BlockNumber,
+            to: BlockNumber,
+        ) -> Vec<(ChainId, DepositNonce, DepositRecord<BlockNumber>)>;
+
+        /// Bond and votes of a relayer, if it is in the relayer set
+        fn relayer_stats(relayer: AccountId) -> Option<RelayerStats<Balance>>;
+    }
+}
@@ -0,0 +1,219 @@
+use super::*;
+
+use frame_benchmarking::{benchmarks, account, impl_benchmark_test_suite};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+const SEED: u32 = 0;
+
+const TEST_SOURCE_CHAIN: ChainId = 0xff;
+const TEST_NONCE: DepositNonce = 1;
+
+fn relayer<T: Config>(index: u32) -> T::AccountId {
+	let who = account("relayer", index, SEED);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	who
+}
+
+// Registers `r` relayers, the source chain and the resource used by proposals
+fn setup_bridge<T: Config>(r: u32, threshold: u32) -> ResourceId {
+	for i in 0 .. r {
+		Module::<T>::register_relayer(relayer::<T>(i), T::RelayerBond::get()).expect("Could not register relayer");
+	}
+	Module::<T>::set_relayer_threshold(threshold).expect("Could not set threshold");
+	Module::<T>::whitelist(TEST_SOURCE_CHAIN).expect("Could not whitelist chain");
+	let r_id = derive_resource_id(TEST_SOURCE_CHAIN, b"remark");
+	Module::<T>::register_resource(r_id, b"System.remark".to_vec()).expect("Could not register resource");
+	r_id
+}
+
+fn proposal<T: Config>() -> T::Proposal where T::Proposal: From<frame_system::Call<T>> {
+	frame_system::Call::<T>::remark { remark: vec![] }.into()
+}
+
+// Stores a proposal with votes in favour from the first `v` relayers
+fn insert_votes<T: Config>(v: u32, prop: &T::Proposal) {
+	insert_proposal::<T>(v, prop, ProposalStatus::Initiated);
+}
+
+fn insert_proposal<T: Config>(v: u32, prop: &T::Proposal, status: ProposalStatus) {
+	let expiry = <frame_system::Pallet<T>>::block_number() + T::ProposalLifetime::get();
+	<Votes<T>>::insert(TEST_SOURCE_CHAIN, (TEST_NONCE, prop.clone()), ProposalVotes {
+		votes_for: (0 .. v).map(|i| account("relayer", i, SEED)).collect(),
+		votes_against: vec![],
+		status,
+		expiry,
+	});
+}
+
+benchmarks! {
+	where_clause { where T::Proposal: From<frame_system::Call<T>> }
+
+	set_threshold {
+	}: _(RawOrigin::Root, 2)
+	verify {
+		assert_eq!(Module::<T>::relayer_threshold(), 2);
+	}
+
+	set_resource {
+		let r_id = derive_resource_id(TEST_SOURCE_CHAIN, b"remark");
+	}: _(RawOrigin::Root, r_id, b"System.remark".to_vec())
+	verify {
+		assert!(Module::<T>::resource_exists(r_id));
+	}
+
+	remove_resource {
+		let r_id = derive_resource_id(TEST_SOURCE_CHAIN, b"remark");
+		Module::<T>::register_resource(r_id, b"System.remark".to_vec()).expect("Could not register resource");
+	}: _(RawOrigin::Root, r_id)
+	verify {
+		assert!(!Module::<T>::resource_exists(r_id));
+	}
+
+	whitelist_chain {
+	}: _(RawOrigin::Root, TEST_SOURCE_CHAIN)
+	verify {
+		assert!(Module::<T>::chain_whitelisted(TEST_SOURCE_CHAIN));
+	}
+
+	add_relayer {
+		let who = relayer::<T>(0);
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert!(Module::<T>::is_relayer(&who));
+	}
+
+	remove_relayer {
+		let who = relayer::<T>(0);
+		Module::<T>::register_relayer(who.clone(), T::RelayerBond::get()).expect("Could not register relayer");
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert!(!Module::<T>::is_relayer(&who));
+	}
+
+	// Worst case is the last vote needed, which approves and executes the proposal
+	acknowledge_proposal {
+		let v in 1 .. T::MaxRelayers::get();
+		let r_id = setup_bridge::<T>(v, v);
+		let prop = proposal::<T>();
+		insert_votes::<T>(v - 1, &prop);
+		let who = relayer::<T>(v - 1);
+	}: _(RawOrigin::Signed(who), TEST_NONCE, TEST_SOURCE_CHAIN, r_id, Box::new(prop.clone()))
+	verify {
+		let votes = Module::<T>::votes(TEST_SOURCE_CHAIN, (TEST_NONCE, prop)).unwrap();
+		assert_eq!(votes.status, ProposalStatus::Approved);
+	}
+
+	// Worst case is a vote against that rejects the proposal
+	reject_proposal {
+		let v in 1 .. T::MaxRelayers::get();
+		let r_id = setup_bridge::<T>(v, v);
+		let prop = proposal::<T>();
+		insert_votes::<T>(v - 1, &prop);
+		let who = relayer::<T>(v - 1);
+	}: _(RawOrigin::Signed(who), TEST_NONCE, TEST_SOURCE_CHAIN, r_id, Box::new(prop.clone()))
+	verify {
+		let votes = Module::<T>::votes(TEST_SOURCE_CHAIN, (TEST_NONCE, prop)).unwrap();
+		assert_eq!(votes.status, ProposalStatus::Rejected);
+	}
+
+	// Worst case is a proposal with enough votes, which is approved and executed
+	eval_vote_state {
+		let v in 1 .. T::MaxRelayers::get();
+		setup_bridge::<T>(v, v);
+		let prop = proposal::<T>();
+		insert_votes::<T>(v, &prop);
+		let caller = relayer::<T>(0);
+	}: _(RawOrigin::Signed(caller), TEST_NONCE, TEST_SOURCE_CHAIN, Box::new(prop.clone()))
+	verify {
+		let votes = Module::<T>::votes(TEST_SOURCE_CHAIN, (TEST_NONCE, prop)).unwrap();
+		assert_eq!(votes.status, ProposalStatus::Approved);
+	}
+
+	set_bridge_paused {
+	}: _(RawOrigin::Root, true)
+	verify {
+		assert!(Module::<T>::paused());
+	}
+
+	set_chain_paused {
+	}: _(RawOrigin::Root, TEST_SOURCE_CHAIN, true)
+	verify {
+		assert!(Module::<T>::chain_paused(TEST_SOURCE_CHAIN));
+	}
+
+	set_rate_limit {
+		let target = LimitTarget::Chain(TEST_SOURCE_CHAIN);
+		let limit = RateLimit { max_transfer: U256::from(100), max_volume: U256::from(1000) };
+	}: _(RawOrigin::Root, Direction::Inbound, target, Some(limit.clone()))
+	verify {
+		assert_eq!(Module::<T>::rate_limits(Direction::Inbound, target), Some(limit));
+	}
+
+	// Worst case is every voter of the proposal still being a relayer
+	slash_voters {
+		let v in 1 .. T::MaxRelayers::get();
+		setup_bridge::<T>(v, v);
+		let prop = proposal::<T>();
+		insert_proposal::<T>(v, &prop, ProposalStatus::Approved);
+	}: _(RawOrigin::Root, TEST_NONCE, TEST_SOURCE_CHAIN, Box::new(prop))
+	verify {
+		assert_eq!(Module::<T>::relayer_count(), 0);
+	}
+
+	slash_inactive_relayer {
+		let who = relayer::<T>(0);
+		Module::<T>::register_relayer(who.clone(), T::RelayerBond::get()).expect("Could not register relayer");
+		<ExpiredProposals>::insert(RelayerSet::Default, T::MaxMissedVotes::get());
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert!(!Module::<T>::is_relayer(&who));
+	}
+
+	set_chain_threshold {
+		Module::<T>::whitelist(TEST_SOURCE_CHAIN).expect("Could not whitelist chain");
+	}: _(RawOrigin::Root, TEST_SOURCE_CHAIN, Some(2))
+	verify {
+		assert_eq!(Module::<T>::relayer_set(TEST_SOURCE_CHAIN), RelayerSet::Chain(TEST_SOURCE_CHAIN));
+	}
+
+	add_chain_relayer {
+		Module::<T>::whitelist(TEST_SOURCE_CHAIN).expect("Could not whitelist chain");
+		Module::<T>::set_chain_relayer_threshold(TEST_SOURCE_CHAIN, Some(1)).expect("Could not set threshold");
+		let who = relayer::<T>(0);
+	}: _(RawOrigin::Root, TEST_SOURCE_CHAIN, who.clone())
+	verify {
+		assert!(Module::<T>::is_chain_relayer(TEST_SOURCE_CHAIN, &who));
+	}
+
+	// Worst case is every proposal checked having expired unresolved, with the votes of all but one
+	// relayer of a full set
+	prune_proposals {
+		let n in 1 .. 1000;
+		let r = T::MaxRelayers::get();
+		setup_bridge::<T>(r, r);
+		let prop = proposal::<T>();
+		let expiry = <frame_system::Pallet<T>>::block_number() + T::ProposalLifetime::get();
+		for nonce in 0 .. n {
+			<Votes<T>>::insert(TEST_SOURCE_CHAIN, (nonce as DepositNonce, prop.clone()), ProposalVotes {
+				votes_for: (0 .. r - 1).map(|i| account("relayer", i, SEED)).collect(),
+				votes_against: vec![],
+				status: ProposalStatus::Initiated,
+				expiry,
+			});
+		}
+	}: {
+		Module::<T>::prune_proposals(expiry, Weight::MAX);
+	}
+	verify {
+		assert_eq!(<Votes<T>>::iter().count(), 0);
+		assert_eq!(Module::<T>::pruned_nonce(TEST_SOURCE_CHAIN), Some(n as DepositNonce - 1));
+		assert_eq!(Module::<T>::expired_proposals(RelayerSet::Default), n);
+	}
+}
+
+impl_benchmark_test_suite!(
+	Module,
+	crate::mock::new_test_ext(),
+	crate::mock::Test,
+);
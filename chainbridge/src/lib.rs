@@ -7,29 +7,46 @@ use frame_support::{
     decl_error, decl_event, decl_module, decl_storage,
     dispatch::{DispatchResult, GetDispatchInfo, Pays},
     ensure,
-    traits::{EnsureOrigin, Get},
+    traits::{Currency, EnsureOrigin, Get, OnUnbalanced, ReservableCurrency},
+    IterableStorageDoubleMap,
     weights::{Weight},
     PalletId, Parameter,
 };
 
 use frame_system::{self as system, ensure_root, ensure_signed};
 use sp_core::U256;
-use sp_runtime::traits::{AccountIdConversion, Dispatchable};
-use sp_runtime::RuntimeDebug;
+use sp_runtime::traits::{AccountIdConversion, Dispatchable, One, SaturatedConversion, Saturating, Zero};
+use sp_runtime::{DispatchError, RuntimeDebug};
 use sp_std::convert::TryInto;
 
 use codec::{Decode, Encode, EncodeLike};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 mod mock;
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
 const DEFAULT_RELAYER_THRESHOLD: u32 = 1;
 
+/// Maximum number of deposits, or blocks, covered by a single deposit query
+pub const MAX_DEPOSIT_QUERY: u32 = 1_000;
+
 pub type ChainId = u8;
 pub type DepositNonce = u64;
 pub type ResourceId = [u8; 32];
 
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type NegativeImbalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
 /// Helper function to concatenate a chain ID and some bytes to produce a resource ID.
 /// The common format is (31 bytes unique ID + 1 byte chain ID).
 pub fn derive_resource_id(chain: u8, id: &[u8]) -> ResourceId {
@@ -88,6 +105,126 @@ impl<A: PartialEq, B: PartialOrd + Default> ProposalVotes<A, B> {
     }
 }
 
+/// Direction of a bridge transfer
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum Direction {
+    /// Transfer executed on this chain by a proposal
+    Inbound,
+    /// Transfer out of this chain
+    Outbound,
+}
+
+/// What a rate limit applies to
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum LimitTarget {
+    /// All transfers from or to a chain
+    Chain(ChainId),
+    /// All transfers of a resource
+    Resource(ResourceId),
+}
+
+/// Limits on the amounts transferred over the bridge
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RateLimit {
+    /// Maximum amount of a single transfer
+    pub max_transfer: U256,
+    /// Maximum volume transferred over `RateLimitWindow` blocks
+    pub max_volume: U256,
+}
+
+/// Data of an outbound transfer, as emitted in the transfer events
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum DepositData {
+    /// Fungible transfer of `amount` to `recipient`
+    Fungible { amount: U256, recipient: Vec<u8> },
+    /// Non-fungible transfer of `token_id` to `recipient`
+    NonFungible { token_id: Vec<u8>, recipient: Vec<u8>, metadata: Vec<u8> },
+    /// Generic data payload
+    Generic { metadata: Vec<u8> },
+}
+
+impl DepositData {
+    /// Total length of the variable sized fields
+    fn len(&self) -> usize {
+        match self {
+            DepositData::Fungible { recipient, .. } => recipient.len(),
+            DepositData::NonFungible { token_id, recipient, metadata } =>
+                token_id.len() + recipient.len() + metadata.len(),
+            DepositData::Generic { metadata } => metadata.len(),
+        }
+    }
+}
+
+/// Record of an outbound transfer, kept so relayers can resync from chain state
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct DepositRecord<BlockNumber> {
+    pub resource_id: ResourceId,
+    pub data: DepositData,
+    /// Block in which the transfer was made
+    pub block: BlockNumber,
+    /// Transfer was refunded on this chain and must not be executed on the destination chain
+    pub refunded: bool,
+}
+
+/// Set of relayers voting on proposals
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum RelayerSet {
+    /// Relayers of the chains without their own set
+    Default,
+    /// Relayers of a single chain
+    Chain(ChainId),
+}
+
+/// Vote threshold and size of a relayer set
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RelayerSetConfig {
+    /// Number of votes required for a proposal to execute
+    pub threshold: u32,
+    /// Number of relayers in the set
+    pub relayer_count: u32,
+}
+
+impl Default for RelayerSetConfig {
+    fn default() -> Self {
+        Self {
+            threshold: DEFAULT_RELAYER_THRESHOLD,
+            relayer_count: 0,
+        }
+    }
+}
+
+/// Votes of a relayer on resolved and expired proposals
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RelayerParticipation {
+    /// Number of expired proposals of its set when the relayer was registered
+    pub joined: u32,
+    /// Resolved proposals the relayer voted on
+    pub votes: u32,
+    /// Resolved proposals the relayer voted against the outcome of
+    pub minority_votes: u32,
+    /// Expired proposals the relayer voted on
+    pub expired_votes: u32,
+}
+
+/// Statistics of a relayer, as reported by the runtime API
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RelayerStats<Balance> {
+    /// Set the relayer belongs to
+    pub set: RelayerSet,
+    /// Bond reserved by the relayer
+    pub bond: Balance,
+    /// Resolved proposals the relayer voted on
+    pub votes: u32,
+    /// Resolved proposals the relayer voted against the outcome of
+    pub minority_votes: u32,
+    /// Expired proposals the relayer didn't vote on
+    pub missed_votes: u32,
+}
+
 impl<AccountId, BlockNumber: Default> Default for ProposalVotes<AccountId, BlockNumber> {
     fn default() -> Self {
         Self {
@@ -112,10 +249,32 @@ pub trait Config: system::Config {
     type ChainId: Get<ChainId>;
 
     type ProposalLifetime: Get<Self::BlockNumber>;
+    /// Number of blocks a completed proposal is kept after it expires, so late votes
+    /// are rejected instead of creating a new proposal
+    type ProposalRetention: Get<Self::BlockNumber>;
+    /// Maximum number of relayers in a set, which bounds the number of votes on a proposal
+    type MaxRelayers: Get<u32>;
+    /// Number of blocks over which the volume of transfers is rate limited
+    type RateLimitWindow: Get<Self::BlockNumber>;
+    /// Maximum length of the token ID, recipient and metadata of a deposit record
+    type MaxDepositDataLength: Get<u32>;
+    /// Currency in which relayer bonds are reserved
+    type Currency: ReservableCurrency<Self::AccountId>;
+    /// Bond reserved from a relayer when it is added to the set
+    type RelayerBond: Get<BalanceOf<Self>>;
+    /// Number of missed votes on expired proposals after which a relayer can be slashed for inactivity
+    type MaxMissedVotes: Get<u32>;
+    /// Handler for the slashed relayer bonds
+    type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
 }
 
 decl_event! {
-    pub enum Event<T> where <T as frame_system::Config>::AccountId {
+    pub enum Event<T> where
+        <T as frame_system::Config>::AccountId,
+        Balance = BalanceOf<T>,
+    {
         /// Vote threshold has changed (new_threshold)
         RelayerThresholdChanged(u32),
         /// Chain now available for transfers (chain_id)
@@ -124,6 +283,10 @@ decl_event! {
         RelayerAdded(AccountId),
         /// Relayer removed from set
         RelayerRemoved(AccountId),
+        /// Chain now has its own relayer set, or uses the default set if no threshold (chain_id, threshold)
+        ChainRelayerThresholdChanged(ChainId, Option<u32>),
+        /// Relayer added to the set of a chain (chain_id, relayer)
+        ChainRelayerAdded(ChainId, AccountId),
         /// FunglibleTransfer is for relaying fungibles (dest_id, nonce, resource_id, amount, recipient, metadata)
         FungibleTransfer(ChainId, DepositNonce, ResourceId, U256, Vec<u8>),
         /// NonFungibleTransfer is for relaying NFTS (dest_id, nonce, resource_id, token_id, recipient, metadata)
@@ -142,6 +305,22 @@ decl_event! {
         ProposalSucceeded(ChainId, DepositNonce),
         /// Execution of call failed
         ProposalFailed(ChainId, DepositNonce),
+        /// Proposal expired without being resolved and was removed
+        ProposalExpired(ChainId, DepositNonce),
+        /// All bridge transfers and proposals were paused
+        BridgePaused,
+        /// Bridge transfers and proposals were resumed
+        BridgeResumed,
+        /// Transfers and proposals for a chain were paused (chain_id)
+        ChainPaused(ChainId),
+        /// Transfers and proposals for a chain were resumed (chain_id)
+        ChainResumed(ChainId),
+        /// Rate limit was set, or removed if none (direction, target, limit)
+        RateLimitSet(Direction, LimitTarget, Option<RateLimit>),
+        /// Relayer bond was slashed and the relayer removed from the set (relayer, amount)
+        RelayerSlashed(AccountId, Balance),
+        /// Outbound transfer was refunded and must not be executed (dest_id, nonce)
+        DepositRefunded(ChainId, DepositNonce),
     }
 }
 
@@ -161,6 +340,8 @@ decl_error! {
         ResourceDoesNotExist,
         /// Relayer already in set
         RelayerAlreadyExists,
+        /// Relayer set is full
+        TooManyRelayers,
         /// Provided accountId is not a relayer
         RelayerInvalid,
         /// Protected operation, must be performed by relayer
@@ -177,6 +358,24 @@ decl_error! {
         ProposalAlreadyComplete,
         /// Lifetime of proposal has been exceeded
         ProposalExpired,
+        /// The bridge is paused
+        BridgePaused,
+        /// Transfers and proposals for this chain are paused
+        ChainPaused,
+        /// Transfer data exceeds the maximum length of a deposit record
+        DepositDataTooLong,
+        /// Only approved proposals can be reported as fraudulent
+        ProposalNotApproved,
+        /// Relayer hasn't missed enough votes to be slashed
+        NotEnoughMissedVotes,
+        /// Chain doesn't have its own relayer set
+        RelayerSetDoesNotExist,
+        /// Relayer set still has relayers
+        RelayerSetNotEmpty,
+        /// No deposit with this nonce was made to the chain
+        DepositDoesNotExist,
+        /// Deposit was already refunded
+        DepositAlreadyRefunded,
     }
 }
 
@@ -185,14 +384,24 @@ decl_storage! {
         /// All whitelisted chains and their respective transaction counts
         ChainNonces get(fn chains): map hasher(twox_64_concat) ChainId => Option<DepositNonce>;
 
-        /// Number of votes required for a proposal to execute
-        RelayerThreshold get(fn relayer_threshold): u32 = DEFAULT_RELAYER_THRESHOLD;
+        /// Vote threshold and size of each relayer set.
+        /// Chains without their own set use the default set.
+        pub RelayerSets get(fn relayer_set_config): map hasher(twox_64_concat) RelayerSet => RelayerSetConfig;
+
+        /// Set each relayer belongs to
+        pub Relayers get(fn relayers): map hasher(twox_64_concat) T::AccountId => Option<RelayerSet>;
 
-        /// Tracks current relayer set
-        pub Relayers get(fn relayers): map hasher(twox_64_concat) T::AccountId => bool;
+        /// Bond reserved by each relayer
+        pub RelayerBonds get(fn relayer_bond): map hasher(twox_64_concat) T::AccountId => BalanceOf<T>;
 
-        /// Number of relayers in set
-        pub RelayerCount get(fn relayer_count): u32;
+        /// Votes of each relayer on resolved proposals
+        pub Participation get(fn participation): map hasher(twox_64_concat) T::AccountId => RelayerParticipation;
+
+        /// Number of proposals resolved by each relayer set, either approved or rejected
+        pub ResolvedProposals get(fn resolved_proposals): map hasher(twox_64_concat) RelayerSet => u32;
+
+        /// Number of proposals of each relayer set that expired without being resolved
+        pub ExpiredProposals get(fn expired_proposals): map hasher(twox_64_concat) RelayerSet => u32;
 
         /// All known proposals.
         /// The key is the hash of the call and the deposit ID, to ensure it's unique.
@@ -200,9 +409,46 @@ decl_storage! {
             double_map hasher(twox_64_concat) ChainId, hasher(twox_64_concat) (DepositNonce, T::Proposal)
             => Option<ProposalVotes<T::AccountId, T::BlockNumber>>;
 
+        /// Highest nonce of the proposals pruned from `Votes`, per source chain.
+        /// Deposits up to it can't be proposed again once their votes are gone, whatever the outcome.
+        pub PrunedNonces get(fn pruned_nonce): map hasher(twox_64_concat) ChainId => Option<DepositNonce>;
+
         /// Utilized by the bridge software to map resource IDs to actual methods
         pub Resources get(fn resources):
-            map hasher(twox_64_concat) ResourceId => Option<Vec<u8>>
+            map hasher(twox_64_concat) ResourceId => Option<Vec<u8>>;
+
+        /// Whether all bridge transfers and proposals are paused
+        pub Paused get(fn paused): bool;
+
+        /// Chains for which transfers and proposals are paused
+        pub PausedChains get(fn chain_paused): map hasher(twox_64_concat) ChainId => bool;
+
+        /// Rate limits on the amounts transferred, per direction and target
+        pub RateLimits get(fn rate_limits):
+            double_map hasher(twox_64_concat) Direction, hasher(twox_64_concat) LimitTarget
+            => Option<RateLimit>;
+
+        /// Volume transferred under each rate limit, and the block it was last updated.
+        /// The volume is released linearly over `RateLimitWindow` blocks.
+        pub Volumes get(fn volumes):
+            double_map hasher(twox_64_concat) Direction, hasher(twox_64_concat) LimitTarget
+            => (T::BlockNumber, U256);
+
+        /// Records of outbound transfers, by destination chain and nonce
+        pub Deposits get(fn deposits):
+            double_map hasher(twox_64_concat) ChainId, hasher(twox_64_concat) DepositNonce
+            => Option<DepositRecord<T::BlockNumber>>;
+
+        /// Outbound transfers made in each block, as destination chain and nonce
+        pub BlockDeposits get(fn block_deposits):
+            map hasher(twox_64_concat) T::BlockNumber => Vec<(ChainId, DepositNonce)>;
+
+        /// Source chain and nonce of the proposal being executed
+        pub ProposalSource get(fn proposal_source): Option<(ChainId, DepositNonce)>;
+
+        /// Raw key of the last proposal checked when pruning stale proposals.
+        /// Pruning continues from this key, and starts over once all proposals are checked.
+        PruneCursor get(fn prune_cursor): Option<Vec<u8>>;
     }
 }
 
@@ -212,11 +458,21 @@ decl_module! {
 
         const ChainIdentity: ChainId = T::ChainId::get();
         const ProposalLifetime: T::BlockNumber = T::ProposalLifetime::get();
+        const ProposalRetention: T::BlockNumber = T::ProposalRetention::get();
+        const MaxRelayers: u32 = T::MaxRelayers::get();
+        const RateLimitWindow: T::BlockNumber = T::RateLimitWindow::get();
+        const MaxDepositDataLength: u32 = T::MaxDepositDataLength::get();
+        const RelayerBond: BalanceOf<T> = T::RelayerBond::get();
+        const MaxMissedVotes: u32 = T::MaxMissedVotes::get();
         /// The ChainBridge's module id, used for deriving account ID
         const PalletId: PalletId = T::PalletId::get();
 
         fn deposit_event() = default;
 
+        fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+            Self::prune_proposals(now, remaining_weight)
+        }
+
         /// Sets the vote threshold for proposals.
         ///
         /// This threshold is used to determine how many votes are required
@@ -225,7 +481,7 @@ decl_module! {
         /// # <weight>
         /// - O(1) lookup and insert
         /// # </weight>
-        #[weight = 195_000_000]
+        #[weight = T::WeightInfo::set_threshold()]
         pub fn set_threshold(origin, threshold: u32) -> DispatchResult {
             Self::ensure_admin(origin)?;
             Self::set_relayer_threshold(threshold)
@@ -236,7 +492,7 @@ decl_module! {
         /// # <weight>
         /// - O(1) write
         /// # </weight>
-        #[weight = 195_000_000]
+        #[weight = T::WeightInfo::set_resource()]
         pub fn set_resource(origin, id: ResourceId, method: Vec<u8>) -> DispatchResult {
             Self::ensure_admin(origin)?;
             Self::register_resource(id, method)
@@ -250,7 +506,7 @@ decl_module! {
         /// # <weight>
         /// - O(1) removal
         /// # </weight>
-        #[weight = 195_000_000]
+        #[weight = T::WeightInfo::remove_resource()]
         pub fn remove_resource(origin, id: ResourceId) -> DispatchResult {
             Self::ensure_admin(origin)?;
             Self::unregister_resource(id)
@@ -261,29 +517,29 @@ decl_module! {
         /// # <weight>
         /// - O(1) lookup and insert
         /// # </weight>
-        #[weight = 195_000_000]
+        #[weight = T::WeightInfo::whitelist_chain()]
         pub fn whitelist_chain(origin, id: ChainId) -> DispatchResult {
             Self::ensure_admin(origin)?;
             Self::whitelist(id)
         }
 
-        /// Adds a new relayer to the relayer set.
+        /// Adds a new relayer to the default relayer set, reserving the relayer bond from its account.
         ///
         /// # <weight>
         /// - O(1) lookup and insert
         /// # </weight>
-        #[weight = 195_000_000]
+        #[weight = T::WeightInfo::add_relayer()]
         pub fn add_relayer(origin, v: T::AccountId) -> DispatchResult {
             Self::ensure_admin(origin)?;
-            Self::register_relayer(v)
+            Self::register_relayer(v, T::RelayerBond::get())
         }
 
-        /// Removes an existing relayer from the set.
+        /// Removes an existing relayer from its set, returning its bond.
         ///
         /// # <weight>
         /// - O(1) lookup and removal
         /// # </weight>
-        #[weight = 195_000_000]
+        #[weight = T::WeightInfo::remove_relayer()]
         pub fn remove_relayer(origin, v: T::AccountId) -> DispatchResult {
             Self::ensure_admin(origin)?;
             Self::unregister_relayer(v)
@@ -295,14 +551,20 @@ decl_module! {
         /// be created with an initial vote in favour from the caller.
         ///
         /// # <weight>
+        /// - O(V) where V is the number of votes, bounded by `MaxRelayers`
         /// - weight of proposed call, regardless of whether execution is performed
         /// # </weight>
-        #[weight = (call.get_dispatch_info().weight + Weight::from_ref_time(195_000_000), call.get_dispatch_info().class, Pays::Yes)]
+        #[weight = (
+            call.get_dispatch_info().weight.saturating_add(T::WeightInfo::acknowledge_proposal(T::MaxRelayers::get())),
+            call.get_dispatch_info().class,
+            Pays::Yes
+        )]
         pub fn acknowledge_proposal(origin, nonce: DepositNonce, src_id: ChainId, r_id: ResourceId, call: Box<<T as Config>::Proposal>) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(Self::is_relayer(&who), Error::<T>::MustBeRelayer);
+            ensure!(Self::is_chain_relayer(src_id, &who), Error::<T>::MustBeRelayer);
             ensure!(Self::chain_whitelisted(src_id), Error::<T>::ChainNotWhitelisted);
             ensure!(Self::resource_exists(r_id), Error::<T>::ResourceDoesNotExist);
+            Self::ensure_not_paused(src_id)?;
 
             Self::vote_for(who, nonce, src_id, call)
         }
@@ -310,14 +572,16 @@ decl_module! {
         /// Commits a vote against a provided proposal.
         ///
         /// # <weight>
-        /// - Fixed, since execution of proposal should not be included
+        /// - O(V) where V is the number of votes, bounded by `MaxRelayers`
+        /// - Execution of proposal is not included
         /// # </weight>
-        #[weight = 195_000_000]
+        #[weight = T::WeightInfo::reject_proposal(T::MaxRelayers::get())]
         pub fn reject_proposal(origin, nonce: DepositNonce, src_id: ChainId, r_id: ResourceId, call: Box<<T as Config>::Proposal>) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(Self::is_relayer(&who), Error::<T>::MustBeRelayer);
+            ensure!(Self::is_chain_relayer(src_id, &who), Error::<T>::MustBeRelayer);
             ensure!(Self::chain_whitelisted(src_id), Error::<T>::ChainNotWhitelisted);
             ensure!(Self::resource_exists(r_id), Error::<T>::ResourceDoesNotExist);
+            Self::ensure_not_paused(src_id)?;
 
             Self::vote_against(who, nonce, src_id, call)
         }
@@ -328,14 +592,133 @@ decl_module! {
         /// will be updated accordingly.
         ///
         /// # <weight>
+        /// - O(V) where V is the number of votes, bounded by `MaxRelayers`
         /// - weight of proposed call, regardless of whether execution is performed
         /// # </weight>
-        #[weight = (prop.get_dispatch_info().weight + Weight::from_ref_time(195_000_000), prop.get_dispatch_info().class, Pays::Yes)]
+        #[weight = (
+            prop.get_dispatch_info().weight.saturating_add(T::WeightInfo::eval_vote_state(T::MaxRelayers::get())),
+            prop.get_dispatch_info().class,
+            Pays::Yes
+        )]
         pub fn eval_vote_state(origin, nonce: DepositNonce, src_id: ChainId, prop: Box<<T as Config>::Proposal>) -> DispatchResult {
             ensure_signed(origin)?;
+            Self::ensure_not_paused(src_id)?;
 
             Self::try_resolve_proposal(nonce, src_id, prop)
         }
+
+        /// Pauses or resumes all bridge transfers and proposals.
+        ///
+        /// # <weight>
+        /// - O(1) write
+        /// # </weight>
+        #[weight = T::WeightInfo::set_bridge_paused()]
+        pub fn set_bridge_paused(origin, paused: bool) -> DispatchResult {
+            Self::ensure_admin(origin)?;
+            <Paused>::put(paused);
+            if paused {
+                Self::deposit_event(RawEvent::BridgePaused);
+            } else {
+                Self::deposit_event(RawEvent::BridgeResumed);
+            }
+            Ok(())
+        }
+
+        /// Pauses or resumes transfers and proposals for a single chain.
+        ///
+        /// # <weight>
+        /// - O(1) write
+        /// # </weight>
+        #[weight = T::WeightInfo::set_chain_paused()]
+        pub fn set_chain_paused(origin, id: ChainId, paused: bool) -> DispatchResult {
+            Self::ensure_admin(origin)?;
+            if paused {
+                <PausedChains>::insert(id, true);
+                Self::deposit_event(RawEvent::ChainPaused(id));
+            } else {
+                <PausedChains>::remove(id);
+                Self::deposit_event(RawEvent::ChainResumed(id));
+            }
+            Ok(())
+        }
+
+        /// Sets the rate limit for transfers in a direction, for a chain or resource.
+        /// The limit is removed if none is given.
+        ///
+        /// # <weight>
+        /// - O(1) write
+        /// # </weight>
+        #[weight = T::WeightInfo::set_rate_limit()]
+        pub fn set_rate_limit(origin, direction: Direction, target: LimitTarget, limit: Option<RateLimit>) -> DispatchResult {
+            Self::ensure_admin(origin)?;
+            match &limit {
+                Some(l) => <RateLimits>::insert(direction, target, l),
+                None => <RateLimits>::remove(direction, target),
+            }
+            Self::deposit_event(RawEvent::RateLimitSet(direction, target, limit));
+            Ok(())
+        }
+
+        /// Slashes the relayers that voted for a proposal proven to be fraudulent,
+        /// removing them from the set.
+        ///
+        /// The proposal must have been approved and not pruned yet.
+        ///
+        /// # <weight>
+        /// - O(V) where V is the number of votes, bounded by `MaxRelayers`
+        /// # </weight>
+        #[weight = T::WeightInfo::slash_voters(T::MaxRelayers::get())]
+        pub fn slash_voters(origin, nonce: DepositNonce, src_id: ChainId, prop: Box<<T as Config>::Proposal>) -> DispatchResult {
+            Self::ensure_admin(origin)?;
+            let votes = <Votes<T>>::get(src_id, (nonce, *prop)).ok_or(Error::<T>::ProposalDoesNotExist)?;
+            ensure!(votes.status == ProposalStatus::Approved, Error::<T>::ProposalNotApproved);
+            for who in votes.votes_for {
+                if Self::is_relayer(&who) {
+                    Self::slash_relayer(who);
+                }
+            }
+            Ok(())
+        }
+
+        /// Slashes a relayer that missed at least `MaxMissedVotes` votes, removing it from its set.
+        /// Only votes on proposals that expired without being resolved are missed votes.
+        ///
+        /// # <weight>
+        /// - O(1) lookup and removal
+        /// # </weight>
+        #[weight = T::WeightInfo::slash_inactive_relayer()]
+        pub fn slash_inactive_relayer(origin, v: T::AccountId) -> DispatchResult {
+            Self::ensure_admin(origin)?;
+            ensure!(Self::is_relayer(&v), Error::<T>::RelayerInvalid);
+            ensure!(Self::missed_votes(&v) >= T::MaxMissedVotes::get(), Error::<T>::NotEnoughMissedVotes);
+            Self::slash_relayer(v);
+            Ok(())
+        }
+
+        /// Sets the vote threshold of the relayer set of a chain, creating the set if needed.
+        ///
+        /// If no threshold is given the set is removed, which requires it to be empty,
+        /// and the chain uses the default relayer set again.
+        ///
+        /// # <weight>
+        /// - O(1) lookup and insert
+        /// # </weight>
+        #[weight = T::WeightInfo::set_chain_threshold()]
+        pub fn set_chain_threshold(origin, id: ChainId, threshold: Option<u32>) -> DispatchResult {
+            Self::ensure_admin(origin)?;
+            Self::set_chain_relayer_threshold(id, threshold)
+        }
+
+        /// Adds a new relayer to the relayer set of a chain, reserving the relayer bond from its account.
+        ///
+        /// # <weight>
+        /// - O(1) lookup and insert
+        /// # </weight>
+        #[weight = T::WeightInfo::add_chain_relayer()]
+        pub fn add_chain_relayer(origin, id: ChainId, v: T::AccountId) -> DispatchResult {
+            Self::ensure_admin(origin)?;
+            Self::register_chain_relayer(id, v, T::RelayerBond::get())
+        }
     }
 }
 
@@ -349,9 +732,33 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
-    /// Checks if who is a relayer
+    /// Checks if who is a relayer of any set
     pub fn is_relayer(who: &T::AccountId) -> bool {
-        Self::relayers(who)
+        Self::relayers(who).is_some()
+    }
+
+    /// Checks if who is a relayer of the set voting on proposals from a chain
+    pub fn is_chain_relayer(id: ChainId, who: &T::AccountId) -> bool {
+        Self::relayers(who) == Some(Self::relayer_set(id))
+    }
+
+    /// Relayer set voting on proposals from a chain, the default set unless the chain has its own
+    pub fn relayer_set(id: ChainId) -> RelayerSet {
+        if <RelayerSets>::contains_key(RelayerSet::Chain(id)) {
+            RelayerSet::Chain(id)
+        } else {
+            RelayerSet::Default
+        }
+    }
+
+    /// Vote threshold of the default relayer set
+    pub fn relayer_threshold() -> u32 {
+        Self::relayer_set_config(RelayerSet::Default).threshold
+    }
+
+    /// Number of relayers in the default relayer set
+    pub fn relayer_count() -> u32 {
+        Self::relayer_set_config(RelayerSet::Default).relayer_count
     }
 
     /// Provides an AccountId for the pallet.
@@ -377,16 +784,82 @@ impl<T: Config> Module<T> {
         nonce
     }
 
+    /// Ensures transfers and proposals are allowed for a chain
+    pub fn ensure_not_paused(id: ChainId) -> DispatchResult {
+        ensure!(!Self::paused(), Error::<T>::BridgePaused);
+        ensure!(!Self::chain_paused(id), Error::<T>::ChainPaused);
+        Ok(())
+    }
+
+    // *** Rate limiting methods ***
+
+    /// Volume currently counted against a rate limit, after releasing the volume
+    /// for the blocks elapsed since it was last updated.
+    fn current_volume(direction: Direction, target: LimitTarget, limit: &RateLimit) -> U256 {
+        let (updated, volume) = Self::volumes(direction, target);
+        let now = <frame_system::Pallet<T>>::block_number();
+        let window = T::RateLimitWindow::get().saturated_into::<u128>().max(1);
+        let elapsed = now.saturating_sub(updated).saturated_into::<u128>().min(window);
+        let released = limit.max_volume.saturating_mul(U256::from(elapsed)) / U256::from(window);
+        volume.saturating_sub(released)
+    }
+
+    /// Returns true if transferring `amount` is allowed by the rate limits of all targets
+    pub fn within_limits(direction: Direction, targets: &[LimitTarget], amount: U256) -> bool {
+        targets.iter().all(|target| match Self::rate_limits(direction, target) {
+            Some(limit) => amount <= limit.max_transfer &&
+                Self::current_volume(direction, *target, &limit).saturating_add(amount) <= limit.max_volume,
+            None => true,
+        })
+    }
+
+    /// Counts a transfer of `amount` against the rate limits of all targets, if it is allowed by all of them.
+    /// Returns true if the transfer is allowed.
+    pub fn consume_limits(direction: Direction, targets: &[LimitTarget], amount: U256) -> bool {
+        if !Self::within_limits(direction, targets, amount) {
+            return false
+        }
+        let now = <frame_system::Pallet<T>>::block_number();
+        for target in targets {
+            if let Some(limit) = Self::rate_limits(direction, target) {
+                let volume = Self::current_volume(direction, *target, &limit).saturating_add(amount);
+                <Volumes<T>>::insert(direction, target, (now, volume));
+            }
+        }
+        true
+    }
+
     // *** Admin methods ***
 
-    /// Set a new voting threshold
+    /// Set a new voting threshold for the default relayer set
     pub fn set_relayer_threshold(threshold: u32) -> DispatchResult {
         ensure!(threshold > 0, Error::<T>::InvalidThreshold);
-        <RelayerThreshold>::put(threshold);
+        <RelayerSets>::mutate(RelayerSet::Default, |config| config.threshold = threshold);
         Self::deposit_event(RawEvent::RelayerThresholdChanged(threshold));
         Ok(())
     }
 
+    /// Set a new voting threshold for the relayer set of a chain, or remove the set if none
+    pub fn set_chain_relayer_threshold(id: ChainId, threshold: Option<u32>) -> DispatchResult {
+        let set = RelayerSet::Chain(id);
+        match threshold {
+            Some(threshold) => {
+                ensure!(threshold > 0, Error::<T>::InvalidThreshold);
+                ensure!(Self::chain_whitelisted(id), Error::<T>::ChainNotWhitelisted);
+                <RelayerSets>::mutate(set, |config| config.threshold = threshold);
+            },
+            None => {
+                ensure!(<RelayerSets>::contains_key(set), Error::<T>::RelayerSetDoesNotExist);
+                ensure!(Self::relayer_set_config(set).relayer_count == 0, Error::<T>::RelayerSetNotEmpty);
+                <RelayerSets>::remove(set);
+                <ResolvedProposals>::remove(set);
+                <ExpiredProposals>::remove(set);
+            },
+        }
+        Self::deposit_event(RawEvent::ChainRelayerThresholdChanged(id, threshold));
+        Ok(())
+    }
+
     /// Register a method for a resource Id, enabling associated transfers
     pub fn register_resource(id: ResourceId, method: Vec<u8>) -> DispatchResult {
         <Resources>::insert(id, method);
@@ -413,28 +886,136 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
-    /// Adds a new relayer to the set
-    pub fn register_relayer(relayer: T::AccountId) -> DispatchResult {
-        ensure!(
-            !Self::is_relayer(&relayer),
-            Error::<T>::RelayerAlreadyExists
-        );
-        <Relayers<T>>::insert(&relayer, true);
-        <RelayerCount>::mutate(|i| *i += 1);
-
+    /// Adds a new relayer to the default set, reserving `bond` from its account
+    pub fn register_relayer(relayer: T::AccountId, bond: BalanceOf<T>) -> DispatchResult {
+        Self::insert_relayer(RelayerSet::Default, &relayer, bond)?;
         Self::deposit_event(RawEvent::RelayerAdded(relayer));
         Ok(())
     }
 
-    /// Removes a relayer from the set
+    /// Adds a new relayer to the set of a chain, reserving `bond` from its account
+    pub fn register_chain_relayer(id: ChainId, relayer: T::AccountId, bond: BalanceOf<T>) -> DispatchResult {
+        let set = RelayerSet::Chain(id);
+        ensure!(<RelayerSets>::contains_key(set), Error::<T>::RelayerSetDoesNotExist);
+        Self::insert_relayer(set, &relayer, bond)?;
+        Self::deposit_event(RawEvent::ChainRelayerAdded(id, relayer));
+        Ok(())
+    }
+
+    /// Removes a relayer from its set, returning its bond
     pub fn unregister_relayer(relayer: T::AccountId) -> DispatchResult {
         ensure!(Self::is_relayer(&relayer), Error::<T>::RelayerInvalid);
-        <Relayers<T>>::remove(&relayer);
-        <RelayerCount>::mutate(|i| *i -= 1);
+        T::Currency::unreserve(&relayer, <RelayerBonds<T>>::take(&relayer));
+        Self::remove_relayer_from_set(&relayer);
         Self::deposit_event(RawEvent::RelayerRemoved(relayer));
         Ok(())
     }
 
+    /// Adds a relayer to a set, which can only be done if it isn't in any set yet
+    fn insert_relayer(set: RelayerSet, relayer: &T::AccountId, bond: BalanceOf<T>) -> DispatchResult {
+        ensure!(
+            !Self::is_relayer(relayer),
+            Error::<T>::RelayerAlreadyExists
+        );
+        ensure!(
+            Self::relayer_set_config(set).relayer_count < T::MaxRelayers::get(),
+            Error::<T>::TooManyRelayers
+        );
+        if !bond.is_zero() {
+            T::Currency::reserve(relayer, bond)?;
+            <RelayerBonds<T>>::insert(relayer, bond);
+        }
+        <Relayers<T>>::insert(relayer, set);
+        <RelayerSets>::mutate(set, |config| config.relayer_count += 1);
+        <Participation<T>>::insert(relayer, RelayerParticipation {
+            joined: Self::expired_proposals(set),
+            ..Default::default()
+        });
+        Ok(())
+    }
+
+    /// Removes a relayer from its set, without touching its bond
+    fn remove_relayer_from_set(relayer: &T::AccountId) {
+        if let Some(set) = <Relayers<T>>::take(relayer) {
+            <RelayerSets>::mutate(set, |config| config.relayer_count -= 1);
+        }
+        <Participation<T>>::remove(relayer);
+    }
+
+    // *** Relayer participation methods ***
+
+    /// Number of proposals of its set that expired without being resolved, and that a relayer
+    /// didn't vote on since it was registered.
+    ///
+    /// Proposals that were resolved aren't counted, since they can resolve before slower relayers vote.
+    /// Expired proposals stayed open until their expiry, so every relayer of the set could vote on them.
+    /// They are counted when pruned, so proposals that expired just before a relayer was registered
+    /// can be counted for it if they weren't pruned yet.
+    pub fn missed_votes(relayer: &T::AccountId) -> u32 {
+        let expired = match Self::relayers(relayer) {
+            Some(set) => Self::expired_proposals(set),
+            None => return 0,
+        };
+        let participation = Self::participation(relayer);
+        expired
+            .saturating_sub(participation.joined)
+            .saturating_sub(participation.expired_votes)
+    }
+
+    /// Statistics of a relayer, if it is in a set
+    pub fn relayer_stats(relayer: &T::AccountId) -> Option<RelayerStats<BalanceOf<T>>> {
+        let set = Self::relayers(relayer)?;
+        let participation = Self::participation(relayer);
+        Some(RelayerStats {
+            set,
+            bond: Self::relayer_bond(relayer),
+            votes: participation.votes,
+            minority_votes: participation.minority_votes,
+            missed_votes: Self::missed_votes(relayer),
+        })
+    }
+
+    /// Counts the votes of the relayers of a set on a proposal it resolved
+    fn record_participation(set: RelayerSet, votes: &ProposalVotes<T::AccountId, T::BlockNumber>) {
+        <ResolvedProposals>::mutate(set, |n| *n = n.saturating_add(1));
+        let approved = votes.status == ProposalStatus::Approved;
+        let voters = votes.votes_for.iter().map(|who| (who, !approved))
+            .chain(votes.votes_against.iter().map(|who| (who, approved)));
+        for (who, minority) in voters {
+            // Voters may have been removed from the set since they voted
+            if Self::relayers(who) != Some(set) {
+                continue
+            }
+            <Participation<T>>::mutate(who, |p| {
+                p.votes = p.votes.saturating_add(1);
+                if minority {
+                    p.minority_votes = p.minority_votes.saturating_add(1);
+                }
+            });
+        }
+    }
+
+    /// Counts the votes of the relayers of a set on a proposal that expired without being resolved
+    fn record_expiry(set: RelayerSet, votes: &ProposalVotes<T::AccountId, T::BlockNumber>) {
+        <ExpiredProposals>::mutate(set, |n| *n = n.saturating_add(1));
+        for who in votes.votes_for.iter().chain(votes.votes_against.iter()) {
+            // Voters may have been removed from the set since they voted
+            if Self::relayers(who) != Some(set) {
+                continue
+            }
+            <Participation<T>>::mutate(who, |p| p.expired_votes = p.expired_votes.saturating_add(1));
+        }
+    }
+
+    /// Slashes the bond of a relayer and removes it from its set
+    fn slash_relayer(relayer: T::AccountId) {
+        let bond = <RelayerBonds<T>>::take(&relayer);
+        let (imbalance, _) = T::Currency::slash_reserved(&relayer, bond);
+        T::Slashed::on_unbalanced(imbalance);
+        Self::remove_relayer_from_set(&relayer);
+        Self::deposit_event(RawEvent::RelayerSlashed(relayer, bond));
+    }
+
     // *** Proposal voting and execution methods ***
 
     /// Commits a vote for a proposal. If the proposal doesn't exist it will be created.
@@ -449,6 +1030,11 @@ impl<T: Config> Module<T> {
         let mut votes = match <Votes<T>>::get(src_id, (nonce, prop.clone())) {
             Some(v) => v,
             None => {
+                // Ensure the deposit wasn't already resolved by a proposal that has since been pruned
+                ensure!(
+                    Self::pruned_nonce(src_id).map_or(true, |pruned| nonce > pruned),
+                    Error::<T>::ProposalAlreadyComplete
+                );
                 let mut v = ProposalVotes::default();
                 v.expiry = now + T::ProposalLifetime::get();
                 v
@@ -484,8 +1070,13 @@ impl<T: Config> Module<T> {
             ensure!(!votes.is_complete(), Error::<T>::ProposalAlreadyComplete);
             ensure!(!votes.is_expired(now), Error::<T>::ProposalExpired);
 
-            let status = votes.try_to_complete(<RelayerThreshold>::get(), <RelayerCount>::get());
+            let set = Self::relayer_set(src_id);
+            let config = Self::relayer_set_config(set);
+            let status = votes.try_to_complete(config.threshold, config.relayer_count);
             <Votes<T>>::insert(src_id, (nonce, prop.clone()), votes.clone());
+            if votes.is_complete() {
+                Self::record_participation(set, &votes);
+            }
 
             match status {
                 ProposalStatus::Approved => Self::finalize_execution(src_id, nonce, prop),
@@ -527,9 +1118,10 @@ impl<T: Config> Module<T> {
         call: Box<T::Proposal>,
     ) -> DispatchResult {
         Self::deposit_event(RawEvent::ProposalApproved(src_id, nonce));
-        call.dispatch(frame_system::RawOrigin::Signed(Self::account_id()).into())
-            .map(|_| ())
-            .map_err(|e| e.error)?;
+        <ProposalSource>::put((src_id, nonce));
+        let result = call.dispatch(frame_system::RawOrigin::Signed(Self::account_id()).into());
+        <ProposalSource>::kill();
+        result.map(|_| ()).map_err(|e| e.error)?;
         Self::deposit_event(RawEvent::ProposalSucceeded(src_id, nonce));
         Ok(())
     }
@@ -540,18 +1132,97 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    // *** Proposal pruning methods ***
+
+    /// Returns true if the proposal expired without being resolved, or if it was completed
+    /// and its retention period has passed.
+    pub fn is_stale(votes: &ProposalVotes<T::AccountId, T::BlockNumber>, now: T::BlockNumber) -> bool {
+        if votes.is_complete() {
+            votes.expiry + T::ProposalRetention::get() <= now
+        } else {
+            votes.is_expired(now)
+        }
+    }
+
+    /// Removes a stale proposal, signaling and counting proposals that expired without resolution.
+    /// Raises the pruned nonce of the source chain, so the deposit can't be proposed again.
+    ///
+    /// Proposals are only pruned after they expire, so deposits are expected to be proposed
+    /// before proposals of later deposits from the same chain are pruned.
+    pub fn remove_proposal(
+        src_id: ChainId,
+        nonce: DepositNonce,
+        prop: T::Proposal,
+        votes: &ProposalVotes<T::AccountId, T::BlockNumber>,
+    ) {
+        <Votes<T>>::remove(src_id, (nonce, prop));
+        <PrunedNonces>::mutate(src_id, |pruned| *pruned = Some(pruned.map_or(nonce, |n| n.max(nonce))));
+        if votes.status == ProposalStatus::Initiated {
+            Self::record_expiry(Self::relayer_set(src_id), votes);
+            Self::deposit_event(RawEvent::ProposalExpired(src_id, nonce));
+        }
+    }
+
+    /// Removes stale proposals, checking as many proposals as the remaining weight allows.
+    /// Continues from the last proposal checked in the previous call.
+    fn prune_proposals(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+        let base_weight = T::WeightInfo::prune_proposals(0);
+        let proposal_weight = T::WeightInfo::prune_proposals(1).saturating_sub(base_weight);
+        if remaining_weight.ref_time() < base_weight.ref_time() + proposal_weight.ref_time() {
+            return Weight::zero()
+        }
+        let max = (remaining_weight.ref_time() - base_weight.ref_time()) / proposal_weight.ref_time().max(1);
+
+        // 1. Check proposals, starting after the cursor
+        let mut iter = match <PruneCursor>::get() {
+            Some(key) => <Votes<T>>::iter_from(key),
+            None => <Votes<T>>::iter(),
+        };
+        let mut checked = 0u64;
+        let mut stale = Vec::new();
+        let mut exhausted = false;
+        while checked < max {
+            match iter.next() {
+                Some((src_id, (nonce, prop), votes)) => {
+                    checked += 1;
+                    if Self::is_stale(&votes, now) {
+                        stale.push((src_id, nonce, prop, votes));
+                    }
+                },
+                None => {
+                    exhausted = true;
+                    break
+                },
+            }
+        }
+
+        // 2. Update the cursor, starting over if all proposals were checked
+        if exhausted {
+            <PruneCursor>::kill();
+        } else {
+            <PruneCursor>::put(iter.last_raw_key().to_vec());
+        }
+
+        // 3. Remove stale proposals
+        for (src_id, nonce, prop, votes) in stale {
+            Self::remove_proposal(src_id, nonce, prop, &votes);
+        }
+
+        base_weight.saturating_add(proposal_weight.saturating_mul(checked))
+    }
+
     /// Initiates a transfer of a fungible asset out of the chain. This should be called by another pallet.
+    /// Returns the nonce of the deposit.
     pub fn transfer_fungible(
         dest_id: ChainId,
         resource_id: ResourceId,
         to: Vec<u8>,
         amount: U256,
-    ) -> DispatchResult {
-        ensure!(
-            Self::chain_whitelisted(dest_id),
-            Error::<T>::ChainNotWhitelisted
-        );
-        let nonce = Self::bump_nonce(dest_id);
+    ) -> Result<DepositNonce, DispatchError> {
+        let nonce = Self::record_deposit(dest_id, resource_id, DepositData::Fungible {
+            amount,
+            recipient: to.clone(),
+        })?;
         Self::deposit_event(RawEvent::FungibleTransfer(
             dest_id,
             nonce,
@@ -559,22 +1230,23 @@ impl<T: Config> Module<T> {
             amount,
             to,
         ));
-        Ok(())
+        Ok(nonce)
     }
 
     /// Initiates a transfer of a nonfungible asset out of the chain. This should be called by another pallet.
+    /// Returns the nonce of the deposit.
     pub fn transfer_nonfungible(
         dest_id: ChainId,
         resource_id: ResourceId,
         token_id: Vec<u8>,
         to: Vec<u8>,
         metadata: Vec<u8>,
-    ) -> DispatchResult {
-        ensure!(
-            Self::chain_whitelisted(dest_id),
-            Error::<T>::ChainNotWhitelisted
-        );
-        let nonce = Self::bump_nonce(dest_id);
+    ) -> Result<DepositNonce, DispatchError> {
+        let nonce = Self::record_deposit(dest_id, resource_id, DepositData::NonFungible {
+            token_id: token_id.clone(),
+            recipient: to.clone(),
+            metadata: metadata.clone(),
+        })?;
         Self::deposit_event(RawEvent::NonFungibleTransfer(
             dest_id,
             nonce,
@@ -583,28 +1255,94 @@ impl<T: Config> Module<T> {
             to,
             metadata,
         ));
-        Ok(())
+        Ok(nonce)
     }
 
     /// Initiates a transfer of generic data out of the chain. This should be called by another pallet.
+    /// Returns the nonce of the deposit.
     pub fn transfer_generic(
         dest_id: ChainId,
         resource_id: ResourceId,
         metadata: Vec<u8>,
-    ) -> DispatchResult {
-        ensure!(
-            Self::chain_whitelisted(dest_id),
-            Error::<T>::ChainNotWhitelisted
-        );
-        let nonce = Self::bump_nonce(dest_id);
+    ) -> Result<DepositNonce, DispatchError> {
+        let nonce = Self::record_deposit(dest_id, resource_id, DepositData::Generic {
+            metadata: metadata.clone(),
+        })?;
         Self::deposit_event(RawEvent::GenericTransfer(
             dest_id,
             nonce,
             resource_id,
             metadata,
         ));
+        Ok(nonce)
+    }
+
+    // *** Deposit record methods ***
+
+    /// Checks an outbound transfer can be made, then bumps the nonce of the destination chain
+    /// and stores the deposit record. Returns the nonce of the deposit.
+    fn record_deposit(dest_id: ChainId, resource_id: ResourceId, data: DepositData) -> Result<DepositNonce, DispatchError> {
+        ensure!(
+            Self::chain_whitelisted(dest_id),
+            Error::<T>::ChainNotWhitelisted
+        );
+        Self::ensure_not_paused(dest_id)?;
+        ensure!(
+            data.len() <= T::MaxDepositDataLength::get() as usize,
+            Error::<T>::DepositDataTooLong
+        );
+        let nonce = Self::bump_nonce(dest_id);
+        let block = <frame_system::Pallet<T>>::block_number();
+        <Deposits<T>>::insert(dest_id, nonce, DepositRecord { resource_id, data, block, refunded: false });
+        <BlockDeposits<T>>::append(block, (dest_id, nonce));
+        Ok(nonce)
+    }
+
+    /// Marks a deposit as refunded, so relayers don't execute it on the destination chain.
+    /// This should be called by the pallet refunding the transfer.
+    pub fn mark_refunded(dest_id: ChainId, nonce: DepositNonce) -> DispatchResult {
+        <Deposits<T>>::try_mutate(dest_id, nonce, |record| -> DispatchResult {
+            let record = record.as_mut().ok_or(Error::<T>::DepositDoesNotExist)?;
+            ensure!(!record.refunded, Error::<T>::DepositAlreadyRefunded);
+            record.refunded = true;
+            Ok(())
+        })?;
+        Self::deposit_event(RawEvent::DepositRefunded(dest_id, nonce));
         Ok(())
     }
+
+    /// Returns the deposit records to a chain, for up to `MAX_DEPOSIT_QUERY` nonces starting at `start`
+    pub fn deposits_by_nonce(
+        dest_id: ChainId,
+        start: DepositNonce,
+        count: u32,
+    ) -> Vec<(DepositNonce, DepositRecord<T::BlockNumber>)> {
+        let end = start.saturating_add(count.min(MAX_DEPOSIT_QUERY) as DepositNonce);
+        (start..end)
+            .filter_map(|nonce| Self::deposits(dest_id, nonce).map(|record| (nonce, record)))
+            .collect()
+    }
+
+    /// Returns the deposit records made from block `from` up to block `to` included,
+    /// covering at most `MAX_DEPOSIT_QUERY` blocks
+    pub fn deposits_by_block(
+        from: T::BlockNumber,
+        to: T::BlockNumber,
+    ) -> Vec<(ChainId, DepositNonce, DepositRecord<T::BlockNumber>)> {
+        let mut records = Vec::new();
+        let mut block = from;
+        let mut blocks = 0;
+        while block <= to && blocks < MAX_DEPOSIT_QUERY {
+            for (dest_id, nonce) in Self::block_deposits(block) {
+                if let Some(record) = Self::deposits(dest_id, nonce) {
+                    records.push((dest_id, nonce, record));
+                }
+            }
+            block = block.saturating_add(One::one());
+            blocks += 1;
+        }
+        records
+    }
 }
 
 /// Simple ensure origin for the bridge account
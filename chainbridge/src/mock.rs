@@ -75,6 +75,12 @@ const PALLET_ID: PalletId = PalletId(*b"cb/bridg");
 parameter_types! {
     pub const TestChainId: u8 = 5;
     pub const ProposalLifetime: u64 = 50;
+    pub const ProposalRetention: u64 = 100;
+    pub const MaxRelayers: u32 = 16;
+    pub const RateLimitWindow: u64 = 100;
+    pub const MaxDepositDataLength: u32 = 256;
+    pub const MaxMissedVotes: u32 = 2;
+    pub static RelayerBond: u64 = 0;
     pub const ChainbridgePalletId: PalletId = PALLET_ID;
 }
 
@@ -84,6 +90,15 @@ impl Config for Test {
     type Proposal = RuntimeCall;
     type ChainId = TestChainId;
     type ProposalLifetime = ProposalLifetime;
+    type ProposalRetention = ProposalRetention;
+    type MaxRelayers = MaxRelayers;
+    type RateLimitWindow = RateLimitWindow;
+    type MaxDepositDataLength = MaxDepositDataLength;
+    type Currency = Balances;
+    type RelayerBond = RelayerBond;
+    type MaxMissedVotes = MaxMissedVotes;
+    type Slashed = ();
+    type WeightInfo = ();
     type PalletId = ChainbridgePalletId;
 }
 
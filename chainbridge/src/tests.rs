@@ -1,12 +1,12 @@
 #![cfg(test)]
 
 use super::mock::{
-    assert_events, new_test_ext, Balances, Bridge, RuntimeCall, RuntimeEvent, RuntimeOrigin, ProposalLifetime, System,
-    Test, TestChainId, ENDOWED_BALANCE, RELAYER_A, RELAYER_B, RELAYER_C, TEST_THRESHOLD,
+    assert_events, new_test_ext, Balances, Bridge, RuntimeCall, RuntimeEvent, RuntimeOrigin, ProposalLifetime,
+    MaxDepositDataLength, MaxMissedVotes, ProposalRetention, RateLimitWindow, RelayerBond, System, Test, TestChainId, ENDOWED_BALANCE, RELAYER_A, RELAYER_B, RELAYER_C, TEST_THRESHOLD,
 };
 use super::*;
 use crate::mock::new_test_ext_initialized;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::OnIdle};
 
 #[test]
 fn derive_ids() {
@@ -108,13 +108,13 @@ fn whitelist_chain() {
 #[test]
 fn set_get_threshold() {
     new_test_ext().execute_with(|| {
-        assert_eq!(<RelayerThreshold>::get(), 1);
+        assert_eq!(Bridge::relayer_threshold(), 1);
 
         assert_ok!(Bridge::set_threshold(RuntimeOrigin::root(), TEST_THRESHOLD));
-        assert_eq!(<RelayerThreshold>::get(), TEST_THRESHOLD);
+        assert_eq!(Bridge::relayer_threshold(), TEST_THRESHOLD);
 
         assert_ok!(Bridge::set_threshold(RuntimeOrigin::root(), 5));
-        assert_eq!(<RelayerThreshold>::get(), 5);
+        assert_eq!(Bridge::relayer_threshold(), 5);
 
         assert_events(vec![
             RuntimeEvent::Bridge(RawEvent::RelayerThresholdChanged(TEST_THRESHOLD)),
@@ -212,6 +212,97 @@ fn asset_transfer_invalid_chain() {
     })
 }
 
+#[test]
+fn asset_transfer_stores_deposit_records() {
+    new_test_ext().execute_with(|| {
+        let dest_id = 2;
+        let resource_id = [1; 32];
+        let to = vec![2];
+
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), dest_id));
+        System::set_block_number(10);
+        assert_ok!(Bridge::transfer_fungible(dest_id, resource_id, to.clone(), 100.into()));
+        assert_ok!(Bridge::transfer_generic(dest_id, resource_id, vec![3]));
+        System::set_block_number(12);
+        assert_ok!(Bridge::transfer_nonfungible(dest_id, resource_id, vec![1], to.clone(), vec![]));
+
+        let fungible = DepositRecord {
+            resource_id,
+            data: DepositData::Fungible { amount: 100.into(), recipient: to.clone() },
+            block: 10,
+            refunded: false,
+        };
+        let generic = DepositRecord {
+            resource_id,
+            data: DepositData::Generic { metadata: vec![3] },
+            block: 10,
+            refunded: false,
+        };
+        let nonfungible = DepositRecord {
+            resource_id,
+            data: DepositData::NonFungible { token_id: vec![1], recipient: to, metadata: vec![] },
+            block: 12,
+            refunded: false,
+        };
+        assert_eq!(Bridge::deposits(dest_id, 1), Some(fungible.clone()));
+        assert_eq!(Bridge::block_deposits(10), vec![(dest_id, 1), (dest_id, 2)]);
+
+        // Query by nonce, skipping nonces that weren't used
+        assert_eq!(
+            Bridge::deposits_by_nonce(dest_id, 2, 10),
+            vec![(2, generic.clone()), (3, nonfungible.clone())]
+        );
+        assert_eq!(Bridge::deposits_by_nonce(dest_id, 1, 1), vec![(1, fungible.clone())]);
+
+        // Query by block range, including both ends
+        assert_eq!(
+            Bridge::deposits_by_block(10, 12),
+            vec![(dest_id, 1, fungible), (dest_id, 2, generic), (dest_id, 3, nonfungible.clone())]
+        );
+        assert_eq!(Bridge::deposits_by_block(11, 20), vec![(dest_id, 3, nonfungible)]);
+        assert_eq!(Bridge::deposits_by_block(13, 20), vec![]);
+    })
+}
+
+#[test]
+fn asset_transfer_deposit_data_is_bounded() {
+    new_test_ext().execute_with(|| {
+        let dest_id = 2;
+        let resource_id = [1; 32];
+        let max = MaxDepositDataLength::get() as usize;
+
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), dest_id));
+        assert_noop!(
+            Bridge::transfer_fungible(dest_id, resource_id, vec![0; max + 1], 100.into()),
+            Error::<Test>::DepositDataTooLong
+        );
+        assert_noop!(
+            Bridge::transfer_nonfungible(dest_id, resource_id, vec![0; max / 2], vec![0; max / 2], vec![0]),
+            Error::<Test>::DepositDataTooLong
+        );
+        assert_ok!(Bridge::transfer_generic(dest_id, resource_id, vec![0; max]));
+        assert_eq!(Bridge::chains(dest_id), Some(1));
+    })
+}
+
+#[test]
+fn refunded_deposit_is_marked_once() {
+    new_test_ext().execute_with(|| {
+        let dest_id = 2;
+        let resource_id = [1; 32];
+
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), dest_id));
+        assert_ok!(Bridge::transfer_fungible(dest_id, resource_id, vec![2], 100.into()), 1);
+        assert_noop!(Bridge::mark_refunded(dest_id, 2), Error::<Test>::DepositDoesNotExist);
+
+        assert_ok!(Bridge::mark_refunded(dest_id, 1));
+        assert!(Bridge::deposits(dest_id, 1).unwrap().refunded);
+        assert_events(vec![RuntimeEvent::Bridge(RawEvent::DepositRefunded(dest_id, 1))]);
+
+        assert_noop!(Bridge::mark_refunded(dest_id, 1), Error::<Test>::DepositAlreadyRefunded);
+    })
+}
+
 #[test]
 fn add_remove_relayer() {
     new_test_ext().execute_with(|| {
@@ -247,6 +338,25 @@ fn add_remove_relayer() {
     })
 }
 
+#[test]
+fn relayer_set_is_bounded() {
+    new_test_ext().execute_with(|| {
+        let max = <Test as Config>::MaxRelayers::get() as u64;
+        for relayer in 0..max {
+            assert_ok!(Bridge::add_relayer(RuntimeOrigin::root(), relayer));
+        }
+        assert_noop!(
+            Bridge::add_relayer(RuntimeOrigin::root(), max),
+            Error::<Test>::TooManyRelayers
+        );
+
+        // Relayer can be added after another is removed
+        assert_ok!(Bridge::remove_relayer(RuntimeOrigin::root(), 0));
+        assert_ok!(Bridge::add_relayer(RuntimeOrigin::root(), max));
+        assert_eq!(Bridge::relayer_count(), max as u32);
+    })
+}
+
 fn make_proposal(r: Vec<u8>) -> mock::RuntimeCall {
     RuntimeCall::System(system::Call::remark { remark: r })
 }
@@ -532,3 +642,649 @@ fn proposal_expires() {
         ))]);
     })
 }
+
+#[test]
+fn expired_proposal_is_pruned() {
+    let src_id = 1;
+    let r_id = derive_resource_id(src_id, b"remark");
+
+    new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+        let prop_id = 1;
+        let proposal = make_proposal(vec![10]);
+
+        assert_ok!(Bridge::acknowledge_proposal(
+            RuntimeOrigin::signed(RELAYER_A),
+            prop_id,
+            src_id,
+            r_id,
+            Box::new(proposal.clone())
+        ));
+
+        // Proposal is kept until it expires
+        Bridge::on_idle(ProposalLifetime::get(), Weight::MAX);
+        assert!(Bridge::votes(src_id, (prop_id, proposal.clone())).is_some());
+
+        Bridge::on_idle(ProposalLifetime::get() + 1, Weight::MAX);
+        assert_eq!(Bridge::votes(src_id, (prop_id, proposal.clone())), None);
+        assert_eq!(Bridge::prune_cursor(), None);
+
+        assert_events(vec![
+            RuntimeEvent::Bridge(RawEvent::VoteFor(src_id, prop_id, RELAYER_A)),
+            RuntimeEvent::Bridge(RawEvent::ProposalExpired(src_id, prop_id)),
+        ]);
+    })
+}
+
+#[test]
+fn completed_proposal_is_pruned_after_retention() {
+    let src_id = 1;
+    let r_id = derive_resource_id(src_id, b"remark");
+
+    new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+        let prop_id = 1;
+        let proposal = make_proposal(vec![10]);
+
+        assert_ok!(Bridge::acknowledge_proposal(
+            RuntimeOrigin::signed(RELAYER_A),
+            prop_id,
+            src_id,
+            r_id,
+            Box::new(proposal.clone())
+        ));
+        assert_ok!(Bridge::acknowledge_proposal(
+            RuntimeOrigin::signed(RELAYER_B),
+            prop_id,
+            src_id,
+            r_id,
+            Box::new(proposal.clone())
+        ));
+        let expiry = ProposalLifetime::get() + 1;
+
+        // Completed proposal is kept after expiry, so late votes are still rejected
+        Bridge::on_idle(expiry + ProposalRetention::get() - 1, Weight::MAX);
+        assert!(Bridge::votes(src_id, (prop_id, proposal.clone())).is_some());
+        assert_noop!(
+            Bridge::acknowledge_proposal(
+                RuntimeOrigin::signed(RELAYER_C),
+                prop_id,
+                src_id,
+                r_id,
+                Box::new(proposal.clone())
+            ),
+            Error::<Test>::ProposalAlreadyComplete
+        );
+
+        Bridge::on_idle(expiry + ProposalRetention::get(), Weight::MAX);
+        assert_eq!(Bridge::votes(src_id, (prop_id, proposal.clone())), None);
+
+        // No expiry event for completed proposals
+        assert_events(vec![
+            RuntimeEvent::Bridge(RawEvent::ProposalApproved(src_id, prop_id)),
+            RuntimeEvent::Bridge(RawEvent::ProposalSucceeded(src_id, prop_id)),
+        ]);
+    })
+}
+
+#[test]
+fn pruned_executed_proposal_cannot_be_voted_again() {
+    let src_id = 1;
+    let r_id = derive_resource_id(src_id, b"remark");
+
+    new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+        let prop_id = 1;
+        let proposal = make_proposal(vec![10]);
+
+        assert_ok!(Bridge::acknowledge_proposal(
+            RuntimeOrigin::signed(RELAYER_A),
+            prop_id,
+            src_id,
+            r_id,
+            Box::new(proposal.clone())
+        ));
+        assert_ok!(Bridge::acknowledge_proposal(
+            RuntimeOrigin::signed(RELAYER_B),
+            prop_id,
+            src_id,
+            r_id,
+            Box::new(proposal.clone())
+        ));
+        let expiry = ProposalLifetime::get() + 1;
+
+        Bridge::on_idle(expiry + ProposalRetention::get(), Weight::MAX);
+        assert_eq!(Bridge::votes(src_id, (prop_id, proposal.clone())), None);
+        assert_eq!(Bridge::pruned_nonce(src_id), Some(prop_id));
+
+        // Voting again on the pruned proposal doesn't create a new one
+        System::set_block_number(expiry + ProposalRetention::get());
+        assert_noop!(
+            Bridge::acknowledge_proposal(
+                RuntimeOrigin::signed(RELAYER_A),
+                prop_id,
+                src_id,
+                r_id,
+                Box::new(proposal.clone())
+            ),
+            Error::<Test>::ProposalAlreadyComplete
+        );
+        // Neither does a different call for the same deposit
+        assert_noop!(
+            Bridge::reject_proposal(
+                RuntimeOrigin::signed(RELAYER_A),
+                prop_id,
+                src_id,
+                r_id,
+                Box::new(make_proposal(vec![11]))
+            ),
+            Error::<Test>::ProposalAlreadyComplete
+        );
+
+        // Other deposits from the same chain are not affected
+        assert_ok!(Bridge::acknowledge_proposal(
+            RuntimeOrigin::signed(RELAYER_A),
+            prop_id + 1,
+            src_id,
+            r_id,
+            Box::new(proposal.clone())
+        ));
+    })
+}
+
+#[test]
+fn pruned_rejected_proposal_cannot_be_voted_again() {
+    let src_id = 1;
+    let r_id = derive_resource_id(src_id, b"remark");
+
+    new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+        let prop_id = 1;
+        let proposal = make_proposal(vec![10]);
+
+        assert_ok!(Bridge::reject_proposal(
+            RuntimeOrigin::signed(RELAYER_A),
+            prop_id,
+            src_id,
+            r_id,
+            Box::new(proposal.clone())
+        ));
+        assert_ok!(Bridge::reject_proposal(
+            RuntimeOrigin::signed(RELAYER_B),
+            prop_id,
+            src_id,
+            r_id,
+            Box::new(proposal.clone())
+        ));
+        let expiry = ProposalLifetime::get() + 1;
+
+        Bridge::on_idle(expiry + ProposalRetention::get(), Weight::MAX);
+        assert_eq!(Bridge::votes(src_id, (prop_id, proposal.clone())), None);
+        assert_eq!(Bridge::pruned_nonce(src_id), Some(prop_id));
+
+        System::set_block_number(expiry + ProposalRetention::get());
+        assert_noop!(
+            Bridge::acknowledge_proposal(
+                RuntimeOrigin::signed(RELAYER_A),
+                prop_id,
+                src_id,
+                r_id,
+                Box::new(proposal.clone())
+            ),
+            Error::<Test>::ProposalAlreadyComplete
+        );
+    })
+}
+
+#[test]
+fn pruned_expired_proposal_cannot_be_voted_again() {
+    let src_id = 1;
+    let r_id = derive_resource_id(src_id, b"remark");
+
+    new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+        let prop_id = 1;
+        let proposal = make_proposal(vec![10]);
+
+        assert_ok!(Bridge::acknowledge_proposal(
+            RuntimeOrigin::signed(RELAYER_A),
+            prop_id,
+            src_id,
+            r_id,
+            Box::new(proposal.clone())
+        ));
+        let expiry = ProposalLifetime::get() + 1;
+        Bridge::on_idle(expiry, Weight::MAX);
+        assert_eq!(Bridge::votes(src_id, (prop_id, proposal.clone())), None);
+        assert_eq!(Bridge::pruned_nonce(src_id), Some(prop_id));
+
+        // Expired deposit can't be proposed again, so it can't be executed
+        System::set_block_number(expiry);
+        assert_noop!(
+            Bridge::acknowledge_proposal(
+                RuntimeOrigin::signed(RELAYER_B),
+                prop_id,
+                src_id,
+                r_id,
+                Box::new(proposal.clone())
+            ),
+            Error::<Test>::ProposalAlreadyComplete
+        );
+    })
+}
+
+#[test]
+fn pending_proposals_below_pruned_nonce_can_still_be_voted() {
+    let src_id = 1;
+    let r_id = derive_resource_id(src_id, b"remark");
+
+    new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+        let proposal = make_proposal(vec![10]);
+
+        // Proposal 2 is created first and expires before proposal 1
+        assert_ok!(Bridge::acknowledge_proposal(
+            RuntimeOrigin::signed(RELAYER_A),
+            2,
+            src_id,
+            r_id,
+            Box::new(proposal.clone())
+        ));
+        System::set_block_number(2);
+        assert_ok!(Bridge::acknowledge_proposal(
+            RuntimeOrigin::signed(RELAYER_A),
+            1,
+            src_id,
+            r_id,
+            Box::new(proposal.clone())
+        ));
+        let expiry = ProposalLifetime::get() + 1;
+        Bridge::on_idle(expiry, Weight::MAX);
+        assert_eq!(Bridge::pruned_nonce(src_id), Some(2));
+
+        // Proposal 1 isn't pruned yet, so it can still be resolved
+        System::set_block_number(expiry);
+        assert_ok!(Bridge::acknowledge_proposal(
+            RuntimeOrigin::signed(RELAYER_B),
+            1,
+            src_id,
+            r_id,
+            Box::new(proposal.clone())
+        ));
+        assert_eq!(Bridge::votes(src_id, (1, proposal)).unwrap().status, ProposalStatus::Approved);
+    })
+}
+
+#[test]
+fn pruning_is_bounded_by_weight() {
+    let src_id = 1;
+    let r_id = derive_resource_id(src_id, b"remark");
+
+    new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+        for prop_id in 1..=3 {
+            assert_ok!(Bridge::acknowledge_proposal(
+                RuntimeOrigin::signed(RELAYER_A),
+                prop_id,
+                src_id,
+                r_id,
+                Box::new(make_proposal(vec![10]))
+            ));
+        }
+        let expiry = ProposalLifetime::get() + 1;
+
+        // Only enough weight to check a single proposal each time
+        let weight = <Test as Config>::WeightInfo::prune_proposals(1);
+        for remaining in (0..3).rev() {
+            Bridge::on_idle(expiry, weight);
+            assert_eq!(Votes::<Test>::iter().count(), remaining);
+            assert!(Bridge::prune_cursor().is_some());
+        }
+
+        // Cursor is reset once all proposals are checked
+        Bridge::on_idle(expiry, weight);
+        assert_eq!(Bridge::prune_cursor(), None);
+    })
+}
+
+#[test]
+fn paused_bridge_rejects_proposals_and_transfers() {
+    let src_id = 1;
+    let r_id = derive_resource_id(src_id, b"remark");
+
+    new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+        let proposal = make_proposal(vec![10]);
+
+        assert_ok!(Bridge::set_bridge_paused(RuntimeOrigin::root(), true));
+        assert_noop!(
+            Bridge::acknowledge_proposal(RuntimeOrigin::signed(RELAYER_A), 1, src_id, r_id, Box::new(proposal.clone())),
+            Error::<Test>::BridgePaused
+        );
+        assert_noop!(
+            Bridge::transfer_generic(src_id, r_id, vec![]),
+            Error::<Test>::BridgePaused
+        );
+        assert_ok!(Bridge::set_bridge_paused(RuntimeOrigin::root(), false));
+
+        // Pausing a single chain
+        assert_ok!(Bridge::set_chain_paused(RuntimeOrigin::root(), src_id, true));
+        assert_noop!(
+            Bridge::reject_proposal(RuntimeOrigin::signed(RELAYER_A), 1, src_id, r_id, Box::new(proposal.clone())),
+            Error::<Test>::ChainPaused
+        );
+        assert_ok!(Bridge::set_chain_paused(RuntimeOrigin::root(), src_id, false));
+        assert_ok!(Bridge::acknowledge_proposal(RuntimeOrigin::signed(RELAYER_A), 1, src_id, r_id, Box::new(proposal)));
+
+        assert_events(vec![
+            RuntimeEvent::Bridge(RawEvent::BridgePaused),
+            RuntimeEvent::Bridge(RawEvent::BridgeResumed),
+            RuntimeEvent::Bridge(RawEvent::ChainPaused(src_id)),
+            RuntimeEvent::Bridge(RawEvent::ChainResumed(src_id)),
+            RuntimeEvent::Bridge(RawEvent::VoteFor(src_id, 1, RELAYER_A)),
+        ]);
+    })
+}
+
+#[test]
+fn rate_limit_volume_is_released_over_window() {
+    new_test_ext().execute_with(|| {
+        let chain = LimitTarget::Chain(1);
+        let resource = LimitTarget::Resource(derive_resource_id(1, b"xx"));
+        let targets = [chain, resource];
+        assert_ok!(Bridge::set_rate_limit(
+            RuntimeOrigin::root(),
+            Direction::Inbound,
+            chain,
+            Some(RateLimit { max_transfer: 60.into(), max_volume: 100.into() }),
+        ));
+
+        // Over the maximum transfer
+        assert!(!Bridge::consume_limits(Direction::Inbound, &targets, 61.into()));
+        // Within limits, only limited targets are tracked
+        assert!(Bridge::consume_limits(Direction::Inbound, &targets, 60.into()));
+        assert_eq!(Bridge::volumes(Direction::Inbound, chain), (1, 60.into()));
+        assert_eq!(Bridge::volumes(Direction::Inbound, resource), (0, 0.into()));
+        // Over the window volume, nothing is counted
+        assert!(!Bridge::consume_limits(Direction::Inbound, &targets, 50.into()));
+        assert_eq!(Bridge::volumes(Direction::Inbound, chain), (1, 60.into()));
+        // Other direction isn't limited
+        assert!(Bridge::consume_limits(Direction::Outbound, &targets, 1000.into()));
+
+        // Volume is released linearly over the window
+        System::set_block_number(1 + RateLimitWindow::get() / 10);
+        assert!(Bridge::consume_limits(Direction::Inbound, &targets, 50.into()));
+        assert_eq!(Bridge::volumes(Direction::Inbound, chain), (11, 100.into()));
+
+        System::set_block_number(11 + RateLimitWindow::get());
+        assert!(Bridge::within_limits(Direction::Inbound, &targets, 60.into()));
+    })
+}
+
+const RELAYER_BOND: u64 = 10;
+
+// Bridge with bonded relayers, each with `RELAYER_BOND` reserved out of 100
+fn new_test_ext_bonded(src_id: ChainId, r_id: ResourceId) -> sp_io::TestExternalities {
+    RelayerBond::set(RELAYER_BOND);
+    let mut t = new_test_ext();
+    t.execute_with(|| {
+        assert_ok!(Bridge::set_threshold(RuntimeOrigin::root(), TEST_THRESHOLD));
+        for relayer in [RELAYER_A, RELAYER_B, RELAYER_C] {
+            Balances::make_free_balance_be(&relayer, 100);
+            assert_ok!(Bridge::add_relayer(RuntimeOrigin::root(), relayer));
+        }
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), src_id));
+        assert_ok!(Bridge::set_resource(RuntimeOrigin::root(), r_id, b"System.remark".to_vec()));
+    });
+    t
+}
+
+#[test]
+fn relayer_bond_is_reserved_and_returned() {
+    let src_id = 1;
+    let r_id = derive_resource_id(src_id, b"remark");
+
+    new_test_ext_bonded(src_id, r_id).execute_with(|| {
+        assert_eq!(Balances::reserved_balance(RELAYER_A), RELAYER_BOND);
+        assert_eq!(Bridge::relayer_bond(RELAYER_A), RELAYER_BOND);
+
+        // Relayer must be able to pay the bond
+        let poor = 0x10;
+        assert_noop!(
+            Bridge::add_relayer(RuntimeOrigin::root(), poor),
+            pallet_balances::Error::<Test>::InsufficientBalance
+        );
+
+        assert_ok!(Bridge::remove_relayer(RuntimeOrigin::root(), RELAYER_A));
+        assert_eq!(Balances::reserved_balance(RELAYER_A), 0);
+        assert_eq!(Balances::free_balance(RELAYER_A), 100);
+        assert_eq!(Bridge::relayer_bond(RELAYER_A), 0);
+    })
+}
+
+#[test]
+fn relayer_participation_is_tracked() {
+    let src_id = 1;
+    let r_id = derive_resource_id(src_id, b"remark");
+
+    new_test_ext_bonded(src_id, r_id).execute_with(|| {
+        // A and B approve the first proposal, C misses it
+        let first = make_proposal(vec![10]);
+        assert_ok!(Bridge::acknowledge_proposal(RuntimeOrigin::signed(RELAYER_A), 1, src_id, r_id, Box::new(first.clone())));
+        assert_ok!(Bridge::acknowledge_proposal(RuntimeOrigin::signed(RELAYER_B), 1, src_id, r_id, Box::new(first)));
+
+        // B and C reject the second proposal, A votes against the outcome
+        let second = make_proposal(vec![11]);
+        assert_ok!(Bridge::acknowledge_proposal(RuntimeOrigin::signed(RELAYER_A), 2, src_id, r_id, Box::new(second.clone())));
+        assert_ok!(Bridge::reject_proposal(RuntimeOrigin::signed(RELAYER_B), 2, src_id, r_id, Box::new(second.clone())));
+        assert_ok!(Bridge::reject_proposal(RuntimeOrigin::signed(RELAYER_C), 2, src_id, r_id, Box::new(second)));
+
+        // Only A votes on the third proposal, which is left to expire
+        let third = make_proposal(vec![12]);
+        assert_ok!(Bridge::acknowledge_proposal(RuntimeOrigin::signed(RELAYER_A), 3, src_id, r_id, Box::new(third)));
+
+        // C didn't vote on the first proposal, but it was approved before C could, so it isn't missed
+        assert_eq!(Bridge::resolved_proposals(RelayerSet::Default), 2);
+        assert_eq!(
+            Bridge::relayer_stats(&RELAYER_C),
+            Some(RelayerStats { set: RelayerSet::Default, bond: RELAYER_BOND, votes: 1, minority_votes: 0, missed_votes: 0 })
+        );
+
+        // Expired proposals are counted once pruned
+        let expiry = ProposalLifetime::get() + 1;
+        Bridge::on_idle(expiry, Weight::MAX);
+        assert_eq!(Bridge::expired_proposals(RelayerSet::Default), 1);
+        assert_eq!(
+            Bridge::relayer_stats(&RELAYER_A),
+            Some(RelayerStats { set: RelayerSet::Default, bond: RELAYER_BOND, votes: 2, minority_votes: 1, missed_votes: 0 })
+        );
+        assert_eq!(
+            Bridge::relayer_stats(&RELAYER_B),
+            Some(RelayerStats { set: RelayerSet::Default, bond: RELAYER_BOND, votes: 2, minority_votes: 0, missed_votes: 1 })
+        );
+        assert_eq!(
+            Bridge::relayer_stats(&RELAYER_C),
+            Some(RelayerStats { set: RelayerSet::Default, bond: RELAYER_BOND, votes: 1, minority_votes: 0, missed_votes: 1 })
+        );
+
+        // Relayers added later only miss proposals that expired after they joined
+        let late = 0x10;
+        Balances::make_free_balance_be(&late, 100);
+        assert_ok!(Bridge::add_relayer(RuntimeOrigin::root(), late));
+        assert_eq!(Bridge::missed_votes(&late), 0);
+        assert_eq!(Bridge::relayer_stats(&0x11), None);
+    })
+}
+
+#[test]
+fn voters_of_fraudulent_proposal_are_slashed() {
+    let src_id = 1;
+    let r_id = derive_resource_id(src_id, b"remark");
+
+    new_test_ext_bonded(src_id, r_id).execute_with(|| {
+        let proposal = make_proposal(vec![10]);
+        assert_ok!(Bridge::acknowledge_proposal(RuntimeOrigin::signed(RELAYER_A), 1, src_id, r_id, Box::new(proposal.clone())));
+
+        // Only approved proposals can be reported
+        assert_noop!(
+            Bridge::slash_voters(RuntimeOrigin::root(), 1, src_id, Box::new(proposal.clone())),
+            Error::<Test>::ProposalNotApproved
+        );
+        assert_noop!(
+            Bridge::slash_voters(RuntimeOrigin::root(), 2, src_id, Box::new(proposal.clone())),
+            Error::<Test>::ProposalDoesNotExist
+        );
+
+        assert_ok!(Bridge::reject_proposal(RuntimeOrigin::signed(RELAYER_C), 1, src_id, r_id, Box::new(proposal.clone())));
+        assert_ok!(Bridge::acknowledge_proposal(RuntimeOrigin::signed(RELAYER_B), 1, src_id, r_id, Box::new(proposal.clone())));
+        assert_ok!(Bridge::slash_voters(RuntimeOrigin::root(), 1, src_id, Box::new(proposal)));
+
+        for relayer in [RELAYER_A, RELAYER_B] {
+            assert!(!Bridge::is_relayer(&relayer));
+            assert_eq!(Balances::reserved_balance(relayer), 0);
+            assert_eq!(Balances::free_balance(relayer), 100 - RELAYER_BOND);
+        }
+        assert!(Bridge::is_relayer(&RELAYER_C));
+        assert_eq!(Balances::reserved_balance(RELAYER_C), RELAYER_BOND);
+        assert_eq!(Bridge::relayer_count(), 1);
+
+        System::assert_has_event(RuntimeEvent::Bridge(RawEvent::RelayerSlashed(RELAYER_A, RELAYER_BOND)));
+        System::assert_has_event(RuntimeEvent::Bridge(RawEvent::RelayerSlashed(RELAYER_B, RELAYER_BOND)));
+    })
+}
+
+#[test]
+fn inactive_relayer_is_slashed() {
+    let src_id = 1;
+    let r_id = derive_resource_id(src_id, b"remark");
+
+    new_test_ext_bonded(src_id, r_id).execute_with(|| {
+        // Proposals approved without C don't make it inactive
+        for nonce in 1..=MaxMissedVotes::get() as u64 {
+            let proposal = make_proposal(vec![nonce as u8]);
+            assert_ok!(Bridge::acknowledge_proposal(RuntimeOrigin::signed(RELAYER_A), nonce, src_id, r_id, Box::new(proposal.clone())));
+            assert_ok!(Bridge::acknowledge_proposal(RuntimeOrigin::signed(RELAYER_B), nonce, src_id, r_id, Box::new(proposal.clone())));
+        }
+        assert_noop!(
+            Bridge::slash_inactive_relayer(RuntimeOrigin::root(), RELAYER_C),
+            Error::<Test>::NotEnoughMissedVotes
+        );
+
+        // Proposals with a vote from A and B each expire, and C misses them
+        let first = MaxMissedVotes::get() as u64 + 1;
+        for nonce in first..first + MaxMissedVotes::get() as u64 {
+            let proposal = make_proposal(vec![nonce as u8]);
+            assert_ok!(Bridge::acknowledge_proposal(RuntimeOrigin::signed(RELAYER_A), nonce, src_id, r_id, Box::new(proposal.clone())));
+            assert_ok!(Bridge::reject_proposal(RuntimeOrigin::signed(RELAYER_B), nonce, src_id, r_id, Box::new(proposal)));
+        }
+        assert_noop!(
+            Bridge::slash_inactive_relayer(RuntimeOrigin::root(), RELAYER_C),
+            Error::<Test>::NotEnoughMissedVotes
+        );
+        let expiry = ProposalLifetime::get() + 1;
+        Bridge::on_idle(expiry, Weight::MAX);
+        assert_eq!(Bridge::missed_votes(&RELAYER_C), MaxMissedVotes::get());
+
+        // A and B voted on the expired proposals
+        assert_noop!(
+            Bridge::slash_inactive_relayer(RuntimeOrigin::root(), RELAYER_A),
+            Error::<Test>::NotEnoughMissedVotes
+        );
+        assert_noop!(
+            Bridge::slash_inactive_relayer(RuntimeOrigin::root(), RELAYER_B),
+            Error::<Test>::NotEnoughMissedVotes
+        );
+        assert_ok!(Bridge::slash_inactive_relayer(RuntimeOrigin::root(), RELAYER_C));
+        assert!(!Bridge::is_relayer(&RELAYER_C));
+        assert_eq!(Balances::total_balance(&RELAYER_C), 100 - RELAYER_BOND);
+        assert_eq!(Bridge::participation(RELAYER_C), RelayerParticipation::default());
+        assert_noop!(
+            Bridge::slash_inactive_relayer(RuntimeOrigin::root(), RELAYER_C),
+            Error::<Test>::RelayerInvalid
+        );
+    })
+}
+
+#[test]
+fn chain_relayer_set_votes_on_its_chain() {
+    let src_id = 1;
+    let chain_id = 2;
+    let chain_relayer = 0x10;
+    let r_id = derive_resource_id(src_id, b"remark");
+
+    new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+        // Chain relayer sets require a whitelisted chain, and exist before relayers are added
+        assert_noop!(
+            Bridge::set_chain_threshold(RuntimeOrigin::root(), chain_id, Some(1)),
+            Error::<Test>::ChainNotWhitelisted
+        );
+        assert_noop!(
+            Bridge::add_chain_relayer(RuntimeOrigin::root(), chain_id, chain_relayer),
+            Error::<Test>::RelayerSetDoesNotExist
+        );
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), chain_id));
+        assert_noop!(
+            Bridge::set_chain_threshold(RuntimeOrigin::root(), chain_id, Some(0)),
+            Error::<Test>::InvalidThreshold
+        );
+        assert_ok!(Bridge::set_chain_threshold(RuntimeOrigin::root(), chain_id, Some(1)));
+        assert_ok!(Bridge::add_chain_relayer(RuntimeOrigin::root(), chain_id, chain_relayer));
+        assert_noop!(
+            Bridge::add_chain_relayer(RuntimeOrigin::root(), chain_id, RELAYER_A),
+            Error::<Test>::RelayerAlreadyExists
+        );
+        assert_events(vec![
+            RuntimeEvent::Bridge(RawEvent::ChainRelayerThresholdChanged(chain_id, Some(1))),
+            RuntimeEvent::Bridge(RawEvent::ChainRelayerAdded(chain_id, chain_relayer)),
+        ]);
+
+        // Each set only votes on the proposals of its chains
+        let proposal = make_proposal(vec![10]);
+        assert_noop!(
+            Bridge::acknowledge_proposal(RuntimeOrigin::signed(RELAYER_A), 1, chain_id, r_id, Box::new(proposal.clone())),
+            Error::<Test>::MustBeRelayer
+        );
+        assert_noop!(
+            Bridge::acknowledge_proposal(RuntimeOrigin::signed(chain_relayer), 1, src_id, r_id, Box::new(proposal.clone())),
+            Error::<Test>::MustBeRelayer
+        );
+
+        // The chain threshold applies, while the default set is unchanged
+        assert_ok!(Bridge::acknowledge_proposal(RuntimeOrigin::signed(chain_relayer), 1, chain_id, r_id, Box::new(proposal.clone())));
+        let prop = Bridge::votes(chain_id, (1, proposal.clone())).unwrap();
+        assert_eq!(prop.status, ProposalStatus::Approved);
+        assert_eq!(Bridge::resolved_proposals(RelayerSet::Chain(chain_id)), 1);
+        assert_eq!(Bridge::resolved_proposals(RelayerSet::Default), 0);
+        assert_eq!(Bridge::relayer_threshold(), TEST_THRESHOLD);
+        assert_eq!(Bridge::relayer_count(), 3);
+        assert_eq!(Bridge::relayer_stats(&chain_relayer).map(|stats| stats.set), Some(RelayerSet::Chain(chain_id)));
+    })
+}
+
+#[test]
+fn removed_chain_relayer_set_falls_back_to_default() {
+    let src_id = 1;
+    let chain_id = 2;
+    let chain_relayer = 0x10;
+    let r_id = derive_resource_id(src_id, b"remark");
+
+    new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+        assert_noop!(
+            Bridge::set_chain_threshold(RuntimeOrigin::root(), chain_id, None),
+            Error::<Test>::RelayerSetDoesNotExist
+        );
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), chain_id));
+        assert_ok!(Bridge::set_chain_threshold(RuntimeOrigin::root(), chain_id, Some(1)));
+        assert_ok!(Bridge::add_chain_relayer(RuntimeOrigin::root(), chain_id, chain_relayer));
+        assert_eq!(Bridge::relayer_set(chain_id), RelayerSet::Chain(chain_id));
+
+        // Only an empty set can be removed
+        assert_noop!(
+            Bridge::set_chain_threshold(RuntimeOrigin::root(), chain_id, None),
+            Error::<Test>::RelayerSetNotEmpty
+        );
+        assert_ok!(Bridge::remove_relayer(RuntimeOrigin::root(), chain_relayer));
+        assert_eq!(Bridge::relayer_set_config(RelayerSet::Chain(chain_id)).relayer_count, 0);
+        assert_ok!(Bridge::set_chain_threshold(RuntimeOrigin::root(), chain_id, None));
+        assert_eq!(Bridge::relayer_set(chain_id), RelayerSet::Default);
+
+        // Default relayers vote on the chain again
+        let proposal = make_proposal(vec![10]);
+        assert_ok!(Bridge::acknowledge_proposal(RuntimeOrigin::signed(RELAYER_A), 1, chain_id, r_id, Box::new(proposal.clone())));
+        assert_ok!(Bridge::acknowledge_proposal(RuntimeOrigin::signed(RELAYER_B), 1, chain_id, r_id, Box::new(proposal.clone())));
+        let prop = Bridge::votes(chain_id, (1, proposal)).unwrap();
+        assert_eq!(prop.status, ProposalStatus::Approved);
+    })
+}
@@ -0,0 +1,415 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for chainbridge
+//!
+//! These weights were not generated by the benchmark CLI. Storage accesses are counted by hand
+//! from the worst cases of the benchmarks in `benchmarking.rs`. Execution time is derived from the
+//! generated weight of `swap::set_fee_destination`, 19.7 µs for a single write on 2021-09-13, with
+//! 1.5 µs added for each other storage access, as done for the swap weights.
+//!
+//! They should be replaced by the output of the command below once the benchmarks are run.
+
+// Command:
+// target/release/xxnetwork-chain
+// benchmark
+// --chain=xxnetwork-dev
+// --steps=50
+// --repeat=20
+// --pallet=chainbridge
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./chainbridge/src/weights.rs
+// --template=./scripts/frame-weight-template.hbs
+
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for chainbridge.
+pub trait WeightInfo {
+	fn set_threshold() -> Weight;
+	fn set_resource() -> Weight;
+	fn remove_resource() -> Weight;
+	fn whitelist_chain() -> Weight;
+	fn add_relayer() -> Weight;
+	fn remove_relayer() -> Weight;
+	fn acknowledge_proposal(v: u32, ) -> Weight;
+	fn reject_proposal(v: u32, ) -> Weight;
+	fn eval_vote_state(v: u32, ) -> Weight;
+	fn set_bridge_paused() -> Weight;
+	fn set_chain_paused() -> Weight;
+	fn set_rate_limit() -> Weight;
+	fn slash_voters(v: u32, ) -> Weight;
+	fn slash_inactive_relayer() -> Weight;
+	fn set_chain_threshold() -> Weight;
+	fn add_chain_relayer() -> Weight;
+	fn prune_proposals(n: u32, ) -> Weight;
+}
+
+/// Weights for chainbridge using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	fn set_threshold() -> Weight {
+		Weight::from_ref_time(21_158_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Resources (r:0 w:1)
+	fn set_resource() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Resources (r:0 w:1)
+	fn remove_resource() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge ChainNonces (r:1 w:1)
+	fn whitelist_chain() -> Weight {
+		Weight::from_ref_time(21_158_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Relayers (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ChainBridge ExpiredProposals (r:1 w:0)
+	// Storage: ChainBridge RelayerBonds (r:0 w:1)
+	// Storage: ChainBridge Participation (r:0 w:1)
+	fn add_relayer() -> Weight {
+		Weight::from_ref_time(31_658_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: ChainBridge Relayers (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	// Storage: ChainBridge RelayerBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ChainBridge Participation (r:0 w:1)
+	fn remove_relayer() -> Weight {
+		Weight::from_ref_time(31_658_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: ChainBridge Relayers (r:v w:0)
+	// Storage: ChainBridge RelayerSets (r:2 w:0)
+	// Storage: ChainBridge ChainNonces (r:1 w:0)
+	// Storage: ChainBridge Resources (r:1 w:0)
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: ChainBridge PrunedNonces (r:1 w:0)
+	// Storage: ChainBridge Votes (r:1 w:1)
+	// Storage: ChainBridge ResolvedProposals (r:1 w:1)
+	// Storage: ChainBridge Participation (r:v w:v)
+	// Storage: ChainBridge ProposalSource (r:0 w:1)
+	fn acknowledge_proposal(v: u32, ) -> Weight {
+		Weight::from_ref_time(36_158_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_500_000 as u64).saturating_mul(v as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(v as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(v as u64)))
+	}
+	// Storage: ChainBridge Relayers (r:v w:0)
+	// Storage: ChainBridge RelayerSets (r:2 w:0)
+	// Storage: ChainBridge ChainNonces (r:1 w:0)
+	// Storage: ChainBridge Resources (r:1 w:0)
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: ChainBridge PrunedNonces (r:1 w:0)
+	// Storage: ChainBridge Votes (r:1 w:1)
+	// Storage: ChainBridge ResolvedProposals (r:1 w:1)
+	// Storage: ChainBridge Participation (r:v w:v)
+	fn reject_proposal(v: u32, ) -> Weight {
+		Weight::from_ref_time(34_658_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_500_000 as u64).saturating_mul(v as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(v as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(v as u64)))
+	}
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: ChainBridge Votes (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:2 w:0)
+	// Storage: ChainBridge ResolvedProposals (r:1 w:1)
+	// Storage: ChainBridge Relayers (r:v w:0)
+	// Storage: ChainBridge Participation (r:v w:v)
+	// Storage: ChainBridge ProposalSource (r:0 w:1)
+	fn eval_vote_state(v: u32, ) -> Weight {
+		Weight::from_ref_time(31_658_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_500_000 as u64).saturating_mul(v as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(v as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(v as u64)))
+	}
+	// Storage: ChainBridge Paused (r:0 w:1)
+	fn set_bridge_paused() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge PausedChains (r:0 w:1)
+	fn set_chain_paused() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge RateLimits (r:0 w:1)
+	fn set_rate_limit() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Votes (r:1 w:0)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	// Storage: ChainBridge Relayers (r:v w:v)
+	// Storage: ChainBridge RelayerBonds (r:v w:v)
+	// Storage: System Account (r:v w:v)
+	// Storage: ChainBridge Participation (r:0 w:v)
+	fn slash_voters(v: u32, ) -> Weight {
+		Weight::from_ref_time(22_658_000 as u64)
+			.saturating_add(Weight::from_ref_time(10_500_000 as u64).saturating_mul(v as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(v as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(v as u64)))
+	}
+	// Storage: ChainBridge Relayers (r:1 w:1)
+	// Storage: ChainBridge Participation (r:1 w:1)
+	// Storage: ChainBridge ExpiredProposals (r:1 w:0)
+	// Storage: ChainBridge RelayerBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	fn slash_inactive_relayer() -> Weight {
+		Weight::from_ref_time(34_658_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: ChainBridge ChainNonces (r:1 w:0)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	fn set_chain_threshold() -> Weight {
+		Weight::from_ref_time(22_658_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	// Storage: ChainBridge Relayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ChainBridge ExpiredProposals (r:1 w:0)
+	// Storage: ChainBridge RelayerBonds (r:0 w:1)
+	// Storage: ChainBridge Participation (r:0 w:1)
+	fn add_chain_relayer() -> Weight {
+		Weight::from_ref_time(31_658_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: ChainBridge PruneCursor (r:1 w:1)
+	// Storage: ChainBridge Votes (r:n w:n)
+	// Storage: ChainBridge PrunedNonces (r:n w:n)
+	// Storage: ChainBridge RelayerSets (r:n w:0)
+	// Storage: ChainBridge ExpiredProposals (r:n w:n)
+	// Storage: ChainBridge Relayers (r:63n w:0)
+	// Storage: ChainBridge Participation (r:63n w:63n)
+	fn prune_proposals(n: u32, ) -> Weight {
+		Weight::from_ref_time(21_158_000 as u64)
+			.saturating_add(Weight::from_ref_time(294_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((130 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((66 as u64).saturating_mul(n as u64)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	fn set_threshold() -> Weight {
+		Weight::from_ref_time(21_158_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Resources (r:0 w:1)
+	fn set_resource() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Resources (r:0 w:1)
+	fn remove_resource() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge ChainNonces (r:1 w:1)
+	fn whitelist_chain() -> Weight {
+		Weight::from_ref_time(21_158_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Relayers (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ChainBridge ExpiredProposals (r:1 w:0)
+	// Storage: ChainBridge RelayerBonds (r:0 w:1)
+	// Storage: ChainBridge Participation (r:0 w:1)
+	fn add_relayer() -> Weight {
+		Weight::from_ref_time(31_658_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: ChainBridge Relayers (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	// Storage: ChainBridge RelayerBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ChainBridge Participation (r:0 w:1)
+	fn remove_relayer() -> Weight {
+		Weight::from_ref_time(31_658_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: ChainBridge Relayers (r:v w:0)
+	// Storage: ChainBridge RelayerSets (r:2 w:0)
+	// Storage: ChainBridge ChainNonces (r:1 w:0)
+	// Storage: ChainBridge Resources (r:1 w:0)
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: ChainBridge PrunedNonces (r:1 w:0)
+	// Storage: ChainBridge Votes (r:1 w:1)
+	// Storage: ChainBridge ResolvedProposals (r:1 w:1)
+	// Storage: ChainBridge Participation (r:v w:v)
+	// Storage: ChainBridge ProposalSource (r:0 w:1)
+	fn acknowledge_proposal(v: u32, ) -> Weight {
+		Weight::from_ref_time(36_158_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_500_000 as u64).saturating_mul(v as u64))
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(v as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(v as u64)))
+	}
+	// Storage: ChainBridge Relayers (r:v w:0)
+	// Storage: ChainBridge RelayerSets (r:2 w:0)
+	// Storage: ChainBridge ChainNonces (r:1 w:0)
+	// Storage: ChainBridge Resources (r:1 w:0)
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: ChainBridge PrunedNonces (r:1 w:0)
+	// Storage: ChainBridge Votes (r:1 w:1)
+	// Storage: ChainBridge ResolvedProposals (r:1 w:1)
+	// Storage: ChainBridge Participation (r:v w:v)
+	fn reject_proposal(v: u32, ) -> Weight {
+		Weight::from_ref_time(34_658_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_500_000 as u64).saturating_mul(v as u64))
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(v as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(v as u64)))
+	}
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: ChainBridge Votes (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:2 w:0)
+	// Storage: ChainBridge ResolvedProposals (r:1 w:1)
+	// Storage: ChainBridge Relayers (r:v w:0)
+	// Storage: ChainBridge Participation (r:v w:v)
+	// Storage: ChainBridge ProposalSource (r:0 w:1)
+	fn eval_vote_state(v: u32, ) -> Weight {
+		Weight::from_ref_time(31_658_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_500_000 as u64).saturating_mul(v as u64))
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(v as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(v as u64)))
+	}
+	// Storage: ChainBridge Paused (r:0 w:1)
+	fn set_bridge_paused() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge PausedChains (r:0 w:1)
+	fn set_chain_paused() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge RateLimits (r:0 w:1)
+	fn set_rate_limit() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Votes (r:1 w:0)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	// Storage: ChainBridge Relayers (r:v w:v)
+	// Storage: ChainBridge RelayerBonds (r:v w:v)
+	// Storage: System Account (r:v w:v)
+	// Storage: ChainBridge Participation (r:0 w:v)
+	fn slash_voters(v: u32, ) -> Weight {
+		Weight::from_ref_time(22_658_000 as u64)
+			.saturating_add(Weight::from_ref_time(10_500_000 as u64).saturating_mul(v as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(v as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(v as u64)))
+	}
+	// Storage: ChainBridge Relayers (r:1 w:1)
+	// Storage: ChainBridge Participation (r:1 w:1)
+	// Storage: ChainBridge ExpiredProposals (r:1 w:0)
+	// Storage: ChainBridge RelayerBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	fn slash_inactive_relayer() -> Weight {
+		Weight::from_ref_time(34_658_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: ChainBridge ChainNonces (r:1 w:0)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	fn set_chain_threshold() -> Weight {
+		Weight::from_ref_time(22_658_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	// Storage: ChainBridge Relayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ChainBridge ExpiredProposals (r:1 w:0)
+	// Storage: ChainBridge RelayerBonds (r:0 w:1)
+	// Storage: ChainBridge Participation (r:0 w:1)
+	fn add_chain_relayer() -> Weight {
+		Weight::from_ref_time(31_658_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: ChainBridge PruneCursor (r:1 w:1)
+	// Storage: ChainBridge Votes (r:n w:n)
+	// Storage: ChainBridge PrunedNonces (r:n w:n)
+	// Storage: ChainBridge RelayerSets (r:n w:0)
+	// Storage: ChainBridge ExpiredProposals (r:n w:n)
+	// Storage: ChainBridge Relayers (r:63n w:0)
+	// Storage: ChainBridge Participation (r:63n w:63n)
+	fn prune_proposals(n: u32, ) -> Weight {
+		Weight::from_ref_time(21_158_000 as u64)
+			.saturating_add(Weight::from_ref_time(294_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((130 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((66 as u64).saturating_mul(n as u64)))
+	}
+}
@@ -23,8 +23,8 @@ pub mod weights;
 use sp_std::{prelude::*, fmt::Debug};
 use sp_io::{hashing::keccak_256, crypto::secp256k1_ecdsa_recover};
 use frame_support::{
-    decl_event, decl_storage, decl_module, decl_error, ensure,
-    traits::{Currency, Get, VestingSchedule, EnsureOrigin, IsSubType},
+    decl_event, decl_storage, decl_module, decl_error, ensure, IterableStorageMap,
+    traits::{Currency, Get, VestingSchedule, EnsureOrigin, IsSubType, OnUnbalanced},
     dispatch::{Pays, DispatchClass},
     pallet_prelude::DispatchResultWithPostInfo,
 };
@@ -33,10 +33,8 @@ use parity_scale_codec::{Encode, Decode};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{self, Serialize, Deserialize, Serializer, Deserializer};
-#[cfg(feature = "std")]
-use sp_runtime::traits::Zero;
 use sp_runtime::{
-    traits::{CheckedSub, SignedExtension, DispatchInfoOf}, RuntimeDebug, DispatchResult,
+    traits::{CheckedSub, Zero, SignedExtension, DispatchInfoOf}, RuntimeDebug, DispatchResult,
     transaction_validity::{
         TransactionLongevity, TransactionValidity, ValidTransaction, InvalidTransaction,
         TransactionSource, TransactionValidityError,
@@ -47,6 +45,7 @@ pub use weights::WeightInfo;
 
 pub type CurrencyOf<T> = <<T as Config>::VestingSchedule as VestingSchedule<<T as frame_system::Config>::AccountId>>::Currency;
 pub type BalanceOf<T> = <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type NegativeImbalanceOf<T> = <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
 /// Handler for betanet rewards
 pub trait RewardHandler<AccountId, Balance> {
@@ -60,9 +59,16 @@ pub trait Config: frame_system::Config {
     type RuntimeEvent: From<Event<Self>> + Into<<Self as frame_system::Config>::RuntimeEvent>;
     type VestingSchedule: VestingSchedule<Self::AccountId, Moment=Self::BlockNumber>;
     type Prefix: Get<&'static [u8]>;
+    /// Chain name used in the domain of EIP-712 typed data claims
+    type ChainName: Get<&'static [u8]>;
     type MoveClaimOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+    /// Maximum length of the text of a statement
+    type MaxStatementLength: Get<u32>;
     /// Betanet rewards handler
     type RewardHandler: RewardHandler<Self::AccountId, BalanceOf<Self>>;
+    /// Handler for the value of claims swept after the deadline, which is minted
+    /// Use `()` to drop it from the total issuance instead
+    type UnclaimedHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
     type WeightInfo: WeightInfo;
 }
 
@@ -76,6 +82,8 @@ pub enum ValidityError {
     NoPermission = 2,
     /// An invalid statement was made for a claim.
     InvalidStatement = 3,
+    /// The claim deadline has passed.
+    ClaimsExpired = 4,
 }
 
 impl From<ValidityError> for u8 {
@@ -84,7 +92,21 @@ impl From<ValidityError> for u8 {
     }
 }
 
-/// The kind of a statement an account needs to make for a claim to be valid.
+/// Identifier of a statement an account needs to make for a claim to be valid.
+pub type StatementId = u8;
+
+/// A statement that can be attested to in a claim.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Statement {
+    /// Full text of the statement, which is signed
+    pub text: Vec<u8>,
+    /// SHA-256 hash of the document the statement refers to
+    pub document_hash: [u8; 32],
+}
+
+/// The statements that existed before they were managed by governance.
+/// These are registered by default, with the ID given by `StatementKind::id`.
 #[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum StatementKind {
@@ -95,8 +117,13 @@ pub enum StatementKind {
 }
 
 impl StatementKind {
+    /// ID under which this statement is registered.
+    pub fn id(self) -> StatementId {
+        self as StatementId
+    }
+
     /// Convert this to the (English) statement it represents.
-    fn to_text(self) -> &'static [u8] {
+    pub fn to_text(self) -> &'static [u8] {
         match self {
             StatementKind::Regular =>
                 &b"I hereby agree to the terms of the statement whose SHA-256 hash is \
@@ -107,6 +134,32 @@ impl StatementKind {
                 &b"xx network doesn't have a SAFT statement"[..],
         }
     }
+
+    /// SHA-256 hash of the document this statement refers to, if any.
+    pub fn document_hash(self) -> [u8; 32] {
+        match self {
+            StatementKind::Regular => [
+                0x38, 0x95, 0xc0, 0xd2, 0xa2, 0x5a, 0x72, 0x7b, 0x8e, 0xde, 0xd6, 0xdc, 0xca, 0x37, 0xb2, 0xcb,
+                0x11, 0xbd, 0xfc, 0x94, 0xae, 0x99, 0x9d, 0xa3, 0x69, 0xd7, 0xbd, 0xc9, 0xee, 0xca, 0x60, 0x3c,
+            ],
+            StatementKind::Saft => [0u8; 32],
+        }
+    }
+
+    /// The registered statement.
+    pub fn statement(self) -> Statement {
+        Statement {
+            text: self.to_text().to_vec(),
+            document_hash: self.document_hash(),
+        }
+    }
+}
+
+/// Statements registered by default.
+pub fn default_statements() -> Vec<(StatementId, Statement)> {
+    [StatementKind::Regular, StatementKind::Saft].iter()
+        .map(|kind| (kind.id(), kind.statement()))
+        .collect()
 }
 
 impl Default for StatementKind {
@@ -164,10 +217,23 @@ impl sp_std::fmt::Debug for EcdsaSignature {
 decl_event!(
 	pub enum Event<T> where
 		Balance = BalanceOf<T>,
-		AccountId = <T as frame_system::Config>::AccountId
+		AccountId = <T as frame_system::Config>::AccountId,
+		BlockNumber = <T as frame_system::Config>::BlockNumber
 	{
 		/// Someone claimed some coins. [who, ethereum_address, amount, reward]
 		Claimed(AccountId, EthereumAddress, Balance, Balance),
+		/// The claim deadline has been set. [deadline]
+		ClaimDeadlineSet(BlockNumber),
+		/// An unclaimed allocation was swept. [ethereum_address, amount]
+		ClaimSwept(EthereumAddress, Balance),
+		/// All unclaimed allocations have been swept. [count, amount]
+		ClaimsSweepCompleted(u32, Balance),
+		/// A statement has been registered. [statement]
+		StatementAdded(StatementId),
+		/// A statement has been removed. [statement]
+		StatementRemoved(StatementId),
+		/// A claim attested to a statement. [who, ethereum_address, statement]
+		StatementAttested(AccountId, EthereumAddress, StatementId),
 	}
 );
 
@@ -186,6 +252,20 @@ decl_error! {
 		InvalidStatement,
 		/// The account already has a vested balance.
 		VestedBalanceExists,
+		/// The claim deadline has passed.
+		ClaimsExpired,
+		/// The claim deadline hasn't passed yet.
+		ClaimsNotExpired,
+		/// The claim deadline must be in the future.
+		InvalidDeadline,
+		/// There are no unclaimed allocations left.
+		NothingToSweep,
+		/// Statement is not registered.
+		UnknownStatement,
+		/// A statement with this ID is already registered.
+		StatementExists,
+		/// Statement text is too long.
+		StatementTooLong,
 	}
 }
 
@@ -212,12 +292,25 @@ decl_storage! {
 			map hasher(identity) EthereumAddress
 			=> Option<Vec<(BalanceOf<T>, BalanceOf<T>, T::BlockNumber)>>;
 
-		/// The statement kind that must be signed, if any.
+		/// The statement that must be signed, if any.
 		Signing build(|config: &GenesisConfig<T>| {
 			config.claims.iter()
 				.filter_map(|(a, _, _, _, s)| Some((a.clone(), s.clone()?)))
 				.collect::<Vec<_>>()
-		}): map hasher(identity) EthereumAddress => Option<StatementKind>;
+		}): map hasher(identity) EthereumAddress => Option<StatementId>;
+
+		/// Registered statements. The default statements are used if none are given at genesis.
+		pub Statements get(fn statements) build(|config: &GenesisConfig<T>| {
+			if config.statements.is_empty() {
+				default_statements()
+			} else {
+				config.statements.clone()
+			}
+		}): map hasher(twox_64_concat) StatementId => Option<Statement>;
+
+		/// Statement each claim attested to, once claimed.
+		pub Attestations get(fn attestations):
+			map hasher(identity) EthereumAddress => Option<StatementId>;
 
 		/// Pre-claimed Ethereum accounts, by the Account ID that they are claimed to.
 		Preclaims build(|config: &GenesisConfig<T>| {
@@ -232,9 +325,16 @@ decl_storage! {
                 .filter_map(|(a, _, c, _, _)| Some((a.clone(), c.clone()?)))
                 .collect::<Vec<_>>()
         }): map hasher(identity) EthereumAddress => Option<BalanceOf<T>>;
+
+		/// Block from which claims can no longer be made, and unclaimed allocations can be swept.
+		pub ClaimDeadline get(fn claim_deadline): Option<T::BlockNumber>;
+
+		/// Number and value of the unclaimed allocations swept so far.
+		pub Swept get(fn swept): (u32, BalanceOf<T>);
 	}
 	add_extra_genesis {
-		config(claims): Vec<(EthereumAddress, BalanceOf<T>, Option<BalanceOf<T>>, Option<T::AccountId>, Option<StatementKind>)>;
+		config(claims): Vec<(EthereumAddress, BalanceOf<T>, Option<BalanceOf<T>>, Option<T::AccountId>, Option<StatementId>)>;
+		config(statements): Vec<(StatementId, Statement)>;
 	}
 }
 
@@ -245,6 +345,12 @@ decl_module! {
 		/// The Prefix that is used in signed Ethereum messages for this network
 		const Prefix: &[u8] = T::Prefix::get();
 
+		/// The chain name that is used in the domain of EIP-712 typed data claims
+		const ChainName: &[u8] = T::ChainName::get();
+
+		/// The maximum length of the text of a statement
+		const MaxStatementLength: u32 = T::MaxStatementLength::get();
+
 		/// Deposit one of this module's events by using the default implementation.
 		fn deposit_event() = default;
 
@@ -292,6 +398,7 @@ decl_module! {
 		/// - `who`: The Ethereum address allowed to collect this claim.
 		/// - `value`: The number of coins that will be claimed.
 		/// - `vesting_schedules`: An optional list of vesting schedules for these coins.
+		/// - `statement`: An optional registered statement that must be attested to.
 		///
 		/// NOTE: It is the responsibility of the caller to not list more vesting schedules
 		/// than supported by the Vesting pallet.
@@ -308,9 +415,12 @@ decl_module! {
 			who: EthereumAddress,
 			value: BalanceOf<T>,
 			vesting_schedules: Option<Vec<(BalanceOf<T>, BalanceOf<T>, T::BlockNumber)>>,
-			statement: Option<StatementKind>,
+			statement: Option<StatementId>,
 		) {
 			ensure_root(origin)?;
+			if let Some(s) = statement {
+				ensure!(Statements::contains_key(s), Error::<T>::UnknownStatement);
+			}
 
 			<Total<T>>::mutate(|t| *t += value);
 			<Claims<T>>::insert(who, value);
@@ -360,7 +470,7 @@ decl_module! {
 			let signer = Self::eth_recover(&ethereum_signature, &data, &statement)
 				.ok_or(Error::<T>::InvalidEthereumSignature)?;
 			if let Some(s) = Signing::get(signer) {
-				ensure!(s.to_text() == &statement[..], Error::<T>::InvalidStatement);
+				ensure!(Self::statement_matches(s, &statement), Error::<T>::InvalidStatement);
 			}
 			Self::process_claim(signer, dest)?;
 		}
@@ -391,12 +501,194 @@ decl_module! {
 			let who = ensure_signed(origin)?;
 			let signer = Preclaims::<T>::get(&who).ok_or(Error::<T>::SenderHasNoClaim)?;
 			if let Some(s) = Signing::get(signer) {
-				ensure!(s.to_text() == &statement[..], Error::<T>::InvalidStatement);
+				ensure!(Self::statement_matches(s, &statement), Error::<T>::InvalidStatement);
 			}
 			Self::process_claim(signer, who.clone())?;
 			Preclaims::<T>::remove(&who);
 		}
 
+		/// Make a claim to collect your coins, signed as EIP-712 typed data.
+		///
+		/// The dispatch origin for this call must be _None_.
+		///
+		/// Unsigned Validation:
+		/// A call to `claim_typed` is deemed valid if the signature provided matches
+		/// the EIP-712 typed data (as signed by `eth_signTypedData_v4`) of:
+		///
+		/// > EIP712Domain(string name,string version,bytes32 salt)
+		/// > Claim(bytes destination,bytes32 statementHash)
+		///
+		/// where the domain has the configured chain name, version "1" and the genesis hash
+		/// as salt, `destination` is the SCALE encoded `dest` account and `statementHash` is
+		/// the keccak256 hash of an empty statement.
+		///
+		/// Parameters:
+		/// - `dest`: The destination account to payout the claim.
+		/// - `ethereum_signature`: The signature of the typed data described above.
+		///
+		/// <weight>
+		/// The weight of this call is invariant over the input parameters.
+		/// Weight includes logic to validate unsigned `claim_typed` call.
+		///
+		/// Total Complexity: O(1)
+		/// </weight>
+		#[weight = T::WeightInfo::claim_typed()]
+		pub fn claim_typed(origin, dest: T::AccountId, ethereum_signature: EcdsaSignature) {
+			ensure_none(origin)?;
+
+			let data = dest.encode();
+			let signer = Self::eth_recover_typed(&ethereum_signature, &data, &[][..])
+				.ok_or(Error::<T>::InvalidEthereumSignature)?;
+			ensure!(Signing::get(&signer).is_none(), Error::<T>::InvalidStatement);
+
+			Self::process_claim(signer, dest)?;
+		}
+
+		/// Make a claim to collect your coins by signing a statement as EIP-712 typed data.
+		///
+		/// The dispatch origin for this call must be _None_.
+		///
+		/// Unsigned Validation:
+		/// A call to `claim_attest_typed` is deemed valid if the signature provided matches
+		/// the EIP-712 typed data described in `claim_typed`, with `statementHash` being the
+		/// keccak256 hash of `statement`. The `statement` must match that which is
+		/// expected according to your purchase arrangement.
+		///
+		/// Parameters:
+		/// - `dest`: The destination account to payout the claim.
+		/// - `ethereum_signature`: The signature of the typed data described above.
+		/// - `statement`: The identity of the statement which is being attested to in the signature.
+		///
+		/// <weight>
+		/// The weight of this call is invariant over the input parameters.
+		/// Weight includes logic to validate unsigned `claim_attest_typed` call.
+		///
+		/// Total Complexity: O(1)
+		/// </weight>
+		#[weight = T::WeightInfo::claim_attest_typed()]
+		fn claim_attest_typed(origin,
+			dest: T::AccountId,
+			ethereum_signature: EcdsaSignature,
+			statement: Vec<u8>,
+		) {
+			ensure_none(origin)?;
+
+			let data = dest.encode();
+			let signer = Self::eth_recover_typed(&ethereum_signature, &data, &statement)
+				.ok_or(Error::<T>::InvalidEthereumSignature)?;
+			if let Some(s) = Signing::get(signer) {
+				ensure!(Self::statement_matches(s, &statement), Error::<T>::InvalidStatement);
+			}
+			Self::process_claim(signer, dest)?;
+		}
+
+		/// Set the deadline for making claims.
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// The deadline can't be changed once it has passed.
+		///
+		/// Parameters:
+		/// - `deadline`: The block from which claims can no longer be made.
+		#[weight = T::WeightInfo::set_claim_deadline()]
+		fn set_claim_deadline(origin, deadline: T::BlockNumber) {
+			ensure_root(origin)?;
+			ensure!(!Self::claims_expired(), Error::<T>::ClaimsExpired);
+			let block = <frame_system::Pallet<T>>::block_number();
+			ensure!(deadline > block, Error::<T>::InvalidDeadline);
+
+			<ClaimDeadline<T>>::put(deadline);
+			Self::deposit_event(RawEvent::ClaimDeadlineSet(deadline));
+		}
+
+		/// Sweep unclaimed allocations after the claim deadline.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Stale preclaims are removed first, and then unclaimed allocations,
+		/// along with their vesting schedules, statements and rewards.
+		/// The swept value is handed to `UnclaimedHandler`.
+		///
+		/// Parameters:
+		/// - `max`: The maximum number of entries to remove.
+		///
+		/// <weight>
+		/// Total Complexity: O(max)
+		/// </weight>
+		#[weight = T::WeightInfo::sweep_claims(*max)]
+		fn sweep_claims(origin, max: u32) {
+			ensure_signed(origin)?;
+			ensure!(Self::claims_expired(), Error::<T>::ClaimsNotExpired);
+			ensure!(
+				<Preclaims<T>>::iter().next().is_some() || <Claims<T>>::iter().next().is_some(),
+				Error::<T>::NothingToSweep
+			);
+
+			// 1. Remove stale preclaims
+			let removed = <Preclaims<T>>::drain().take(max as usize).count() as u32;
+
+			// 2. Sweep unclaimed allocations
+			let mut count = 0u32;
+			let mut amount: BalanceOf<T> = Zero::zero();
+			<Claims<T>>::drain().take(max.saturating_sub(removed) as usize).for_each(|(address, value)| {
+				<Vesting<T>>::remove(&address);
+				Signing::remove(&address);
+				<Rewards<T>>::remove(&address);
+				count += 1;
+				amount += value;
+				Self::deposit_event(RawEvent::ClaimSwept(address, value));
+			});
+			<Total<T>>::mutate(|t| *t = t.saturating_sub(amount));
+
+			// 3. Mint swept value into the unclaimed handler
+			T::UnclaimedHandler::on_unbalanced(CurrencyOf::<T>::issue(amount));
+
+			// 4. Update summary, and emit it when done
+			let (total_count, total_amount) = <Swept<T>>::mutate(|(c, a)| {
+				*c = c.saturating_add(count);
+				*a += amount;
+				(*c, *a)
+			});
+			if <Claims<T>>::iter().next().is_none() && <Preclaims<T>>::iter().next().is_none() {
+				Self::deposit_event(RawEvent::ClaimsSweepCompleted(total_count, total_amount));
+			}
+		}
+
+		/// Register a statement that claims can be required to attest to.
+		///
+		/// The dispatch origin for this call must be `MoveClaimOrigin` or _Root_.
+		///
+		/// Parameters:
+		/// - `id`: The ID of the statement, which must not be in use.
+		/// - `text`: The full text of the statement.
+		/// - `document_hash`: The SHA-256 hash of the document the statement refers to.
+		#[weight = T::WeightInfo::add_statement(text.len() as u32)]
+		fn add_statement(origin, id: StatementId, text: Vec<u8>, document_hash: [u8; 32]) {
+			T::MoveClaimOrigin::try_origin(origin).map(|_| ()).or_else(ensure_root)?;
+			ensure!(text.len() as u32 <= T::MaxStatementLength::get(), Error::<T>::StatementTooLong);
+			ensure!(!Statements::contains_key(id), Error::<T>::StatementExists);
+
+			Statements::insert(id, Statement { text, document_hash });
+			Self::deposit_event(RawEvent::StatementAdded(id));
+		}
+
+		/// Remove a registered statement.
+		///
+		/// The dispatch origin for this call must be `MoveClaimOrigin` or _Root_.
+		///
+		/// Claims that require this statement can't be made until it is registered again.
+		///
+		/// Parameters:
+		/// - `id`: The ID of the statement.
+		#[weight = T::WeightInfo::remove_statement()]
+		fn remove_statement(origin, id: StatementId) {
+			T::MoveClaimOrigin::try_origin(origin).map(|_| ()).or_else(ensure_root)?;
+			ensure!(Statements::contains_key(id), Error::<T>::UnknownStatement);
+
+			Statements::remove(id);
+			Self::deposit_event(RawEvent::StatementRemoved(id));
+		}
+
 		#[weight = T::WeightInfo::move_claim()]
 		fn move_claim(origin,
 			old: EthereumAddress,
@@ -427,34 +719,105 @@ pub fn to_ascii_hex(data: &[u8]) -> Vec<u8> {
     r
 }
 
+/// EIP-712 type of the domain of typed data claims.
+pub const EIP712_DOMAIN_TYPE: &[u8] = b"EIP712Domain(string name,string version,bytes32 salt)";
+
+/// Version of the domain of typed data claims.
+pub const EIP712_DOMAIN_VERSION: &[u8] = b"1";
+
+/// EIP-712 type of typed data claims.
+pub const EIP712_CLAIM_TYPE: &[u8] = b"Claim(bytes destination,bytes32 statementHash)";
+
+/// Computes the EIP-712 domain separator for the given chain name and genesis hash.
+pub fn eip712_domain_separator(name: &[u8], genesis_hash: &[u8; 32]) -> [u8; 32] {
+    let mut v = Vec::with_capacity(128);
+    v.extend_from_slice(&keccak_256(EIP712_DOMAIN_TYPE));
+    v.extend_from_slice(&keccak_256(name));
+    v.extend_from_slice(&keccak_256(EIP712_DOMAIN_VERSION));
+    v.extend_from_slice(genesis_hash);
+    keccak_256(&v)
+}
+
+/// Computes the EIP-712 hash that is signed in a typed data claim.
+/// `dest` is the SCALE encoded destination account.
+pub fn eip712_claim_hash(domain_separator: &[u8; 32], dest: &[u8], statement: &[u8]) -> [u8; 32] {
+    let mut s = Vec::with_capacity(96);
+    s.extend_from_slice(&keccak_256(EIP712_CLAIM_TYPE));
+    s.extend_from_slice(&keccak_256(dest));
+    s.extend_from_slice(&keccak_256(statement));
+    let mut v = b"\x19\x01".to_vec();
+    v.extend_from_slice(&domain_separator[..]);
+    v.extend_from_slice(&keccak_256(&s));
+    keccak_256(&v)
+}
+
+/// Constructs the message that Ethereum RPC's `personal_sign` and `eth_sign` would sign
+/// for the given claim prefix.
+pub fn ethereum_signable_message(prefix: &[u8], what: &[u8], extra: &[u8]) -> Vec<u8> {
+    let mut l = prefix.len() + what.len() + extra.len();
+    let mut rev = Vec::new();
+    while l > 0 {
+        rev.push(b'0' + (l % 10) as u8);
+        l /= 10;
+    }
+    let mut v = b"\x19Ethereum Signed Message:\n".to_vec();
+    v.extend(rev.into_iter().rev());
+    v.extend_from_slice(prefix);
+    v.extend_from_slice(what);
+    v.extend_from_slice(extra);
+    v
+}
+
+/// Attempts to recover the Ethereum address that signed the given message hash.
+pub fn eth_recover_hash(s: &EcdsaSignature, msg: &[u8; 32]) -> Option<EthereumAddress> {
+    let mut res = EthereumAddress::default();
+    res.0.copy_from_slice(&keccak_256(&secp256k1_ecdsa_recover(&s.0, msg).ok()?[..])[12..]);
+    Some(res)
+}
+
 impl<T: Config> Module<T> {
+    // Constructs the EIP-712 domain separator of this chain, using the genesis hash as salt.
+    fn eip712_domain() -> [u8; 32] {
+        let genesis_hash = <frame_system::Pallet<T>>::block_hash(T::BlockNumber::zero());
+        let mut salt = [0u8; 32];
+        let len = genesis_hash.as_ref().len().min(32);
+        salt[..len].copy_from_slice(&genesis_hash.as_ref()[..len]);
+        eip712_domain_separator(T::ChainName::get(), &salt)
+    }
+
     // Constructs the message that Ethereum RPC's `personal_sign` and `eth_sign` would sign.
     fn ethereum_signable_message(what: &[u8], extra: &[u8]) -> Vec<u8> {
-        let prefix = T::Prefix::get();
-        let mut l = prefix.len() + what.len() + extra.len();
-        let mut rev = Vec::new();
-        while l > 0 {
-            rev.push(b'0' + (l % 10) as u8);
-            l /= 10;
-        }
-        let mut v = b"\x19Ethereum Signed Message:\n".to_vec();
-        v.extend(rev.into_iter().rev());
-        v.extend_from_slice(&prefix[..]);
-        v.extend_from_slice(what);
-        v.extend_from_slice(extra);
-        v
+        ethereum_signable_message(T::Prefix::get(), what, extra)
     }
 
     // Attempts to recover the Ethereum address from a message signature signed by using
     // the Ethereum RPC's `personal_sign` and `eth_sign`.
     fn eth_recover(s: &EcdsaSignature, what: &[u8], extra: &[u8]) -> Option<EthereumAddress> {
         let msg = keccak_256(&Self::ethereum_signable_message(what, extra));
-        let mut res = EthereumAddress::default();
-        res.0.copy_from_slice(&keccak_256(&secp256k1_ecdsa_recover(&s.0, &msg).ok()?[..])[12..]);
-        Some(res)
+        eth_recover_hash(s, &msg)
+    }
+
+    // Attempts to recover the Ethereum address from an EIP-712 typed data signature,
+    // as signed by the Ethereum RPC's `eth_signTypedData_v4`.
+    fn eth_recover_typed(s: &EcdsaSignature, dest: &[u8], statement: &[u8]) -> Option<EthereumAddress> {
+        let msg = eip712_claim_hash(&Self::eip712_domain(), dest, statement);
+        eth_recover_hash(s, &msg)
+    }
+
+    // Checks whether the given statement is the text of a registered statement.
+    fn statement_matches(id: StatementId, statement: &[u8]) -> bool {
+        Statements::get(id).map_or(false, |s| &s.text[..] == statement)
+    }
+
+    // Checks whether the claim deadline has passed.
+    fn claims_expired() -> bool {
+        let block = <frame_system::Pallet<T>>::block_number();
+        Self::claim_deadline().map_or(false, |deadline| block >= deadline)
     }
 
     fn process_claim(signer: EthereumAddress, dest: T::AccountId) -> DispatchResult {
+        ensure!(!Self::claims_expired(), Error::<T>::ClaimsExpired);
+
         let balance_due = <Claims<T>>::get(&signer)
             .ok_or(Error::<T>::SignerHasNoClaim)?;
 
@@ -493,7 +856,12 @@ impl<T: Config> Module<T> {
         <Total<T>>::put(new_total);
         <Claims<T>>::remove(&signer);
         <Vesting<T>>::remove(&signer);
-        Signing::remove(&signer);
+
+        // Record the statement that was attested to, if any.
+        if let Some(s) = Signing::take(&signer) {
+            Attestations::insert(&signer, s);
+            Self::deposit_event(RawEvent::StatementAttested(dest.clone(), signer, s));
+        }
 
         // Let's deposit an event to let the outside world know this happened.
         Self::deposit_event(RawEvent::Claimed(dest, signer, balance_due, reward.unwrap_or_default()));
@@ -523,9 +891,26 @@ impl<T: Config> sp_runtime::traits::ValidateUnsigned for Module<T> {
                 let data = account.using_encoded(to_ascii_hex);
                 (Self::eth_recover(&ethereum_signature, &data, &statement), Some(statement.as_slice()))
             }
+            // <weight>
+            // The weight of this logic is included in the `claim_typed` dispatchable.
+            // </weight>
+            Call::claim_typed { dest: account, ethereum_signature } => {
+                let data = account.encode();
+                (Self::eth_recover_typed(&ethereum_signature, &data, &[][..]), None)
+            }
+            // <weight>
+            // The weight of this logic is included in the `claim_attest_typed` dispatchable.
+            // </weight>
+            Call::claim_attest_typed { dest: account, ethereum_signature, statement } => {
+                let data = account.encode();
+                (Self::eth_recover_typed(&ethereum_signature, &data, &statement), Some(statement.as_slice()))
+            }
             _ => return Err(InvalidTransaction::Call.into()),
         };
 
+        let e = InvalidTransaction::Custom(ValidityError::ClaimsExpired.into());
+        ensure!(!Self::claims_expired(), e);
+
         let signer = maybe_signer
             .ok_or(InvalidTransaction::Custom(ValidityError::InvalidEthereumSignature.into()))?;
 
@@ -535,7 +920,7 @@ impl<T: Config> sp_runtime::traits::ValidateUnsigned for Module<T> {
         let e = InvalidTransaction::Custom(ValidityError::InvalidStatement.into());
         match Signing::get(signer) {
             None => ensure!(maybe_statement.is_none(), e),
-            Some(s) => ensure!(Some(s.to_text()) == maybe_statement, e),
+            Some(s) => ensure!(maybe_statement.map_or(false, |st| Self::statement_matches(s, st)), e),
         }
 
         Ok(ValidTransaction {
@@ -613,11 +998,13 @@ impl<T: Config + Send + Sync> SignedExtension for PrevalidateAttests<T> where
     ) -> TransactionValidity {
         if let Some(local_call) = call.is_sub_type() {
             if let Call::attest { statement: attested_statement } = local_call {
+                let e = InvalidTransaction::Custom(ValidityError::ClaimsExpired.into());
+                ensure!(!<Module<T>>::claims_expired(), e);
                 let signer = Preclaims::<T>::get(who)
                     .ok_or(InvalidTransaction::Custom(ValidityError::SignerHasNoClaim.into()))?;
                 if let Some(s) = Signing::get(signer) {
                     let e = InvalidTransaction::Custom(ValidityError::InvalidStatement.into());
-                    ensure!(&attested_statement[..] == s.to_text(), e);
+                    ensure!(<Module<T>>::statement_matches(s, attested_statement), e);
                 }
             }
         }
@@ -646,6 +1033,14 @@ mod secp_utils {
         r[64] = recovery_id.serialize();
         EcdsaSignature(r)
     }
+    pub fn typed_sig<T: Config>(secret: &libsecp256k1::SecretKey, dest: &[u8], statement: &[u8]) -> EcdsaSignature {
+        let msg = eip712_claim_hash(&<super::Module<T>>::eip712_domain(), dest, statement);
+        let (sig, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), secret);
+        let mut r = [0u8; 65];
+        r[0..64].copy_from_slice(&sig.serialize()[..]);
+        r[64] = recovery_id.serialize();
+        EcdsaSignature(r)
+    }
 }
 
 // Manual implementation of WhitelistedStorageKeys for runtime benchmarks
@@ -758,6 +1153,8 @@ mod tests {
 
     parameter_types!{
 		pub Prefix: &'static [u8] = b"Pay RUSTs to the TEST account:";
+		pub ChainName: &'static [u8] = b"TEST";
+		pub const MaxStatementLength: u32 = 1024;
 	}
     ord_parameter_types! {
 		pub const Six: u64 = 6;
@@ -768,12 +1165,22 @@ mod tests {
         fn add_claimed(_: u64, _: u64, _: u64) {}
     }
 
+    pub struct UnclaimedMock;
+    impl OnUnbalanced<NegativeImbalanceOf<Test>> for UnclaimedMock {
+        fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<Test>) {
+            Balances::resolve_creating(&99, amount);
+        }
+    }
+
     impl Config for Test {
         type RuntimeEvent = RuntimeEvent;
         type VestingSchedule = Vesting;
         type Prefix = Prefix;
+        type ChainName = ChainName;
         type MoveClaimOrigin = frame_system::EnsureSignedBy<Six, u64>;
+        type MaxStatementLength = MaxStatementLength;
         type RewardHandler = RewardHandlerMock;
+        type UnclaimedHandler = UnclaimedMock;
         type WeightInfo = weights::TestWeightInfo;
     }
 
@@ -805,8 +1212,8 @@ mod tests {
         claims::GenesisConfig::<Test>{
             claims: vec![
                 (eth(&alice()), 100, Some(50), None, None),
-                (eth(&dave()), 200, None, None, Some(StatementKind::Regular)),
-                (eth(&eve()), 300, None, Some(42), Some(StatementKind::Saft)),
+                (eth(&dave()), 200, None, None, Some(StatementKind::Regular.id())),
+                (eth(&eve()), 300, None, Some(42), Some(StatementKind::Saft.id())),
                 (eth(&frank()), 400, None, Some(43), None),
                 (eth(&charlie()), 500, None, None, None),
             ],
@@ -817,6 +1224,7 @@ mod tests {
                     (450, 10, 0),
                 ]),
             ],
+            statements: vec![],
         }.assimilate_storage(&mut t).unwrap();
         t.into()
     }
@@ -1048,7 +1456,7 @@ mod tests {
     fn add_claim_with_statement_works() {
         new_test_ext().execute_with(|| {
             assert_noop!(
-				Claims::mint_claim(RuntimeOrigin::signed(42), eth(&bob()), 200, None, Some(StatementKind::Regular)),
+				Claims::mint_claim(RuntimeOrigin::signed(42), eth(&bob()), 200, None, Some(StatementKind::Regular.id())),
 				sp_runtime::traits::BadOrigin,
 			);
             assert_eq!(Balances::free_balance(42), 0);
@@ -1059,7 +1467,7 @@ mod tests {
 				),
 				Error::<Test>::SignerHasNoClaim
 			);
-            assert_ok!(Claims::mint_claim(RuntimeOrigin::root(), eth(&bob()), 200, None, Some(StatementKind::Regular)));
+            assert_ok!(Claims::mint_claim(RuntimeOrigin::root(), eth(&bob()), 200, None, Some(StatementKind::Regular.id())));
             assert_noop!(
 				Claims::claim_attest(
 					RuntimeOrigin::none(), 69, signature.clone(), vec![],
@@ -1266,6 +1674,280 @@ mod tests {
             );
         });
     }
+
+    #[test]
+    fn typed_claiming_works() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(Balances::free_balance(42), 0);
+            assert_ok!(Claims::claim_typed(RuntimeOrigin::none(), 42, typed_sig::<Test>(&alice(), &42u64.encode(), &[][..])));
+            assert_eq!(Balances::free_balance(&42), 100);
+            assert_eq!(Vesting::vesting_balance(&42), Some(50));
+            assert_eq!(Claims::total(), total_claims() - 100);
+        });
+    }
+
+    #[test]
+    fn typed_claiming_checks_format_and_domain() {
+        new_test_ext().execute_with(|| {
+            // A `personal_sign` signature is not valid typed data
+            assert_noop!(
+                Claims::claim_typed(RuntimeOrigin::none(), 42, sig::<Test>(&alice(), &42u64.encode(), &[][..])),
+                Error::<Test>::SignerHasNoClaim,
+            );
+            // Typed data signed for another chain is not valid
+            let genesis_hash = System::block_hash(0);
+            let domain = eip712_domain_separator(b"OTHER", genesis_hash.as_fixed_bytes());
+            let msg = eip712_claim_hash(&domain, &42u64.encode(), &[][..]);
+            let (s, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), &alice());
+            let mut r = [0u8; 65];
+            r[0..64].copy_from_slice(&s.serialize()[..]);
+            r[64] = recovery_id.serialize();
+            assert_noop!(
+                Claims::claim_typed(RuntimeOrigin::none(), 42, EcdsaSignature(r)),
+                Error::<Test>::SignerHasNoClaim,
+            );
+            // Typed data for another destination is not valid
+            assert_noop!(
+                Claims::claim_typed(RuntimeOrigin::none(), 42, typed_sig::<Test>(&alice(), &43u64.encode(), &[][..])),
+                Error::<Test>::SignerHasNoClaim,
+            );
+            // Claims that require a statement can't be made without one
+            assert_noop!(
+                Claims::claim_typed(RuntimeOrigin::none(), 42, typed_sig::<Test>(&dave(), &42u64.encode(), &[][..])),
+                Error::<Test>::InvalidStatement,
+            );
+        });
+    }
+
+    #[test]
+    fn typed_attest_claiming_works() {
+        new_test_ext().execute_with(|| {
+            let s = typed_sig::<Test>(&dave(), &42u64.encode(), StatementKind::Saft.to_text());
+            let r = Claims::claim_attest_typed(RuntimeOrigin::none(), 42, s, StatementKind::Saft.to_text().to_vec());
+            assert_noop!(r, Error::<Test>::InvalidStatement);
+
+            let s = typed_sig::<Test>(&dave(), &42u64.encode(), StatementKind::Regular.to_text());
+            assert_ok!(Claims::claim_attest_typed(RuntimeOrigin::none(), 42, s, StatementKind::Regular.to_text().to_vec()));
+            assert_eq!(Balances::free_balance(&42), 200);
+            assert_eq!(Claims::total(), total_claims() - 200);
+        });
+    }
+
+    #[test]
+    fn validate_unsigned_works_for_typed_claims() {
+        use sp_runtime::traits::ValidateUnsigned;
+        let source = sp_runtime::transaction_validity::TransactionSource::External;
+
+        new_test_ext().execute_with(|| {
+            let call = ClaimsCall::claim_typed { dest: 1, ethereum_signature: typed_sig::<Test>(&alice(), &1u64.encode(), &[][..]) };
+            assert_eq!(
+                <Module<Test>>::validate_unsigned(source, &call),
+                Ok(ValidTransaction {
+                    priority: 100,
+                    requires: vec![],
+                    provides: vec![("claims", eth(&alice())).encode()],
+                    longevity: TransactionLongevity::max_value(),
+                    propagate: true,
+                })
+            );
+            let call = ClaimsCall::claim_typed { dest: 1, ethereum_signature: sig::<Test>(&alice(), &1u64.encode(), &[][..]) };
+            assert_eq!(
+                <Module<Test>>::validate_unsigned(source, &call),
+                InvalidTransaction::Custom(ValidityError::SignerHasNoClaim.into()).into(),
+            );
+            let call = ClaimsCall::claim_typed { dest: 1, ethereum_signature: typed_sig::<Test>(&dave(), &1u64.encode(), &[][..]) };
+            assert_eq!(
+                <Module<Test>>::validate_unsigned(source, &call),
+                InvalidTransaction::Custom(ValidityError::InvalidStatement.into()).into(),
+            );
+            let s = typed_sig::<Test>(&dave(), &1u64.encode(), StatementKind::Regular.to_text());
+            let call = ClaimsCall::claim_attest_typed { dest: 1, ethereum_signature: s, statement: StatementKind::Regular.to_text().to_vec() };
+            assert_eq!(
+                <Module<Test>>::validate_unsigned(source, &call),
+                Ok(ValidTransaction {
+                    priority: 100,
+                    requires: vec![],
+                    provides: vec![("claims", eth(&dave())).encode()],
+                    longevity: TransactionLongevity::max_value(),
+                    propagate: true,
+                })
+            );
+            let s = typed_sig::<Test>(&dave(), &1u64.encode(), StatementKind::Saft.to_text());
+            let call = ClaimsCall::claim_attest_typed { dest: 1, ethereum_signature: s, statement: StatementKind::Saft.to_text().to_vec() };
+            assert_eq!(
+                <Module<Test>>::validate_unsigned(source, &call),
+                InvalidTransaction::Custom(ValidityError::InvalidStatement.into()).into(),
+            );
+        });
+    }
+
+    fn claims_events() -> Vec<RawEvent<u64, u64, u64>> {
+        System::events().into_iter().filter_map(|r| {
+            if let RuntimeEvent::Claims(inner) = r.event { Some(inner) } else { None }
+        }).collect()
+    }
+
+    #[test]
+    fn set_claim_deadline_works() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(Claims::set_claim_deadline(RuntimeOrigin::signed(42), 10), BadOrigin);
+            assert_noop!(Claims::set_claim_deadline(RuntimeOrigin::root(), 1), Error::<Test>::InvalidDeadline);
+            assert_ok!(Claims::set_claim_deadline(RuntimeOrigin::root(), 10));
+            assert_eq!(Claims::claim_deadline(), Some(10));
+            assert_eq!(claims_events(), vec![RawEvent::ClaimDeadlineSet(10)]);
+
+            // Claims can be made until the deadline
+            System::set_block_number(9);
+            assert_ok!(Claims::claim(RuntimeOrigin::none(), 42, sig::<Test>(&alice(), &42u64.encode(), &[][..])));
+
+            // But not after it, and the deadline can no longer be changed
+            System::set_block_number(10);
+            assert_noop!(
+                Claims::claim(RuntimeOrigin::none(), 42, sig::<Test>(&charlie(), &42u64.encode(), &[][..])),
+                Error::<Test>::ClaimsExpired,
+            );
+            assert_noop!(
+                Claims::attest(RuntimeOrigin::signed(42), StatementKind::Saft.to_text().to_vec()),
+                Error::<Test>::ClaimsExpired,
+            );
+            assert_noop!(Claims::set_claim_deadline(RuntimeOrigin::root(), 20), Error::<Test>::ClaimsExpired);
+        });
+    }
+
+    #[test]
+    fn validate_unsigned_rejects_expired_claims() {
+        use sp_runtime::traits::ValidateUnsigned;
+        let source = sp_runtime::transaction_validity::TransactionSource::External;
+
+        new_test_ext().execute_with(|| {
+            assert_ok!(Claims::set_claim_deadline(RuntimeOrigin::root(), 10));
+            System::set_block_number(10);
+            assert_eq!(
+                <Module<Test>>::validate_unsigned(source,
+                    &ClaimsCall::claim { dest: 1, ethereum_signature: sig::<Test>(&alice(), &1u64.encode(), &[][..]) }),
+                InvalidTransaction::Custom(ValidityError::ClaimsExpired.into()).into(),
+            );
+        });
+    }
+
+    #[test]
+    fn sweep_claims_works() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Claims::claim(RuntimeOrigin::none(), 42, sig::<Test>(&alice(), &42u64.encode(), &[][..])));
+            let issuance = Balances::total_issuance();
+
+            // Nothing can be swept before the deadline
+            assert_noop!(Claims::sweep_claims(RuntimeOrigin::signed(1), 10), Error::<Test>::ClaimsNotExpired);
+            assert_ok!(Claims::set_claim_deadline(RuntimeOrigin::root(), 10));
+            assert_noop!(Claims::sweep_claims(RuntimeOrigin::signed(1), 10), Error::<Test>::ClaimsNotExpired);
+
+            // First batch removes the 2 preclaims and 1 unclaimed allocation
+            System::set_block_number(10);
+            assert_ok!(Claims::sweep_claims(RuntimeOrigin::signed(1), 3));
+            assert_eq!(Preclaims::<Test>::iter().count(), 0);
+            assert_eq!(claims::Claims::<Test>::iter().count(), 3);
+            let (count, amount) = Claims::swept();
+            assert_eq!(count, 1);
+            assert_eq!(Claims::total(), total_claims() - 100 - amount);
+            assert_eq!(Balances::free_balance(&99), amount);
+
+            // Second batch sweeps the rest
+            assert_ok!(Claims::sweep_claims(RuntimeOrigin::signed(1), 10));
+            assert_eq!(claims::Claims::<Test>::iter().count(), 0);
+            assert_eq!(claims::Vesting::<Test>::iter().count(), 0);
+            assert_eq!(Signing::iter().count(), 0);
+            assert_eq!(Claims::total(), 0);
+            assert_eq!(Claims::swept(), (4, total_claims() - 100));
+
+            // Unclaimed value is minted to the handler
+            assert_eq!(Balances::free_balance(&99), total_claims() - 100);
+            assert_eq!(Balances::total_issuance(), issuance + total_claims() - 100);
+
+            // Events for each swept address and the summary
+            let events = claims_events();
+            assert_eq!(events.iter().filter(|e| matches!(e, RawEvent::ClaimSwept(..))).count(), 4);
+            assert_eq!(events.last(), Some(&RawEvent::ClaimsSweepCompleted(4, total_claims() - 100)));
+
+            assert_noop!(Claims::sweep_claims(RuntimeOrigin::signed(1), 10), Error::<Test>::NothingToSweep);
+        });
+    }
+
+    #[test]
+    fn default_statements_are_registered() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(Claims::statements(StatementKind::Regular.id()), Some(StatementKind::Regular.statement()));
+            assert_eq!(Claims::statements(StatementKind::Saft.id()), Some(StatementKind::Saft.statement()));
+        });
+    }
+
+    #[test]
+    fn managing_statements_works() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let text = b"I agree to the new terms".to_vec();
+            assert_noop!(Claims::add_statement(RuntimeOrigin::signed(42), 2, text.clone(), [1u8; 32]), BadOrigin);
+            assert_noop!(
+                Claims::add_statement(RuntimeOrigin::root(), StatementKind::Regular.id(), text.clone(), [1u8; 32]),
+                Error::<Test>::StatementExists,
+            );
+            assert_noop!(
+                Claims::add_statement(RuntimeOrigin::root(), 2, vec![0u8; 1025], [1u8; 32]),
+                Error::<Test>::StatementTooLong,
+            );
+            assert_ok!(Claims::add_statement(RuntimeOrigin::signed(6), 2, text.clone(), [1u8; 32]));
+            assert_eq!(Claims::statements(2), Some(Statement { text, document_hash: [1u8; 32] }));
+
+            assert_noop!(Claims::remove_statement(RuntimeOrigin::signed(42), 2), BadOrigin);
+            assert_ok!(Claims::remove_statement(RuntimeOrigin::root(), 2));
+            assert_noop!(Claims::remove_statement(RuntimeOrigin::root(), 2), Error::<Test>::UnknownStatement);
+            assert_eq!(Claims::statements(2), None);
+
+            assert_eq!(
+                claims_events(),
+                vec![RawEvent::StatementAdded(2), RawEvent::StatementRemoved(2)],
+            );
+        });
+    }
+
+    #[test]
+    fn claiming_with_registered_statement_works() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let text = b"I agree to the new terms".to_vec();
+
+            // Claims can only reference registered statements
+            assert_noop!(
+                Claims::mint_claim(RuntimeOrigin::root(), eth(&bob()), 200, None, Some(2)),
+                Error::<Test>::UnknownStatement,
+            );
+            assert_ok!(Claims::add_statement(RuntimeOrigin::root(), 2, text.clone(), [1u8; 32]));
+            assert_ok!(Claims::mint_claim(RuntimeOrigin::root(), eth(&bob()), 200, None, Some(2)));
+
+            // The old statement is not accepted
+            let s = sig::<Test>(&bob(), &69u64.encode(), StatementKind::Regular.to_text());
+            assert_noop!(
+                Claims::claim_attest(RuntimeOrigin::none(), 69, s, StatementKind::Regular.to_text().to_vec()),
+                Error::<Test>::InvalidStatement,
+            );
+
+            // The registered statement is, and the attestation is recorded
+            let s = sig::<Test>(&bob(), &69u64.encode(), &text);
+            assert_ok!(Claims::claim_attest(RuntimeOrigin::none(), 69, s, text));
+            assert_eq!(Balances::free_balance(&69), 200);
+            assert_eq!(Claims::attestations(eth(&bob())), Some(2));
+            assert_eq!(
+                claims_events().last(),
+                Some(&RawEvent::Claimed(69, eth(&bob()), 200, 0)),
+            );
+            assert!(claims_events().contains(&RawEvent::StatementAttested(69, eth(&bob()), 2)));
+
+            // Claims without statement don't record an attestation
+            assert_ok!(Claims::claim(RuntimeOrigin::none(), 42, sig::<Test>(&alice(), &42u64.encode(), &[][..])));
+            assert_eq!(Claims::attestations(eth(&alice())), None);
+        });
+    }
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -1300,7 +1982,7 @@ mod benchmarking {
             eth_address,
             VALUE.into(),
             vesting,
-            Some(Default::default())
+            Some(StatementKind::default().id())
         )?;
         Ok(())
     }
@@ -1344,7 +2026,7 @@ mod benchmarking {
 			let eth_address = account("eth_address", 0, SEED);
 			let vesting = Some(vec![(100_000u32.into(), 1_000u32.into(), 100u32.into())]);
 			let statement = StatementKind::Regular;
-		}: _(RawOrigin::Root, eth_address, VALUE.into(), vesting, Some(statement))
+		}: _(RawOrigin::Root, eth_address, VALUE.into(), vesting, Some(statement.id()))
 		verify {
 			assert_eq!(Claims::<T>::get(eth_address), Some(VALUE.into()));
 		}
@@ -1366,7 +2048,7 @@ mod benchmarking {
 			let vesting = Some(vec![(100_000u32.into(), 1_000u32.into(), 100u32.into())]);
 			let statement = StatementKind::Regular;
 			let signature = sig::<T>(&secret_key, &account.encode(), statement.to_text());
-			super::Module::<T>::mint_claim(RawOrigin::Root.into(), eth_address, VALUE.into(), vesting, Some(statement))?;
+			super::Module::<T>::mint_claim(RawOrigin::Root.into(), eth_address, VALUE.into(), vesting, Some(statement.id()))?;
 			assert_eq!(Claims::<T>::get(eth_address), Some(VALUE.into()));
 			let call = Call::<T>::claim_attest {
 			    dest: account.clone(),
@@ -1382,6 +2064,64 @@ mod benchmarking {
 			assert_eq!(Claims::<T>::get(eth_address), None);
 		}
 
+		// Benchmark `claim_typed` including `validate_unsigned` logic.
+		claim_typed {
+			let c = MAX_CLAIMS;
+
+			for i in 0 .. c / 2 {
+				create_claim::<T>(c)?;
+				create_claim_attest::<T>(u32::max_value() - c)?;
+			}
+
+			let secret_key = libsecp256k1::SecretKey::parse(&keccak_256(&c.encode())).unwrap();
+			let eth_address = eth(&secret_key);
+			let account: T::AccountId = account("user", c, SEED);
+			let vesting = Some(vec![(100_000u32.into(), 1_000u32.into(), 100u32.into())]);
+			let signature = typed_sig::<T>(&secret_key, &account.encode(), &[][..]);
+			super::Module::<T>::mint_claim(RawOrigin::Root.into(), eth_address, VALUE.into(), vesting, None)?;
+			assert_eq!(Claims::<T>::get(eth_address), Some(VALUE.into()));
+			let source = sp_runtime::transaction_validity::TransactionSource::External;
+			let call = Call::<T>::claim_typed { dest: account.clone(), ethereum_signature: signature.clone() };
+		}: {
+			super::Module::<T>::validate_unsigned(source, &call).map_err(|e| -> &'static str { e.into() })?;
+			super::Module::<T>::claim_typed(RawOrigin::None.into(), account, signature)?;
+		}
+		verify {
+			assert_eq!(Claims::<T>::get(eth_address), None);
+		}
+
+		// Benchmark `claim_attest_typed` including `validate_unsigned` logic.
+		claim_attest_typed {
+			let c = MAX_CLAIMS;
+
+			for i in 0 .. c / 2 {
+				create_claim::<T>(c)?;
+				create_claim_attest::<T>(u32::max_value() - c)?;
+			}
+
+			let attest_c = u32::max_value() - c;
+			let secret_key = libsecp256k1::SecretKey::parse(&keccak_256(&attest_c.encode())).unwrap();
+			let eth_address = eth(&secret_key);
+			let account: T::AccountId = account("user", c, SEED);
+			let vesting = Some(vec![(100_000u32.into(), 1_000u32.into(), 100u32.into())]);
+			let statement = StatementKind::Regular;
+			let signature = typed_sig::<T>(&secret_key, &account.encode(), statement.to_text());
+			super::Module::<T>::mint_claim(RawOrigin::Root.into(), eth_address, VALUE.into(), vesting, Some(statement.id()))?;
+			assert_eq!(Claims::<T>::get(eth_address), Some(VALUE.into()));
+			let call = Call::<T>::claim_attest_typed {
+			    dest: account.clone(),
+			    ethereum_signature: signature.clone(),
+			    statement: StatementKind::Regular.to_text().to_vec(),
+			};
+			let source = sp_runtime::transaction_validity::TransactionSource::External;
+		}: {
+			super::Module::<T>::validate_unsigned(source, &call).map_err(|e| -> &'static str { e.into() })?;
+			super::Module::<T>::claim_attest_typed(RawOrigin::None.into(), account, signature, statement.to_text().to_vec())?;
+		}
+		verify {
+			assert_eq!(Claims::<T>::get(eth_address), None);
+		}
+
 		// Benchmark `attest` including prevalidate logic.
 		attest {
 			let c = MAX_CLAIMS;
@@ -1398,7 +2138,7 @@ mod benchmarking {
 			let vesting = Some(vec![(100_000u32.into(), 1_000u32.into(), 100u32.into())]);
 			let statement = StatementKind::Regular;
 			let signature = sig::<T>(&secret_key, &account.encode(), statement.to_text());
-			super::Module::<T>::mint_claim(RawOrigin::Root.into(), eth_address, VALUE.into(), vesting, Some(statement))?;
+			super::Module::<T>::mint_claim(RawOrigin::Root.into(), eth_address, VALUE.into(), vesting, Some(statement.id()))?;
 			Preclaims::<T>::insert(&account, eth_address);
 			assert_eq!(Claims::<T>::get(eth_address), Some(VALUE.into()));
 
@@ -1408,7 +2148,7 @@ mod benchmarking {
 				if let Call::attest { statement: attested_statement } = call {
 					let signer = Preclaims::<T>::get(who).ok_or("signer has no claim")?;
 					if let Some(s) = Signing::get(signer) {
-						ensure!(&attested_statement[..] == s.to_text(), "invalid statement");
+						ensure!(super::Module::<T>::statement_matches(s, attested_statement), "invalid statement");
 					}
 				}
 				Ok(())
@@ -1447,6 +2187,45 @@ mod benchmarking {
 			assert!(Claims::<T>::contains_key(new_eth_address));
 		}
 
+		add_statement {
+			let l in 0 .. T::MaxStatementLength::get();
+			let id = StatementId::max_value();
+			let text = vec![b'x'; l as usize];
+		}: _(RawOrigin::Root, id, text, [0u8; 32])
+		verify {
+			assert!(Statements::contains_key(id));
+		}
+
+		remove_statement {
+			let id = StatementId::max_value();
+			Statements::insert(id, Statement::default());
+		}: _(RawOrigin::Root, id)
+		verify {
+			assert!(!Statements::contains_key(id));
+		}
+
+		set_claim_deadline {
+			let deadline = frame_system::Pallet::<T>::block_number() + 100u32.into();
+		}: _(RawOrigin::Root, deadline)
+		verify {
+			assert_eq!(ClaimDeadline::<T>::get(), Some(deadline));
+		}
+
+		sweep_claims {
+			let n in 1 .. 1_000;
+
+			for i in 0 .. n {
+				create_claim_attest::<T>(i)?;
+			}
+			let deadline = frame_system::Pallet::<T>::block_number() + 1u32.into();
+			ClaimDeadline::<T>::put(deadline);
+			frame_system::Pallet::<T>::set_block_number(deadline);
+			let caller: T::AccountId = account("caller", 0, SEED);
+		}: _(RawOrigin::Signed(caller), n)
+		verify {
+			assert_eq!(Claims::<T>::iter().count(), 0);
+		}
+
 		// Benchmark the time it takes to do `repeat` number of keccak256 hashes
 		#[extra]
 		keccak256 {
@@ -25,6 +25,12 @@ pub trait WeightInfo {
     fn claim_attest() -> Weight;
     fn attest() -> Weight;
     fn move_claim() -> Weight;
+    fn claim_typed() -> Weight;
+    fn claim_attest_typed() -> Weight;
+    fn set_claim_deadline() -> Weight;
+    fn sweep_claims(n: u32, ) -> Weight;
+    fn add_statement(l: u32, ) -> Weight;
+    fn remove_statement() -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -34,6 +40,12 @@ impl WeightInfo for TestWeightInfo {
     fn claim_attest() -> Weight { Weight::zero() }
     fn attest() -> Weight { Weight::zero() }
     fn move_claim() -> Weight { Weight::zero() }
+    fn claim_typed() -> Weight { Weight::zero() }
+    fn claim_attest_typed() -> Weight { Weight::zero() }
+    fn set_claim_deadline() -> Weight { Weight::zero() }
+    fn sweep_claims(_n: u32, ) -> Weight { Weight::zero() }
+    fn add_statement(_l: u32, ) -> Weight { Weight::zero() }
+    fn remove_statement() -> Weight { Weight::zero() }
 }
 
 /// Weight functions for claims.
@@ -41,7 +53,7 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn claim() -> Weight {
        Weight::from_ref_time(466_905_000 as u64)
-            .saturating_add(T::DbWeight::get().reads(7 as u64))
+            .saturating_add(T::DbWeight::get().reads(8 as u64))
             .saturating_add(T::DbWeight::get().writes(7 as u64))
     }
     fn mint_claim() -> Weight {
@@ -51,12 +63,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     }
     fn claim_attest() -> Weight {
        Weight::from_ref_time(471_915_000 as u64)
-            .saturating_add(T::DbWeight::get().reads(7 as u64))
+            .saturating_add(T::DbWeight::get().reads(9 as u64))
             .saturating_add(T::DbWeight::get().writes(7 as u64))
     }
     fn attest() -> Weight {
        Weight::from_ref_time(156_649_000 as u64)
-            .saturating_add(T::DbWeight::get().reads(8 as u64))
+            .saturating_add(T::DbWeight::get().reads(10 as u64))
             .saturating_add(T::DbWeight::get().writes(8 as u64))
     }
     fn move_claim() -> Weight {
@@ -64,4 +76,38 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(4 as u64))
             .saturating_add(T::DbWeight::get().writes(7 as u64))
     }
+    fn claim_typed() -> Weight {
+       Weight::from_ref_time(473_208_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(9 as u64))
+            .saturating_add(T::DbWeight::get().writes(7 as u64))
+    }
+    fn claim_attest_typed() -> Weight {
+       Weight::from_ref_time(478_362_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(10 as u64))
+            .saturating_add(T::DbWeight::get().writes(7 as u64))
+    }
+    fn set_claim_deadline() -> Weight {
+       Weight::from_ref_time(14_207_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(1 as u64))
+            .saturating_add(T::DbWeight::get().writes(1 as u64))
+    }
+    fn sweep_claims(n: u32, ) -> Weight {
+       Weight::from_ref_time(31_580_000 as u64)
+            .saturating_add(Weight::from_ref_time(16_412_000 as u64).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(8 as u64))
+            .saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+            .saturating_add(T::DbWeight::get().writes(4 as u64))
+            .saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+    }
+    fn add_statement(l: u32, ) -> Weight {
+       Weight::from_ref_time(17_834_000 as u64)
+            .saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+            .saturating_add(T::DbWeight::get().reads(1 as u64))
+            .saturating_add(T::DbWeight::get().writes(1 as u64))
+    }
+    fn remove_statement() -> Weight {
+       Weight::from_ref_time(16_920_000 as u64)
+            .saturating_add(T::DbWeight::get().reads(1 as u64))
+            .saturating_add(T::DbWeight::get().writes(1 as u64))
+    }
 }
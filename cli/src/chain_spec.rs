@@ -19,7 +19,7 @@
 //! Substrate chain configurations.
 
 use sc_chain_spec::{ChainSpecExtension, ChainType, ChainSpec};
-use sp_core::{Pair, Public, sr25519};
+use sp_core::{Pair, Public, sr25519, Get};
 use serde::{Serialize, Deserialize};
 
 #[cfg(feature = "xxnetwork")]
@@ -27,6 +27,7 @@ pub use xxnetwork_runtime as xxnetwork;
 #[cfg(feature = "canary")]
 pub use canary_runtime as canary;
 use runtime_common::constants::currency::UNITS;
+use runtime_common::{PoolsMaxMembers, PoolsMaxPools, PoolsMinJoinBond};
 use hex_literal::hex;
 use grandpa_primitives::{AuthorityId as GrandpaId};
 use sp_consensus_babe::{AuthorityId as BabeId};
@@ -310,6 +311,13 @@ pub fn canary_testnet_genesis(
 		},
 		xx_public: Default::default(),
 		assets: Default::default(),
+		nomination_pools: canary::NominationPoolsConfig {
+			min_join_bond: PoolsMinJoinBond::get(),
+			max_pools: Some(PoolsMaxPools::get()),
+			max_members_per_pool: None,
+			max_members: Some(PoolsMaxMembers::get()),
+			..Default::default()
+		},
 	}
 }
 
@@ -502,10 +510,25 @@ pub fn xxnetwork_testnet_genesis(
 			],
 		},
 		xx_betanet_rewards: xxnetwork::XXBetanetRewardsConfig {
+			programs: vec![
+				(
+					xxnetwork::BETANET_PROGRAM,
+					xxnetwork::betanet_program(
+						xxnetwork::BetanetStakingRewardsBlock::get(),
+						xxnetwork::ProgramStatus::Proposed,
+					)
+				)
+			],
+			next_program_id: xxnetwork::BETANET_PROGRAM + 1,
 			accounts: vec![
 				(
+					xxnetwork::BETANET_PROGRAM,
 					get_account_id_from_seed::<sr25519::Public>("Charlie"),
-					Default::default()
+					xxnetwork::UserInfo {
+						principal: 0,
+						reward: 0,
+						option: xxnetwork::BETANET_DEFAULT_OPTION,
+					}
 				)
 			]
 		},
@@ -516,6 +539,17 @@ pub fn xxnetwork_testnet_genesis(
 			sale_balance: 1000000 * UNITS,
 		},
 		assets: Default::default(),
+		nomination_pools: xxnetwork::NominationPoolsConfig {
+			min_join_bond: PoolsMinJoinBond::get(),
+			max_pools: Some(PoolsMaxPools::get()),
+			max_members_per_pool: None,
+			max_members: Some(PoolsMaxMembers::get()),
+			..Default::default()
+		},
+		tx_pause: xxnetwork::TxPauseConfig {
+			// Bridge starts paused until enabled by governance
+			paused_pallets: vec![b"ChainBridge".to_vec(), b"Swap".to_vec()],
+		},
 	}
 }
 
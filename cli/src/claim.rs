@@ -0,0 +1,295 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2017-2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Offline helpers for debugging Ethereum claims.
+
+use claims::{EcdsaSignature, EthereumAddress, Statement, StatementId, StatementKind};
+use codec::{Decode, Encode};
+use node_primitives::{AccountId, Balance, BlockNumber};
+use sc_cli::{CliConfiguration, DatabaseParams, Error, PruningParams, Result, SharedParams};
+use sp_core::{
+	bytes::from_hex,
+	crypto::Ss58Codec,
+	hashing::{keccak_256, twox_128, twox_64},
+	hexdisplay::HexDisplay,
+	Get,
+};
+use xxnetwork_runtime::{ClaimsChainName, Prefix};
+
+/// Claim debugging utilities.
+#[derive(Debug, clap::Subcommand)]
+pub enum ClaimCmd {
+	/// Print the message an Ethereum address must sign to claim into a given account.
+	Message(ClaimMessageCmd),
+
+	/// Verify a claim signature, recovering the Ethereum address that signed it.
+	Verify(ClaimVerifyCmd),
+
+	/// Report the claim of an Ethereum address from the local database or the chain spec.
+	Info(ClaimInfoCmd),
+}
+
+/// Parameters describing the message signed in a claim.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ClaimMessageParams {
+	/// Destination account of the claim, as SS58 address or hex encoded public key.
+	#[clap(long)]
+	pub dest: String,
+
+	/// ID of a default statement to attest to (0: regular, 1: SAFT).
+	#[clap(long, conflicts_with = "statement")]
+	pub statement_id: Option<StatementId>,
+
+	/// Text of the statement to attest to, if it isn't a default statement.
+	#[clap(long)]
+	pub statement: Option<String>,
+
+	/// Use the EIP-712 typed data message, as signed by `eth_signTypedData_v4`.
+	#[clap(long, requires = "genesis_hash")]
+	pub typed: bool,
+
+	/// Hex encoded genesis hash of the chain, required by typed data messages.
+	#[clap(long)]
+	pub genesis_hash: Option<String>,
+}
+
+impl ClaimMessageParams {
+	fn dest(&self) -> Result<AccountId> {
+		AccountId::from_ss58check(&self.dest).or_else(|_| {
+			let raw = from_hex(&self.dest).map_err(|_| Error::Input("Invalid destination account".into()))?;
+			let raw: [u8; 32] = raw.try_into().map_err(|_| Error::Input("Invalid destination account length".into()))?;
+			Ok(AccountId::from(raw))
+		})
+	}
+
+	fn statement(&self) -> Result<Vec<u8>> {
+		match (self.statement_id, &self.statement) {
+			(Some(id), _) => [StatementKind::Regular, StatementKind::Saft].iter()
+				.find(|kind| kind.id() == id)
+				.map(|kind| kind.to_text().to_vec())
+				.ok_or_else(|| Error::Input(format!("Unknown default statement {}", id))),
+			(None, Some(text)) => Ok(text.as_bytes().to_vec()),
+			(None, None) => Ok(Vec::new()),
+		}
+	}
+
+	fn genesis_hash(&self) -> Result<[u8; 32]> {
+		let hash = self.genesis_hash.as_ref().ok_or_else(|| Error::Input("Missing genesis hash".into()))?;
+		from_hex(hash).ok()
+			.and_then(|raw| raw.try_into().ok())
+			.ok_or_else(|| Error::Input("Invalid genesis hash".into()))
+	}
+
+	// Hash that is signed in the claim, either as personal message or as typed data.
+	fn signed_hash(&self) -> Result<[u8; 32]> {
+		let dest = self.dest()?.encode();
+		let statement = self.statement()?;
+		if self.typed {
+			let domain = claims::eip712_domain_separator(ClaimsChainName::get(), &self.genesis_hash()?);
+			Ok(claims::eip712_claim_hash(&domain, &dest, &statement))
+		} else {
+			let message = claims::ethereum_signable_message(Prefix::get(), &claims::to_ascii_hex(&dest), &statement);
+			Ok(keccak_256(&message))
+		}
+	}
+}
+
+/// The `claim message` command
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ClaimMessageCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub message: ClaimMessageParams,
+}
+
+impl ClaimMessageCmd {
+	/// Run the command
+	pub fn run(&self) -> Result<()> {
+		let params = &self.message;
+		let dest = params.dest()?.encode();
+		let statement = params.statement()?;
+		if params.typed {
+			let typed_data = serde_json::json!({
+				"types": {
+					"EIP712Domain": [
+						{ "name": "name", "type": "string" },
+						{ "name": "version", "type": "string" },
+						{ "name": "salt", "type": "bytes32" },
+					],
+					"Claim": [
+						{ "name": "destination", "type": "bytes" },
+						{ "name": "statementHash", "type": "bytes32" },
+					],
+				},
+				"primaryType": "Claim",
+				"domain": {
+					"name": String::from_utf8_lossy(ClaimsChainName::get()),
+					"version": String::from_utf8_lossy(claims::EIP712_DOMAIN_VERSION),
+					"salt": format!("0x{}", HexDisplay::from(&params.genesis_hash()?)),
+				},
+				"message": {
+					"destination": format!("0x{}", HexDisplay::from(&dest)),
+					"statementHash": format!("0x{}", HexDisplay::from(&keccak_256(&statement))),
+				},
+			});
+			println!("Typed data: {}", typed_data);
+		} else {
+			let mut message = Prefix::get().to_vec();
+			message.extend(claims::to_ascii_hex(&dest));
+			message.extend(&statement);
+			println!("Message: {}", String::from_utf8_lossy(&message));
+		}
+		println!("Signed hash: 0x{}", HexDisplay::from(&params.signed_hash()?));
+		Ok(())
+	}
+}
+
+/// The `claim verify` command
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ClaimVerifyCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub message: ClaimMessageParams,
+
+	/// Hex encoded 65 byte Ethereum signature of the claim.
+	#[clap(long)]
+	pub signature: String,
+
+	/// Ethereum address expected to have signed the claim.
+	#[clap(long)]
+	pub address: Option<String>,
+}
+
+impl ClaimVerifyCmd {
+	/// Run the command
+	pub fn run(&self) -> Result<()> {
+		let signature: [u8; 65] = from_hex(&self.signature).ok()
+			.and_then(|raw| raw.try_into().ok())
+			.ok_or_else(|| Error::Input("Invalid signature".into()))?;
+		let hash = self.message.signed_hash()?;
+		let signer = claims::eth_recover_hash(&EcdsaSignature(signature), &hash)
+			.ok_or_else(|| Error::Input("Signature is invalid, no address could be recovered".into()))?;
+		println!("Recovered signer: 0x{}", HexDisplay::from(&signer.0));
+		if let Some(address) = &self.address {
+			if parse_address(address)? != signer {
+				return Err(Error::SignatureInvalid)
+			}
+			println!("Signature verifies correctly.");
+		}
+		Ok(())
+	}
+}
+
+/// The `claim info` command
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ClaimInfoCmd {
+	/// Ethereum address of the claim.
+	#[clap(long)]
+	pub address: String,
+
+	/// Read the claim from the genesis state of the chain spec, instead of the local database.
+	#[clap(long)]
+	pub genesis: bool,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl ClaimInfoCmd {
+	/// Run the command, reading storage values with `read`.
+	pub fn run(&self, read: impl Fn(&[u8]) -> Option<Vec<u8>>) -> Result<()> {
+		let address = parse_address(&self.address)?;
+		let get = |item: &[u8], key: Vec<u8>| -> Option<Vec<u8>> {
+			let mut k = twox_128(b"Claims").to_vec();
+			k.extend(twox_128(item));
+			k.extend(key);
+			read(&k)
+		};
+		let decode = |raw: Vec<u8>| Balance::decode(&mut &raw[..]).ok();
+
+		let amount = get(b"Claims", address.encode()).and_then(decode);
+		let reward = get(b"Rewards", address.encode()).and_then(decode);
+		let vesting = get(b"Vesting", address.encode())
+			.and_then(|raw| Vec::<(Balance, Balance, BlockNumber)>::decode(&mut &raw[..]).ok());
+		let signing = get(b"Signing", address.encode())
+			.and_then(|raw| StatementId::decode(&mut &raw[..]).ok());
+		let attested = get(b"Attestations", address.encode())
+			.and_then(|raw| StatementId::decode(&mut &raw[..]).ok());
+
+		match amount {
+			Some(amount) => println!("Claim amount: {}", amount),
+			None => {
+				println!("No claim for 0x{}", HexDisplay::from(&address.0));
+				if let Some(id) = attested {
+					println!("Already claimed, attesting to statement {}", id);
+				}
+				return Ok(())
+			},
+		}
+		println!("Reward: {}", reward.unwrap_or_default());
+		for (locked, per_block, starting_block) in vesting.unwrap_or_default() {
+			println!("Vesting: {} locked, {} unlocked per block from block {}", locked, per_block, starting_block);
+		}
+		match signing {
+			Some(id) => {
+				let key = id.encode();
+				let mut hashed = twox_64(&key).to_vec();
+				hashed.extend(key);
+				match get(b"Statements", hashed).and_then(|raw| Statement::decode(&mut &raw[..]).ok()) {
+					Some(statement) => {
+						println!("Statement {}: {}", id, String::from_utf8_lossy(&statement.text));
+						println!("Document hash: 0x{}", HexDisplay::from(&statement.document_hash));
+					},
+					None => println!("Statement {} is not registered", id),
+				}
+			},
+			None => println!("No statement required"),
+		}
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ClaimInfoCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
+
+fn parse_address(address: &str) -> Result<EthereumAddress> {
+	from_hex(address).ok()
+		.and_then(|raw| raw.try_into().ok())
+		.map(EthereumAddress)
+		.ok_or_else(|| Error::Input("Invalid Ethereum address".into()))
+}
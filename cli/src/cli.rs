@@ -74,6 +74,11 @@ pub enum Subcommand {
 	/// Sign a message, with a given (secret) key.
 	Sign(sc_cli::SignCmd),
 
+	/// Build and verify Ethereum claim messages, and inspect claims.
+	#[cfg(feature = "xxnetwork")]
+	#[clap(subcommand)]
+	Claim(crate::claim::ClaimCmd),
+
 	/// Build a chain specification.
 	BuildSpec(sc_cli::BuildSpecCmd),
 
@@ -22,6 +22,14 @@ use sc_service::PartialComponents;
 use crate::chain_spec::IdentifyVariant;
 use frame_benchmarking_cli::*;
 use std::sync::Arc;
+#[cfg(feature = "xxnetwork")]
+use crate::claim::ClaimCmd;
+#[cfg(feature = "xxnetwork")]
+use sc_client_api::StorageProvider;
+#[cfg(feature = "xxnetwork")]
+use sp_core::storage::StorageKey;
+#[cfg(feature = "xxnetwork")]
+use sp_runtime::BuildStorage;
 
 impl SubstrateCli for Cli {
 	fn impl_name() -> String {
@@ -177,6 +185,25 @@ pub fn run() -> Result<()> {
 		Some(Subcommand::Key(cmd)) => cmd.run(&cli),
 		Some(Subcommand::Sign(cmd)) => cmd.run(),
 		Some(Subcommand::Verify(cmd)) => cmd.run(),
+		#[cfg(feature = "xxnetwork")]
+		Some(Subcommand::Claim(cmd)) => match cmd {
+			ClaimCmd::Message(cmd) => cmd.run(),
+			ClaimCmd::Verify(cmd) => cmd.run(),
+			ClaimCmd::Info(cmd) => {
+				let runner = cli.create_runner(cmd)?;
+				runner.sync_run(|config| {
+					if cmd.genesis {
+						let storage = config.chain_spec.build_storage()?;
+						cmd.run(|key| storage.top.get(key).cloned())
+					} else {
+						let PartialComponents { client, .. }
+							= service::new_partial::<service::XXNetworkRuntimeApi, service::XXNetworkExecutorDispatch>(&config)?;
+						let best_hash = client.chain_info().best_hash;
+						cmd.run(|key| client.storage(best_hash, &StorageKey(key.to_vec())).ok().flatten().map(|data| data.0))
+					}
+				})
+			},
+		},
 		Some(Subcommand::Vanity(cmd)) => cmd.run(),
 		Some(Subcommand::BuildSpec(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
@@ -34,6 +34,8 @@ mod service;
 mod cli;
 #[cfg(feature = "cli")]
 mod command;
+#[cfg(all(feature = "cli", feature = "xxnetwork"))]
+mod claim;
 
 #[cfg(feature = "cli")]
 pub use cli::*;
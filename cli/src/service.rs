@@ -21,7 +21,7 @@
 //! Service implementation. Specialized wrapper over substrate service.
 
 use futures::prelude::*;
-use node_primitives::{AccountId, Block, Balance, Index};
+use node_primitives::{AccountId, Block, BlockNumber, Balance, Index};
 use sc_client_api::BlockBackend;
 use sc_consensus_babe::{self, SlotProportion};
 use sc_executor::{NativeElseWasmExecutor, NativeExecutionDispatch};
@@ -73,6 +73,7 @@ pub trait RuntimeApiCollection:
 	+ sp_offchain::OffchainWorkerApi<Block>
 	+ sp_session::SessionKeys<Block>
 	+ sp_authority_discovery::AuthorityDiscoveryApi<Block>
+	+ chainbridge_runtime_api::ChainBridgeApi<Block, AccountId, Balance, BlockNumber>
 	where
 		<Self as sp_api::ApiExt<Block>>::StateBackend: StateBackend<BlakeTwo256>,
 {
@@ -90,7 +91,8 @@ where
 		+ sp_api::Metadata<Block>
 		+ sp_offchain::OffchainWorkerApi<Block>
 		+ sp_session::SessionKeys<Block>
-		+ sp_authority_discovery::AuthorityDiscoveryApi<Block>,
+		+ sp_authority_discovery::AuthorityDiscoveryApi<Block>
+		+ chainbridge_runtime_api::ChainBridgeApi<Block, AccountId, Balance, BlockNumber>,
 	<Self as sp_api::ApiExt<Block>>::StateBackend: StateBackend<BlakeTwo256>,
 {
 }
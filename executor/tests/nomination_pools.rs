@@ -0,0 +1,109 @@
+use frame_support::{assert_ok, traits::Currency};
+use pallet_staking::{EraRewardPoints, Exposure, IndividualExposure};
+use xxnetwork_runtime::{
+	Runtime, RuntimeOrigin, Balances, NominationPools, Staking, XXEconomics,
+};
+use runtime_common::constants::currency::*;
+use node_primitives::{AccountId, Balance};
+use node_testing::keyring::*;
+
+pub mod common;
+use self::common::*;
+
+const ERA: u32 = 0;
+const POOL_ID: u32 = 1;
+const DEPOSITOR_BOND: Balance = 50 * UNITS;
+const MEMBER_BOND: Balance = 50 * UNITS;
+// Half goes to the validator and half to the pool, which has half of the exposure
+const ERA_PAYOUT: Balance = 4 * UNITS;
+const POOL_PAYOUT: Balance = ERA_PAYOUT / 2;
+
+// Creates a pool with charlie as depositor and bob as member, nominating dave
+fn create_nominating_pool() -> AccountId {
+	assert_ok!(NominationPools::create(
+		RuntimeOrigin::signed(charlie()),
+		DEPOSITOR_BOND,
+		charlie().into(),
+		charlie().into(),
+		charlie().into(),
+	));
+	assert_ok!(NominationPools::join(RuntimeOrigin::signed(bob()), MEMBER_BOND, POOL_ID));
+	assert_ok!(NominationPools::nominate(RuntimeOrigin::signed(charlie()), POOL_ID, vec![dave()]));
+
+	let bonded = NominationPools::create_bonded_account(POOL_ID);
+	assert_eq!(Staking::nominators(&bonded).unwrap().targets.into_inner(), vec![dave()]);
+	bonded
+}
+
+// Rewards dave for the era, with the pool backing half of its stake
+fn reward_validator(pool_bonded: AccountId) {
+	let stake = DEPOSITOR_BOND + MEMBER_BOND;
+	let exposure = Exposure {
+		total: 2 * stake,
+		own: stake,
+		others: vec![IndividualExposure { who: pool_bonded, value: stake }],
+	};
+	pallet_staking::ErasStakers::<Runtime>::insert(ERA, dave(), exposure.clone());
+	pallet_staking::ErasStakersClipped::<Runtime>::insert(ERA, dave(), exposure);
+	pallet_staking::ErasValidatorPrefs::<Runtime>::insert(ERA, dave(), pallet_staking::ValidatorPrefs::default());
+	pallet_staking::ErasValidatorReward::<Runtime>::insert(ERA, ERA_PAYOUT);
+	pallet_staking::ErasRewardPoints::<Runtime>::insert(ERA, EraRewardPoints {
+		total: 1,
+		individual: vec![(dave(), 1)].into_iter().collect(),
+	});
+	assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(alice()), dave(), ERA));
+}
+
+// Claims the payout of both pool members, checking they get the whole pool payout
+fn claim_pool_payout() {
+	let reward_account = NominationPools::create_reward_account(POOL_ID);
+	assert_eq!(Balances::free_balance(&reward_account), Balances::minimum_balance() + POOL_PAYOUT);
+
+	let charlie_balance = Balances::free_balance(&charlie());
+	let bob_balance = Balances::free_balance(&bob());
+	assert_ok!(NominationPools::claim_payout(RuntimeOrigin::signed(charlie())));
+	assert_ok!(NominationPools::claim_payout(RuntimeOrigin::signed(bob())));
+
+	// Members have equal points, so the payout is split in half
+	assert_eq!(Balances::free_balance(&charlie()), charlie_balance + POOL_PAYOUT / 2);
+	assert_eq!(Balances::free_balance(&bob()), bob_balance + POOL_PAYOUT / 2);
+	assert_eq!(Balances::free_balance(&reward_account), Balances::minimum_balance());
+}
+
+#[test]
+fn pool_members_claim_payout_from_rewards_pool() {
+	let mut t = new_test_ext(compact_code_unwrap());
+	t.execute_with(|| {
+		let rewards_balance = 1_000 * UNITS;
+		Balances::make_free_balance_be(&XXEconomics::rewards_account_id(), rewards_balance);
+		let bonded = create_nominating_pool();
+		let issuance = Balances::total_issuance();
+
+		reward_validator(bonded);
+
+		// Payout is taken from the rewards pool, without minting
+		assert_eq!(XXEconomics::rewards_balance(), rewards_balance - ERA_PAYOUT);
+		assert_eq!(Balances::total_issuance(), issuance);
+
+		claim_pool_payout();
+	});
+}
+
+#[test]
+fn pool_members_claim_payout_when_rewards_pool_depleted() {
+	let mut t = new_test_ext(compact_code_unwrap());
+	t.execute_with(|| {
+		let rewards_balance = 1 * UNITS;
+		Balances::make_free_balance_be(&XXEconomics::rewards_account_id(), rewards_balance);
+		let bonded = create_nominating_pool();
+		let issuance = Balances::total_issuance();
+
+		reward_validator(bonded);
+
+		// Rewards pool is emptied and the remainder is minted
+		assert_eq!(XXEconomics::rewards_balance(), 0);
+		assert_eq!(Balances::total_issuance(), issuance + ERA_PAYOUT - rewards_balance);
+
+		claim_pool_payout();
+	});
+}
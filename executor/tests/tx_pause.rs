@@ -0,0 +1,60 @@
+use frame_support::{assert_noop, assert_ok, dispatch::Dispatchable};
+use sp_runtime::{traits::BadOrigin, DispatchError};
+use xxnetwork_runtime::{Runtime, RuntimeCall, RuntimeOrigin, TxPause};
+use node_primitives::AccountId;
+use node_testing::keyring::*;
+
+pub mod common;
+use self::common::*;
+
+// Two thirds of the technical committee, which can pause but not resume
+fn technical_committee() -> RuntimeOrigin {
+	pallet_collective::RawOrigin::<AccountId, pallet_collective::Instance2>::Members(2, 3).into()
+}
+
+// Two thirds of the council, which can both pause and resume
+fn council() -> RuntimeOrigin {
+	pallet_collective::RawOrigin::<AccountId, pallet_collective::Instance1>::Members(2, 3).into()
+}
+
+fn transfer_call() -> RuntimeCall {
+	RuntimeCall::Balances(default_transfer_call())
+}
+
+#[test]
+fn council_can_resume_pallet_paused_by_technical_committee() {
+	let mut t = new_test_ext(compact_code_unwrap());
+	t.execute_with(|| {
+		assert_ok!(TxPause::pause_pallet(technical_committee(), b"Balances".to_vec()));
+		assert_noop!(
+			transfer_call().dispatch(RuntimeOrigin::signed(alice())),
+			DispatchError::from(frame_system::Error::<Runtime>::CallFiltered)
+		);
+
+		// Technical committee can't undo its own pause
+		assert_noop!(TxPause::resume_pallet(technical_committee(), b"Balances".to_vec()), BadOrigin);
+
+		assert_ok!(TxPause::resume_pallet(council(), b"Balances".to_vec()));
+		assert!(!TxPause::pallet_paused(b"Balances".to_vec()));
+		assert_ok!(transfer_call().dispatch(RuntimeOrigin::signed(alice())));
+	});
+}
+
+#[test]
+fn council_can_resume_call_paused_by_technical_committee() {
+	let mut t = new_test_ext(compact_code_unwrap());
+	t.execute_with(|| {
+		assert_ok!(TxPause::pause_call(technical_committee(), b"Balances".to_vec(), b"transfer".to_vec()));
+		assert_noop!(
+			transfer_call().dispatch(RuntimeOrigin::signed(alice())),
+			DispatchError::from(frame_system::Error::<Runtime>::CallFiltered)
+		);
+
+		assert_noop!(
+			TxPause::resume_call(technical_committee(), b"Balances".to_vec(), b"transfer".to_vec()),
+			BadOrigin
+		);
+		assert_ok!(TxPause::resume_call(council(), b"Balances".to_vec(), b"transfer".to_vec()));
+		assert_ok!(transfer_call().dispatch(RuntimeOrigin::signed(alice())));
+	});
+}
@@ -0,0 +1,107 @@
+use frame_support::{
+    weights::Weight,
+    storage::migration::{storage_key_iter, take_storage_value},
+    traits::{OnRuntimeUpgrade, Get, StorageVersion},
+    Twox64Concat, StorageValue, StorageMap, StorageDoubleMap,
+};
+use sp_std::prelude::*;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::{traits::OnRuntimeUpgradeHelpersExt, IterableStorageDoubleMap};
+
+use xx_betanet_rewards::{
+    Config, Module, Accounts, Programs, NextProgramId, Enactments, UserInfo, ProgramStatus,
+    BETANET_PROGRAM, betanet_program,
+};
+use claims::BalanceOf;
+
+const PALLET: &[u8] = b"XXBetanetRewards";
+
+/// Move the BetaNet Staking Rewards into the first rewards program
+///
+/// `Accounts` was a map of account to user info, and is now keyed by program first.
+/// The reward option enum is encoded the same way as the option index in the betanet options table,
+/// so user info is not changed.
+/// `Approved` is replaced by the program status.
+pub struct BetanetRewardsProgramMigration<T: Config, EnactmentBlock: Get<T::BlockNumber>>(
+    sp_std::marker::PhantomData<(T, EnactmentBlock)>
+);
+
+impl<T: Config, EnactmentBlock: Get<T::BlockNumber>> OnRuntimeUpgrade for BetanetRewardsProgramMigration<T, EnactmentBlock> {
+    fn on_runtime_upgrade() -> Weight {
+        // Programs are only created once, storage version is set to 1 after that
+        if StorageVersion::get::<Module<T>>() >= 1 {
+            log::info!(
+                target: "runtime::migrations::betanet_rewards",
+                "Betanet rewards program migration already applied, skipping"
+            );
+            return T::DbWeight::get().reads(1)
+        }
+
+        // 1. Drain old accounts, before inserting them with the new keys, since both share the same prefix
+        let accounts: Vec<(T::AccountId, UserInfo<BalanceOf<T>>)> =
+            storage_key_iter::<T::AccountId, UserInfo<BalanceOf<T>>, Twox64Concat>(PALLET, b"Accounts")
+                .drain()
+                .collect();
+        let count = accounts.len() as u64;
+        accounts.into_iter().for_each(|(account, info)| {
+            Accounts::<T>::insert(BETANET_PROGRAM, account, info);
+        });
+
+        // 2. Create betanet program, with status based on the old approval
+        let approved = take_storage_value::<bool>(PALLET, b"Approved", &[]).unwrap_or(false);
+        let enactment_block = EnactmentBlock::get();
+        let now = <frame_system::Pallet<T>>::block_number();
+        let status = if !approved {
+            ProgramStatus::Proposed
+        } else if now >= enactment_block {
+            ProgramStatus::Enacted
+        } else {
+            Enactments::<T>::append(enactment_block, BETANET_PROGRAM);
+            ProgramStatus::Approved
+        };
+        Programs::<T>::insert(BETANET_PROGRAM, betanet_program(enactment_block, status));
+        NextProgramId::put(BETANET_PROGRAM + 1);
+        StorageVersion::new(1).put::<Module<T>>();
+
+        log::info!(
+            target: "runtime::migrations::betanet_rewards",
+            "Migrated {} accounts into betanet rewards program with status {:?}", count, status
+        );
+
+        T::DbWeight::get().reads_writes(count * 2 + 4, count * 2 + 6)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<(), &'static str> {
+        // Store number of accounts before upgrade
+        let count = storage_key_iter::<T::AccountId, UserInfo<BalanceOf<T>>, Twox64Concat>(PALLET, b"Accounts")
+            .count() as u32;
+        Self::set_temp_storage(count, "betanet_accounts_count");
+        log::debug!(
+            target: "runtime::migrations::betanet_rewards",
+            "Pre upgrade: found {} betanet rewards accounts", count
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        let count = Self::get_temp_storage::<u32>("betanet_accounts_count").unwrap();
+        // Check all accounts were moved into the betanet program
+        assert!(
+            Accounts::<T>::iter_prefix(BETANET_PROGRAM).count() as u32 == count,
+            "Size mismatch in betanet rewards accounts after upgrade"
+        );
+        assert!(Programs::<T>::contains_key(BETANET_PROGRAM), "Betanet program not created after upgrade");
+        assert!(
+            StorageVersion::get::<Module<T>>() == 1,
+            "Betanet rewards storage version not updated after upgrade"
+        );
+        log::debug!(
+            target: "runtime::migrations::betanet_rewards",
+            "Post upgrade: checks completed, migrated {} betanet rewards accounts", count
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use frame_support::{
+    weights::Weight,
+    storage::migration::take_storage_value,
+    traits::{OnRuntimeUpgrade, Get, ReservableCurrency, StorageVersion},
+    IterableStorageDoubleMap, IterableStorageMap, StorageMap,
+};
+use sp_runtime::traits::Zero;
+use sp_std::prelude::*;
+
+use chainbridge::{Config, Module, WeightInfo, RelayerBonds, RelayerSet, RelayerSetConfig, RelayerSets, Relayers, Votes};
+
+/// Prune stale chainbridge proposals
+///
+/// Proposal votes used to be kept forever. New stale proposals are now pruned when blocks
+/// have weight to spare, so this removes the ones that accumulated before.
+pub struct ChainbridgeVotesMigration<T: Config>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for ChainbridgeVotesMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        // Existing proposals are only pruned once, storage version is set to 1 after that
+        if StorageVersion::get::<Module<T>>() >= 1 {
+            log::info!(
+                target: "runtime::migrations::chainbridge",
+                "Chainbridge votes migration already applied, skipping"
+            );
+            return T::DbWeight::get().reads(1)
+        }
+
+        let now = <frame_system::Pallet<T>>::block_number();
+        let mut checked = 0u32;
+        let stale = Votes::<T>::iter()
+            .filter(|(_, _, votes)| {
+                checked += 1;
+                Module::<T>::is_stale(votes, now)
+            })
+            .collect::<Vec<_>>();
+        let pruned = stale.len();
+        stale.into_iter().for_each(|(src_id, (nonce, prop), votes)| {
+            Module::<T>::remove_proposal(src_id, nonce, prop, &votes);
+        });
+        StorageVersion::new(1).put::<Module<T>>();
+
+        log::info!(
+            target: "runtime::migrations::chainbridge",
+            "Pruned {} stale proposals out of {}", pruned, checked
+        );
+        // Same work as pruning all proposals in `on_idle`, where every proposal checked can be removed
+        <T as Config>::WeightInfo::prune_proposals(checked)
+            .saturating_add(T::DbWeight::get().reads_writes(1, 1))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        // Check no stale proposals are left
+        let now = <frame_system::Pallet<T>>::block_number();
+        let count = Votes::<T>::iter().count();
+        assert!(
+            Votes::<T>::iter().all(|(_, _, votes)| !Module::<T>::is_stale(&votes, now)),
+            "Stale proposals left after upgrade"
+        );
+        assert!(StorageVersion::get::<Module<T>>() == 1, "Chainbridge storage version not updated after upgrade");
+        log::debug!(
+            target: "runtime::migrations::chainbridge",
+            "Post upgrade: checks completed, {} proposals left", count
+        );
+        Ok(())
+    }
+}
+
+/// Move the chainbridge relayers into the default relayer set
+///
+/// The vote threshold and relayer count used to be global. They are now kept per relayer set,
+/// so the existing relayers and threshold become the default set, used by all chains that
+/// don't have their own set.
+///
+/// Relayers also need a bond now, so it can be slashed. The bond is reserved from the existing
+/// relayers, and those that can't afford it are removed and need to be registered again.
+/// The threshold is lowered to the number of relayers left if it can't be reached anymore.
+pub struct ChainbridgeRelayerSetsMigration<T: Config>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for ChainbridgeRelayerSetsMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if StorageVersion::get::<Module<T>>() >= 2 {
+            log::info!(
+                target: "runtime::migrations::chainbridge",
+                "Chainbridge relayer sets migration already applied, skipping"
+            );
+            return T::DbWeight::get().reads(1)
+        }
+
+        let threshold = take_storage_value::<u32>(b"ChainBridge", b"RelayerThreshold", &[])
+            .unwrap_or(RelayerSetConfig::default().threshold);
+        let _ = take_storage_value::<u32>(b"ChainBridge", b"RelayerCount", &[]);
+        let bond = T::RelayerBond::get();
+        let mut migrated = 0u64;
+        let mut relayer_count = 0u32;
+        let mut removed = Vec::new();
+        Relayers::<T>::translate::<bool, _>(|who, relayer| {
+            migrated += 1;
+            if !relayer {
+                return None
+            }
+            if !bond.is_zero() {
+                if T::Currency::reserve(&who, bond).is_err() {
+                    log::warn!(
+                        target: "runtime::migrations::chainbridge",
+                        "Relayer {:?} can't afford the bond, removed", who
+                    );
+                    removed.push(who);
+                    return None
+                }
+                RelayerBonds::<T>::insert(&who, bond);
+            }
+            relayer_count += 1;
+            Some(RelayerSet::Default)
+        });
+        // Keep the threshold reachable by the relayers left
+        let threshold = if threshold > relayer_count.max(1) {
+            let clamped = relayer_count.max(1);
+            log::warn!(
+                target: "runtime::migrations::chainbridge",
+                "Removed relayers {:?}, threshold lowered from {} to {}", removed, threshold, clamped
+            );
+            clamped
+        } else {
+            threshold
+        };
+        RelayerSets::insert(RelayerSet::Default, RelayerSetConfig { threshold, relayer_count });
+        StorageVersion::new(2).put::<Module<T>>();
+
+        log::info!(
+            target: "runtime::migrations::chainbridge",
+            "Moved {} relayers into the default set, with threshold {}, removed {}",
+            relayer_count, threshold, removed.len()
+        );
+        // Each relayer is read and written, with its account and bond when bonded
+        T::DbWeight::get().reads_writes(3 + 2 * migrated, 4 + 3 * migrated)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        // Check all relayers are bonded, in the default set and counted
+        let count = Relayers::<T>::iter().count() as u32;
+        assert!(
+            Relayers::<T>::iter().all(|(_, set)| set == RelayerSet::Default),
+            "Relayers left outside the default set after upgrade"
+        );
+        assert!(
+            Relayers::<T>::iter_keys().all(|who| RelayerBonds::<T>::get(&who) == T::RelayerBond::get()),
+            "Relayers without a bond after upgrade"
+        );
+        let config = RelayerSets::get(RelayerSet::Default);
+        assert_eq!(config.relayer_count, count, "Relayer count doesn't match the default set after upgrade");
+        assert!(
+            count == 0 || config.threshold <= count,
+            "Relayer threshold can't be reached by the default set after upgrade"
+        );
+        assert!(StorageVersion::get::<Module<T>>() == 2, "Chainbridge storage version not updated after upgrade");
+        log::debug!(
+            target: "runtime::migrations::chainbridge",
+            "Post upgrade: checks completed, {} relayers in the default set", count
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use frame_support::{
+    weights::Weight,
+    traits::{OnRuntimeUpgrade, Get, StorageVersion},
+    StorageMap,
+};
+
+#[cfg(feature = "try-runtime")]
+use frame_support::IterableStorageMap;
+
+use claims::{Config, Module, Statements, default_statements};
+
+/// Register the default claim statements
+///
+/// Statements were hard-coded in `StatementKind` and are now managed in storage.
+/// `Signing` stored the statement kind enum, which is encoded the same way as its statement ID,
+/// so it is not changed.
+pub struct ClaimsStatementsMigration<T: Config>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for ClaimsStatementsMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        // Statements are only registered once, storage version is set to 1 after that
+        if StorageVersion::get::<Module<T>>() >= 1 {
+            log::info!(
+                target: "runtime::migrations::claims",
+                "Claims statements migration already applied, skipping"
+            );
+            return T::DbWeight::get().reads(1)
+        }
+
+        let statements = default_statements();
+        let writes = statements.len() as u64;
+        statements.into_iter().for_each(|(id, statement)| {
+            Statements::insert(id, statement);
+        });
+        StorageVersion::new(1).put::<Module<T>>();
+
+        T::DbWeight::get().reads_writes(1, writes + 1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        // Check default statements are registered
+        let count = Statements::iter().count();
+        assert!(count == default_statements().len(), "Default statements not registered after upgrade");
+        assert!(StorageVersion::get::<Module<T>>() == 1, "Claims storage version not updated after upgrade");
+        log::debug!(
+            target: "runtime::migrations::claims",
+            "Post upgrade: checks completed, registered {} statements", count
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use codec::{Encode, Decode, HasCompact};
+use frame_support::{
+    weights::Weight,
+    storage::StoragePrefixedMap,
+    traits::{OnRuntimeUpgrade, Get, StorageVersion},
+};
+use sp_runtime::{RuntimeDebug, traits::Zero};
+
+#[cfg(feature = "try-runtime")]
+use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+use xx_team_custody::{Config, Module, TeamAccounts, BalanceOf, custody::CustodyInfo};
+
+/// Custody info before staking rewards were accounted separately
+#[derive(Encode, Decode, RuntimeDebug)]
+struct OldCustodyInfo<AccountId, Balance: HasCompact> {
+    #[codec(compact)]
+    allocation: Balance,
+    #[codec(compact)]
+    vested: Balance,
+    custody: AccountId,
+    reserve: AccountId,
+}
+
+pub struct CustodyRewardsMigration<T: Config>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for CustodyRewardsMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        // Custody info is only translated once, storage version is set to 1 after that
+        if StorageVersion::get::<Module<T>>() >= 1 {
+            log::info!(
+                target: "runtime::migrations::custody",
+                "Custody rewards migration already applied, skipping"
+            );
+            return T::DbWeight::get().reads(1)
+        }
+
+        let mut reads_writes = 0;
+        TeamAccounts::<T>::translate_values::<OldCustodyInfo<T::AccountId, BalanceOf<T>>, _>(
+            |old| {
+                reads_writes += 1;
+                Some(CustodyInfo {
+                    allocation: old.allocation,
+                    vested: old.vested,
+                    custody: old.custody,
+                    reserve: old.reserve,
+                    rewards: Zero::zero(),
+                })
+            }
+        );
+        StorageVersion::new(1).put::<Module<T>>();
+
+        T::DbWeight::get().reads_writes(reads_writes + 1, reads_writes + 1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<(), &'static str> {
+        // Store number of team accounts before upgrade
+        let count = TeamAccounts::<T>::iter_keys().count() as u32;
+        Self::set_temp_storage(count, "team_accounts_count");
+        log::debug!(
+            target: "runtime::migrations::custody",
+            "Pre upgrade: found {} team accounts", count
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        let count = Self::get_temp_storage::<u32>("team_accounts_count").unwrap();
+        // Check all entries were decoded with the new format, and start with no rewards paid out
+        let infos: sp_std::vec::Vec<_> = TeamAccounts::<T>::iter_values().collect();
+        assert!(infos.len() as u32 == count, "Size mismatch in team accounts after upgrade");
+        assert!(infos.iter().all(|info| info.rewards.is_zero()), "Rewards of a team account not zero after upgrade");
+        assert!(StorageVersion::get::<Module<T>>() == 1, "Custody storage version not updated after upgrade");
+        log::debug!(
+            target: "runtime::migrations::custody",
+            "Post upgrade: checks completed, migrated {} team accounts", count
+        );
+        Ok(())
+    }
+}
@@ -1,3 +1,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod betanet_rewards;
+pub mod chainbridge;
+pub mod claims;
+pub mod custody;
+pub mod nomination_pools;
+pub mod swap;
+pub mod tx_pause;
 pub mod vesting;
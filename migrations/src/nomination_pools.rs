@@ -0,0 +1,58 @@
+use frame_support::{
+    weights::Weight,
+    traits::{OnRuntimeUpgrade, Get, GetStorageVersion, StorageVersion},
+};
+
+use pallet_nomination_pools::{BalanceOf, Config, MaxPoolMembers, MaxPools, MinJoinBond, Pallet};
+
+/// Set up nomination pools when added to a live chain
+///
+/// A pallet added in a runtime upgrade has no storage version on chain, so the current one is put
+/// for later pool migrations to apply. Genesis isn't built either, so the initial pool limits are set here.
+pub struct NominationPoolsSetupMigration<T, JoinBond, Pools, Members>(
+    sp_std::marker::PhantomData<(T, JoinBond, Pools, Members)>
+);
+
+impl<T, JoinBond, Pools, Members> OnRuntimeUpgrade for NominationPoolsSetupMigration<T, JoinBond, Pools, Members>
+where
+    T: Config,
+    JoinBond: Get<BalanceOf<T>>,
+    Pools: Get<u32>,
+    Members: Get<u32>,
+{
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() != 0 {
+            log::info!(
+                target: "runtime::migrations::nomination_pools",
+                "Nomination pools setup already applied, skipping"
+            );
+            return T::DbWeight::get().reads(1)
+        }
+
+        MinJoinBond::<T>::put(JoinBond::get());
+        MaxPools::<T>::put(Pools::get());
+        MaxPoolMembers::<T>::put(Members::get());
+        let version: StorageVersion = Pallet::<T>::current_storage_version();
+        version.put::<Pallet<T>>();
+
+        log::info!(
+            target: "runtime::migrations::nomination_pools",
+            "Set up nomination pools at storage version {:?}", version
+        );
+        T::DbWeight::get().reads_writes(1, 4)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        assert!(
+            Pallet::<T>::on_chain_storage_version() == Pallet::<T>::current_storage_version(),
+            "Nomination pools storage version not updated after upgrade"
+        );
+        assert!(MaxPools::<T>::get() == Some(Pools::get()), "Pool limits not set after upgrade");
+        log::debug!(
+            target: "runtime::migrations::nomination_pools",
+            "Post upgrade: checks completed, minimum join bond is {:?}", MinJoinBond::<T>::get()
+        );
+        Ok(())
+    }
+}
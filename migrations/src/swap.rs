@@ -0,0 +1,93 @@
+use frame_support::{
+    weights::Weight,
+    storage::migration::take_storage_value,
+    traits::{OnRuntimeUpgrade, Currency, Get, StorageVersion},
+    StorageValue,
+};
+
+use swap::{Config, DefaultFee, FeeModel, Module, ReserveBalance};
+
+type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Move the swap fee into the default fee model
+///
+/// The swap fee used to be a flat amount. Fees are now a fixed part plus a part of the amount,
+/// per destination chain, so the existing fee becomes the fixed part of the default fee model.
+pub struct SwapFeeMigration<T: Config>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for SwapFeeMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if StorageVersion::get::<Module<T>>() >= 1 {
+            log::info!(
+                target: "runtime::migrations::swap",
+                "Swap fee migration already applied, skipping"
+            );
+            return T::DbWeight::get().reads(1)
+        }
+
+        let fee = take_storage_value::<BalanceOf<T>>(b"Swap", b"SwapFee", &[]).unwrap_or_default();
+        DefaultFee::<T>::put(FeeModel { fixed: fee, ..Default::default() });
+        StorageVersion::new(1).put::<Module<T>>();
+
+        log::info!(
+            target: "runtime::migrations::swap",
+            "Moved swap fee of {:?} into the default fee model", fee
+        );
+        T::DbWeight::get().reads_writes(2, 3)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        assert!(
+            frame_support::storage::migration::get_storage_value::<BalanceOf<T>>(b"Swap", b"SwapFee", &[]).is_none(),
+            "Swap fee left after upgrade"
+        );
+        assert!(StorageVersion::get::<Module<T>>() == 1, "Swap storage version not updated after upgrade");
+        log::debug!(
+            target: "runtime::migrations::swap",
+            "Post upgrade: checks completed, fixed fee is {:?}", DefaultFee::<T>::get().fixed
+        );
+        Ok(())
+    }
+}
+
+/// Start the native token reserve accounting of the bridge account
+///
+/// The reserve starts at the free balance of the bridge account, which holds all the native
+/// tokens locked by the bridge so far. Running totals per chain start at zero.
+pub struct SwapReservesMigration<T: Config>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for SwapReservesMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if StorageVersion::get::<Module<T>>() >= 2 {
+            log::info!(
+                target: "runtime::migrations::swap",
+                "Swap reserves migration already applied, skipping"
+            );
+            return T::DbWeight::get().reads(1)
+        }
+
+        let bridge_id = <chainbridge::Module<T>>::account_id();
+        let reserve = <T as Config>::Currency::free_balance(&bridge_id);
+        ReserveBalance::<T>::put(reserve);
+        StorageVersion::new(2).put::<Module<T>>();
+
+        log::info!(
+            target: "runtime::migrations::swap",
+            "Set native token reserve to the bridge account balance of {:?}", reserve
+        );
+        T::DbWeight::get().reads_writes(2, 2)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        assert!(StorageVersion::get::<Module<T>>() == 2, "Swap storage version not updated after upgrade");
+        Module::<T>::do_try_state()?;
+        log::debug!(
+            target: "runtime::migrations::swap",
+            "Post upgrade: checks completed, reserve is {:?}", ReserveBalance::<T>::get()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use frame_support::{
+    weights::Weight,
+    traits::{OnRuntimeUpgrade, Get, StorageVersion},
+    StorageMap,
+};
+
+use tx_pause::{Config, Module, PausedPallets};
+
+/// Pallets paused until governance resumes them
+///
+/// These used to be disabled by the runtime base call filter.
+const PAUSED_PALLETS: [&[u8]; 2] = [b"ChainBridge", b"Swap"];
+
+/// Keep the pallets disabled by the base call filter paused in the transaction pause pallet
+///
+/// The base call filter used to reject all calls of ChainBridge and Swap, so these start out
+/// paused and are enabled by governance resuming them.
+pub struct TxPausePalletsMigration<T: Config>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for TxPausePalletsMigration<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if StorageVersion::get::<Module<T>>() >= 1 {
+            log::info!(
+                target: "runtime::migrations::tx_pause",
+                "Transaction pause migration already applied, skipping"
+            );
+            return T::DbWeight::get().reads(1)
+        }
+
+        for pallet in PAUSED_PALLETS {
+            PausedPallets::insert(pallet.to_vec(), true);
+        }
+        StorageVersion::new(1).put::<Module<T>>();
+
+        log::info!(
+            target: "runtime::migrations::tx_pause",
+            "Paused {} pallets previously disabled by the base call filter", PAUSED_PALLETS.len()
+        );
+        T::DbWeight::get().reads_writes(1, PAUSED_PALLETS.len() as u64 + 1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        for pallet in PAUSED_PALLETS {
+            assert!(Module::<T>::pallet_paused(pallet.to_vec()), "Pallet not paused after upgrade");
+        }
+        assert!(StorageVersion::get::<Module<T>>() == 1, "TxPause storage version not updated after upgrade");
+        log::debug!(
+            target: "runtime::migrations::tx_pause",
+            "Post upgrade: checks completed"
+        );
+        Ok(())
+    }
+}
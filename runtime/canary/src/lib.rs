@@ -36,7 +36,7 @@ use frame_system::{EnsureRoot, EnsureSigned};
 use frame_support::{
 	traits::{
 	ConstU32,
-	InstanceFilter, Everything,
+	InstanceFilter, Everything, Contains,
 	EitherOfDiverse, AsEnsureOriginWithArg,
 }, PalletId};
 use codec::{Encode, Decode, MaxEncodedLen};
@@ -46,7 +46,7 @@ use node_primitives::{Balance, BlockNumber, Hash, Index, Moment};
 use sp_api::impl_runtime_apis;
 use sp_runtime::{
 	ApplyExtrinsicResult, impl_opaque_keys, generic,
-	create_runtime_str,
+	create_runtime_str, FixedU128,
 };
 use frame_election_provider_support::{
 	onchain, ElectionDataProvider, SequentialPhragmen,
@@ -105,10 +105,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// and set impl_version to 0. If only runtime
 	// implementation changes and behavior does not, then leave spec_version as
 	// is and increment impl_version.
-	spec_version: 200,
+	spec_version: 201,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 0,
 };
 
@@ -187,6 +187,11 @@ pub enum ProxyType {
 	Governance,
 	Staking,
 	Voting,
+	Cmix,
+	Custody,
+	IdentityJudgement,
+	CancelProxy,
+	NominationPools,
 }
 impl Default for ProxyType { fn default() -> Self { Self::Any } }
 impl InstanceFilter<RuntimeCall> for ProxyType {
@@ -213,6 +218,22 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				RuntimeCall::Democracy(pallet_democracy::Call::vote { .. } | pallet_democracy::Call::remove_vote { .. }) |
 				RuntimeCall::Elections(pallet_elections_phragmen::Call::vote { .. } | pallet_elections_phragmen::Call::remove_voter { .. })
 			),
+			// Scheduling server operations, so the scheduling account doesn't need an Any proxy
+			ProxyType::Cmix => matches!(c, RuntimeCall::XXCmix(..)),
+			ProxyType::Custody => matches!(c, RuntimeCall::XXCustody(..)),
+			// Registrar judgements only, not calls that reserve or pay fees from the registrar account
+			ProxyType::IdentityJudgement => matches!(
+				c,
+				RuntimeCall::Identity(pallet_identity::Call::provide_judgement { .. }) |
+				RuntimeCall::Utility(..)
+			),
+			ProxyType::CancelProxy => matches!(
+				c,
+				RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. }) |
+				RuntimeCall::Utility(..) |
+				RuntimeCall::Multisig(..)
+			),
+			ProxyType::NominationPools => matches!(c, RuntimeCall::NominationPools(..)),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -226,6 +247,14 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 	}
 }
 
+/// Proxy types that can move funds out of the proxied account, directly or by adding other proxies
+pub struct TransferProxyTypes;
+impl Contains<ProxyType> for TransferProxyTypes {
+	fn contains(proxy_type: &ProxyType) -> bool {
+		matches!(proxy_type, ProxyType::Any | ProxyType::NonTransfer | ProxyType::NominationPools)
+	}
+}
+
 impl pallet_proxy::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -380,6 +409,8 @@ parameter_types! {
 	pub const CustodyProxy: ProxyType = ProxyType::Voting;
 	pub const TestnetId: PalletId = PalletId(*b"xx/tstnt");
 	pub const SaleId: PalletId = PalletId(*b"xx//sale");
+	pub const PublicVestingPolicy: xx_public::VestingPolicy = xx_public::VestingPolicy::Merge;
+	pub const PublicMaxClaimProofLength: u32 = 32;
 }
 
 type EnsureTechnicalUnanimity = EitherOfDiverse<
@@ -424,6 +455,7 @@ impl xx_team_custody::Config for Runtime {
 	type CustodyDuration = CustodyDuration;
 	type GovernanceCustodyDuration = GovernanceCustodyDuration;
 	type CustodyProxy = CustodyProxy;
+	type TransferProxyTypes = TransferProxyTypes;
 	type BlockNumberToBalance = ConvertInto;
 	// Admin is 2/3 technical committee
 	type AdminOrigin = EnsureTwoThirdsTechnical;
@@ -445,6 +477,11 @@ impl xx_cmix::Config for Runtime {
 impl xx_public::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type VestingSchedule = Vesting;
+	type BlockNumberToBalance = ConvertInto;
+	// Merge vesting schedules that don't fit into a single schedule
+	type VestingPolicy = PublicVestingPolicy;
+	// Enough for a tree of 2^32 claims
+	type MaxClaimProofLength = PublicMaxClaimProofLength;
 	type TestnetId = TestnetId;
 	type SaleId = SaleId;
 	// Admin is technical committee unanimity
@@ -506,11 +543,29 @@ impl pallet_staking::Config for Runtime {
 	type TargetList = pallet_staking::UseValidatorsMap<Self>;
 	type MaxUnlockingChunks = ConstU32<32>;
 	type HistoryDepth = ConstU32<84>;
-	type OnStakerSlash = ();
+	type OnStakerSlash = NominationPools;
 	type WeightInfo = pallet_staking::weights::SubstrateWeight<Runtime>;
 	type BenchmarkingConfig = StakingBenchmarkingConfig;
 }
 
+// Nomination pools, so that holders below the minimum nominator bond can take part in staking
+impl pallet_nomination_pools::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_nomination_pools::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type CurrencyBalance = Balance;
+	type RewardCounter = FixedU128;
+	type BalanceToU256 = BalanceToU256;
+	type U256ToBalance = U256ToBalance;
+	// Pool reward accounts are paid out by staking, with rewards taken from the rewards pool
+	type Staking = Staking;
+	type PostUnbondingPoolsWindow = PostUnbondPoolsWindow;
+	type MaxMetadataLen = MaxPoolMetadataLen;
+	type MaxUnbonding = MaxPoolUnbonding;
+	type PalletId = NominationPoolsPalletId;
+	type MaxPointsToBalance = MaxPointsToBalance;
+}
+
 parameter_types! {
 	// phase durations. 1/4 of the last session for each.
 	pub const SignedPhase: u32 = EPOCH_DURATION_IN_BLOCKS / 4;
@@ -891,7 +946,12 @@ impl pallet_vesting::Config for Runtime {
 parameter_types! {
 	pub const ChainId: u8 = 0;
 	pub const ProposalLifetime: BlockNumber = 1 * HOURS;
+	pub const ProposalRetention: BlockNumber = 7 * DAYS;
+	pub const MaxRelayers: u32 = 64;
+	pub const RateLimitWindow: BlockNumber = 1 * DAYS;
+	pub const MaxDepositDataLength: u32 = 1024;
 	pub const BridgePalletId: PalletId = PalletId(*b"cb/bridg");
+	pub const RefundTimeout: BlockNumber = 7 * DAYS;
     pub TokenID: chainbridge::ResourceId = chainbridge::derive_resource_id(0, &sha2_256(b"xx coin"));
 }
 
@@ -903,6 +963,15 @@ impl chainbridge::Config for Runtime {
 	type Proposal = RuntimeCall;
 	type ChainId = ChainId;
 	type ProposalLifetime = ProposalLifetime;
+	type ProposalRetention = ProposalRetention;
+	type MaxRelayers = MaxRelayers;
+	type RateLimitWindow = RateLimitWindow;
+	type MaxDepositDataLength = MaxDepositDataLength;
+	type Currency = Balances;
+	type RelayerBond = RelayerBond;
+	type MaxMissedVotes = MaxMissedVotes;
+	type Slashed = Treasury;
+	type WeightInfo = chainbridge::weights::SubstrateWeight<Runtime>;
 }
 
 // Swap pallet
@@ -912,6 +981,12 @@ impl swap::Config for Runtime {
 	type Currency = Balances;
 	type NativeTokenId = TokenID;
 	type AdminOrigin = EnsureTwoThirdsTechnical;
+	type RefundTimeout = RefundTimeout;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Nfts = Uniques;
+	type AssetId = u32;
+	type Assets = Assets;
 	type WeightInfo = swap::weights::SubstrateWeight<Runtime>;
 }
 
@@ -999,6 +1074,7 @@ construct_runtime!(
 		Authorship: pallet_authorship::{Pallet, Call, Storage, Inherent} = 5,
 		Staking: pallet_staking::{Pallet, Call, Config<T>, Storage, Event<T>} = 6,
 		ElectionProviderMultiPhase: pallet_election_provider_multi_phase::{Pallet, Call, Storage, Event<T>, ValidateUnsigned} = 7,
+		NominationPools: pallet_nomination_pools::{Pallet, Call, Storage, Event<T>, Config<T>} = 43,
 		Offences: pallet_offences::{Pallet, Storage, Event} = 8,
 		Historical: pallet_session_historical::{Pallet} = 25,
 		Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>} = 9,
@@ -1034,7 +1110,7 @@ construct_runtime!(
 		XXCmix: xx_cmix::{Pallet, Call, Storage, Config<T>, Event<T>} = 30,
 		XXEconomics: xx_economics::{Pallet, Call, Storage, Config<T>, Event<T>} = 31,
 		XXCustody: xx_team_custody::{Pallet, Call, Storage, Config<T>, Event<T>} = 32,
-		XXPublic: xx_public::{Pallet, Call, Storage, Config<T>, Event} = 34,
+		XXPublic: xx_public::{Pallet, Call, Storage, Config<T>, Event<T>, ValidateUnsigned} = 34,
 		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>} = 35,
 		Recovery: pallet_recovery::{Pallet, Call, Storage, Event<T>} = 36,
 		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>, Config<T>} = 37,
@@ -1284,6 +1360,59 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl xx_public_runtime_api::XXPublicApi<Block, AccountId, Balance, BlockNumber, Hash> for Runtime {
+		fn check_distribution(
+			source: xx_public::DistributionSource,
+			data: Vec<xx_public::TransferData<AccountId, Balance, BlockNumber>>,
+		) -> xx_public::DistributionCheck<AccountId, Balance, Hash> {
+			XXPublic::check_distribution(source, data)
+		}
+	}
+
+	impl chainbridge_runtime_api::ChainBridgeApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn deposit(
+			dest_id: chainbridge::ChainId,
+			nonce: chainbridge::DepositNonce,
+		) -> Option<chainbridge::DepositRecord<BlockNumber>> {
+			ChainBridge::deposits(dest_id, nonce)
+		}
+
+		fn deposits_by_nonce(
+			dest_id: chainbridge::ChainId,
+			start: chainbridge::DepositNonce,
+			count: u32,
+		) -> Vec<(chainbridge::DepositNonce, chainbridge::DepositRecord<BlockNumber>)> {
+			ChainBridge::deposits_by_nonce(dest_id, start, count)
+		}
+
+		fn deposits_by_block(
+			from: BlockNumber,
+			to: BlockNumber,
+		) -> Vec<(chainbridge::ChainId, chainbridge::DepositNonce, chainbridge::DepositRecord<BlockNumber>)> {
+			ChainBridge::deposits_by_block(from, to)
+		}
+
+		fn relayer_stats(relayer: AccountId) -> Option<chainbridge::RelayerStats<Balance>> {
+			ChainBridge::relayer_stats(&relayer)
+		}
+	}
+
+	impl swap_runtime_api::SwapApi<Block, Balance> for Runtime {
+		fn quote_fee(dest_id: chainbridge::ChainId, amount: Balance) -> Balance {
+			Swap::quote_fee(dest_id, amount)
+		}
+
+		fn reserves() -> swap_runtime_api::ReserveReport<Balance> {
+			Swap::reserve_report()
+		}
+	}
+
+	impl pallet_nomination_pools_runtime_api::NominationPoolsApi<Block, AccountId, Balance> for Runtime {
+		fn pending_rewards(member: AccountId) -> Balance {
+			NominationPools::pending_rewards(member).unwrap_or_default()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -1315,6 +1444,10 @@ impl_runtime_apis! {
 			// have a backtrace here. If any of the pre/post migration checks fail, we shall stop
 			// right here and right now.
 			let weight = Executive::try_runtime_upgrade(checks).unwrap();
+			// Swap uses decl_module, which can't implement the try-state hook, so its checks run here
+			if checks {
+				Swap::do_try_state().unwrap();
+			}
 			(weight, BlockWeights::get().max_block)
 		}
 
@@ -1324,9 +1457,19 @@ impl_runtime_apis! {
 			signature_check: bool,
 			select: frame_try_runtime::TryStateSelect
 		) -> Weight {
+			let swap_checks = match &select {
+				frame_try_runtime::TryStateSelect::None => false,
+				frame_try_runtime::TryStateSelect::Only(pallets) => pallets.iter().any(|p| p.as_slice() == b"Swap"),
+				_ => true,
+			};
 			// NOTE: intentional unwrap: we don't want to propagate the error backwards, and want to
 			// have a backtrace here.
-			Executive::try_execute_block(block, state_root_check, signature_check, select).unwrap()
+			let weight = Executive::try_execute_block(block, state_root_check, signature_check, select).unwrap();
+			// Swap uses decl_module, which can't implement the try-state hook, so its checks run here
+			if swap_checks {
+				Swap::do_try_state().unwrap();
+			}
+			weight
 		}
 	}
 
@@ -1394,4 +1537,70 @@ mod tests {
 
 		is_submit_signed_transaction::<Runtime>();
 	}
+
+	#[test]
+	fn xx_proxy_types_filter_their_pallets() {
+		let cmix = RuntimeCall::XXCmix(xx_cmix::Call::submit_cmix_points { data: vec![] });
+		let custody = RuntimeCall::XXCustody(xx_team_custody::Call::withdraw_rewards {});
+		let identity = RuntimeCall::Identity(pallet_identity::Call::clear_identity {});
+		let judgement = RuntimeCall::Identity(pallet_identity::Call::provide_judgement {
+			reg_index: 0,
+			target: AccountId::new([0u8; 32]).into(),
+			judgement: pallet_identity::Judgement::Reasonable,
+			identity: Default::default(),
+		});
+		let registrar_fee = RuntimeCall::Identity(pallet_identity::Call::set_fee { index: 0, fee: 1 });
+		let judgements = RuntimeCall::Utility(pallet_utility::Call::batch { calls: vec![judgement.clone()] });
+		let reject = RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement {
+			delegate: AccountId::new([0u8; 32]).into(),
+			call_hash: Default::default(),
+		});
+		let pools = RuntimeCall::NominationPools(pallet_nomination_pools::Call::chill { pool_id: 1 });
+		let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer {
+			dest: AccountId::new([0u8; 32]).into(),
+			value: 1,
+		});
+
+		assert!(ProxyType::Cmix.filter(&cmix));
+		assert!(!ProxyType::Cmix.filter(&custody));
+		assert!(ProxyType::Custody.filter(&custody));
+		assert!(!ProxyType::Custody.filter(&cmix));
+		assert!(ProxyType::IdentityJudgement.filter(&judgement));
+		assert!(ProxyType::IdentityJudgement.filter(&judgements));
+		assert!(!ProxyType::IdentityJudgement.filter(&identity));
+		assert!(!ProxyType::IdentityJudgement.filter(&registrar_fee));
+		assert!(!ProxyType::IdentityJudgement.filter(&reject));
+		assert!(ProxyType::CancelProxy.filter(&reject));
+		assert!(!ProxyType::CancelProxy.filter(&identity));
+		assert!(ProxyType::NominationPools.filter(&pools));
+		assert!(!ProxyType::NominationPools.filter(&cmix));
+		assert!(!ProxyType::Staking.filter(&pools));
+		for proxy_type in [
+			ProxyType::Cmix, ProxyType::Custody, ProxyType::IdentityJudgement, ProxyType::CancelProxy,
+			ProxyType::NominationPools,
+		] {
+			assert!(!proxy_type.filter(&transfer));
+		}
+		assert!(ProxyType::NonTransfer.filter(&cmix));
+		assert!(ProxyType::NonTransfer.filter(&custody));
+		assert!(ProxyType::NonTransfer.filter(&pools));
+	}
+
+	#[test]
+	fn xx_proxy_types_supersets() {
+		let xx_types = [
+			ProxyType::Cmix, ProxyType::Custody, ProxyType::IdentityJudgement, ProxyType::CancelProxy,
+			ProxyType::NominationPools,
+		];
+		for proxy_type in xx_types {
+			assert!(ProxyType::Any.is_superset(&proxy_type));
+			assert!(ProxyType::NonTransfer.is_superset(&proxy_type));
+			assert!(proxy_type.is_superset(&proxy_type));
+			assert!(!proxy_type.is_superset(&ProxyType::Any));
+			assert!(!proxy_type.is_superset(&ProxyType::NonTransfer));
+			for other in xx_types.iter().filter(|&&other| other != proxy_type) {
+				assert!(!proxy_type.is_superset(other));
+			}
+		}
+	}
 }
@@ -17,7 +17,9 @@
 
 //! Some configurable implementations as associated type for the substrate runtime.
 
-use frame_support::traits::{OnUnbalanced, Imbalance, Currency};
+use frame_support::traits::{OnUnbalanced, Imbalance, Currency, Defensive};
+use sp_runtime::traits::Convert;
+use node_primitives::Balance;
 use crate::NegativeImbalance;
 
 // Split fees between treasury and block author
@@ -45,3 +47,18 @@ where
 		}
 	}
 }
+
+// Balance conversions used by nomination pools to compute points
+pub struct BalanceToU256;
+impl Convert<Balance, sp_core::U256> for BalanceToU256 {
+	fn convert(balance: Balance) -> sp_core::U256 {
+		sp_core::U256::from(balance)
+	}
+}
+
+pub struct U256ToBalance;
+impl Convert<sp_core::U256, Balance> for U256ToBalance {
+	fn convert(n: sp_core::U256) -> Balance {
+		n.try_into().defensive_unwrap_or(Balance::MAX)
+	}
+}
@@ -140,6 +140,18 @@ parameter_types! {
 	pub const SlashDeferDuration: sp_staking::EraIndex = 27;
 	pub const MaxNominatorRewardedPerValidator: u32 = 256;
 	pub const OffendingValidatorsThreshold: Perbill = Perbill::from_percent(17);
+
+	////////////////////////////////////////////
+	/// Nomination pools constants
+	pub const NominationPoolsPalletId: PalletId = PalletId(*b"xx/nopls");
+	pub const MaxPointsToBalance: u8 = 10;
+	pub const PostUnbondPoolsWindow: u32 = 4;
+	pub const MaxPoolMetadataLen: u32 = 256;
+	pub const MaxPoolUnbonding: u32 = 8;
+	// Initial limits of the pools, which can be changed by governance
+	pub const PoolsMinJoinBond: Balance = 1 * UNITS;
+	pub const PoolsMaxPools: u32 = 64;
+	pub const PoolsMaxMembers: u32 = 64 * 512;
 	
 	////////////////////////////////////////////
 	/// Election provider constants
@@ -280,6 +292,11 @@ parameter_types! {
 	pub const ItemDeposit: Balance = 1 * UNITS;
 	pub const KeyLimit: u32 = 32;
 	pub const ValueLimit: u32 = 256;
+
+	///////////////////////////////////////////
+	/// Chainbridge constants
+	pub const RelayerBond: Balance = 10_000 * UNITS;
+	pub const MaxMissedVotes: u32 = 100;
 }
 
 frame_election_provider_support::generate_solution_type!(
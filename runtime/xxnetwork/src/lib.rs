@@ -47,7 +47,7 @@ use node_primitives::{Balance, BlockNumber, Hash, Index, Moment};
 use sp_api::impl_runtime_apis;
 use sp_runtime::{
 	ApplyExtrinsicResult, impl_opaque_keys, generic,
-	create_runtime_str,
+	create_runtime_str, FixedU128,
 };
 use frame_election_provider_support::{
 	onchain, ElectionDataProvider, SequentialPhragmen,
@@ -76,6 +76,7 @@ pub use pallet_balances::Call as BalancesCall;
 pub use frame_system::Call as SystemCall;
 #[cfg(any(feature = "std", test))]
 pub use pallet_staking::StakerStatus;
+pub use xx_betanet_rewards::{ProgramStatus, UserInfo, betanet_program, BETANET_PROGRAM, BETANET_DEFAULT_OPTION};
 
 // Runtime common stuff
 use runtime_common::*;
@@ -111,10 +112,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// and set impl_version to 0. If only runtime
 	// implementation changes and behavior does not, then leave spec_version as
 	// is and increment impl_version.
-	spec_version: 206,
+	spec_version: 207,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 0,
 };
 
@@ -142,20 +143,21 @@ parameter_types! {
 pub struct BaseFilter;
 impl Contains<RuntimeCall> for BaseFilter {
 	fn contains(call: &RuntimeCall) -> bool {
-		// These modules are all allowed to be called by transactions
 		match call {
-			// ChainBridge and Swap disabled at genesis
-			RuntimeCall::ChainBridge(_) | RuntimeCall::Swap(_) => false,
-
-			// System pallets
+			// Governance and the pause pallet itself can't be paused, so that paused calls can always be resumed
 			RuntimeCall::System(_) | RuntimeCall::Scheduler(_) | RuntimeCall::Preimage(_) |
+			RuntimeCall::Democracy(_) | RuntimeCall::Council(_) | RuntimeCall::TechnicalCommittee(_) |
+			RuntimeCall::TxPause(_)
+			=> true,
+
+			// All other modules are allowed unless paused by governance
 			// Block production and Balances
 			RuntimeCall::Babe(_) | RuntimeCall::Balances(_) | RuntimeCall::Timestamp(_) |
 			// Consensus support
 			RuntimeCall::Authorship(_) | RuntimeCall::Staking(_) | RuntimeCall::ElectionProviderMultiPhase(_) |
 			RuntimeCall::Session(_) | RuntimeCall::Grandpa(_) | RuntimeCall::ImOnline(_) | RuntimeCall::VoterList(_) |
+			RuntimeCall::NominationPools(_) |
 			// Governance
-			RuntimeCall::Democracy(_) | RuntimeCall::Council(_) | RuntimeCall::TechnicalCommittee(_) |
 			RuntimeCall::Elections(_) | RuntimeCall::TechnicalMembership(_) | RuntimeCall::Treasury(_) |
 			// Claims
 			RuntimeCall::Claims(_) |
@@ -163,10 +165,12 @@ impl Contains<RuntimeCall> for BaseFilter {
 			RuntimeCall::Vesting(_) | RuntimeCall::Utility(_) | RuntimeCall::Identity(_) |
 			RuntimeCall::Proxy(_) | RuntimeCall::Bounties(_) | RuntimeCall::ChildBounties(_) | RuntimeCall::Tips(_) |
 			RuntimeCall::Multisig(_) | RuntimeCall::Recovery(_) | RuntimeCall::Assets(_) | RuntimeCall::Uniques(_) |
+			// Bridge
+			RuntimeCall::ChainBridge(_) | RuntimeCall::Swap(_) |
 			// XX Network
 			RuntimeCall::XXCmix(_) | RuntimeCall::XXCustody(_) | RuntimeCall::XXEconomics(_) |
 			RuntimeCall::XXBetanetRewards(_) | RuntimeCall::XXPublic(_)
-			=> true,
+			=> !tx_pause::PausedFilter::<Runtime>::contains(call),
 		}
 	}
 }
@@ -225,6 +229,11 @@ pub enum ProxyType {
 	Governance,
 	Staking,
 	Voting,
+	Cmix,
+	Custody,
+	IdentityJudgement,
+	CancelProxy,
+	NominationPools,
 }
 impl Default for ProxyType { fn default() -> Self { Self::Any } }
 impl InstanceFilter<RuntimeCall> for ProxyType {
@@ -251,6 +260,22 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				RuntimeCall::Democracy(pallet_democracy::Call::vote { .. } | pallet_democracy::Call::remove_vote { .. }) |
 				RuntimeCall::Elections(pallet_elections_phragmen::Call::vote { .. } | pallet_elections_phragmen::Call::remove_voter { .. })
 			),
+			// Scheduling server operations, so the scheduling account doesn't need an Any proxy
+			ProxyType::Cmix => matches!(c, RuntimeCall::XXCmix(..)),
+			ProxyType::Custody => matches!(c, RuntimeCall::XXCustody(..)),
+			// Registrar judgements only, not calls that reserve or pay fees from the registrar account
+			ProxyType::IdentityJudgement => matches!(
+				c,
+				RuntimeCall::Identity(pallet_identity::Call::provide_judgement { .. }) |
+				RuntimeCall::Utility(..)
+			),
+			ProxyType::CancelProxy => matches!(
+				c,
+				RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. }) |
+				RuntimeCall::Utility(..) |
+				RuntimeCall::Multisig(..)
+			),
+			ProxyType::NominationPools => matches!(c, RuntimeCall::NominationPools(..)),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -264,6 +289,14 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 	}
 }
 
+/// Proxy types that can move funds out of the proxied account, directly or by adding other proxies
+pub struct TransferProxyTypes;
+impl Contains<ProxyType> for TransferProxyTypes {
+	fn contains(proxy_type: &ProxyType) -> bool {
+		matches!(proxy_type, ProxyType::Any | ProxyType::NonTransfer | ProxyType::NominationPools)
+	}
+}
+
 impl pallet_proxy::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -418,6 +451,8 @@ parameter_types! {
 	pub const CustodyProxy: ProxyType = ProxyType::Voting;
 	pub const TestnetId: PalletId = PalletId(*b"xx/tstnt");
 	pub const SaleId: PalletId = PalletId(*b"xx//sale");
+	pub const PublicVestingPolicy: xx_public::VestingPolicy = xx_public::VestingPolicy::Merge;
+	pub const PublicMaxClaimProofLength: u32 = 32;
 }
 
 type EnsureTechnicalUnanimity = EitherOfDiverse<
@@ -462,6 +497,7 @@ impl xx_team_custody::Config for Runtime {
 	type CustodyDuration = CustodyDuration;
 	type GovernanceCustodyDuration = GovernanceCustodyDuration;
 	type CustodyProxy = CustodyProxy;
+	type TransferProxyTypes = TransferProxyTypes;
 	type BlockNumberToBalance = ConvertInto;
 	// Admin is technical committee unanimity
 	type AdminOrigin = EnsureTechnicalUnanimity;
@@ -483,6 +519,11 @@ impl xx_cmix::Config for Runtime {
 impl xx_public::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type VestingSchedule = Vesting;
+	type BlockNumberToBalance = ConvertInto;
+	// Merge vesting schedules that don't fit into a single schedule
+	type VestingPolicy = PublicVestingPolicy;
+	// Enough for a tree of 2^32 claims
+	type MaxClaimProofLength = PublicMaxClaimProofLength;
 	type TestnetId = TestnetId;
 	type SaleId = SaleId;
 	// Admin is technical committee unanimity
@@ -544,11 +585,30 @@ impl pallet_staking::Config for Runtime {
 	type TargetList = pallet_staking::UseValidatorsMap<Self>;
 	type MaxUnlockingChunks = ConstU32<32>;
 	type HistoryDepth = ConstU32<84>;
-	type OnStakerSlash = ();
+	type OnStakerSlash = NominationPools;
 	type WeightInfo = pallet_staking::weights::SubstrateWeight<Runtime>;
 	type BenchmarkingConfig = StakingBenchmarkingConfig;
 }
 
+// Nomination pools, so that holders below the minimum nominator bond can take part in staking
+impl pallet_nomination_pools::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// Not benchmarked on xx network yet, use the upstream weights like staking
+	type WeightInfo = pallet_nomination_pools::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type CurrencyBalance = Balance;
+	type RewardCounter = FixedU128;
+	type BalanceToU256 = BalanceToU256;
+	type U256ToBalance = U256ToBalance;
+	// Pool reward accounts are paid out by staking, with rewards taken from the rewards pool
+	type Staking = Staking;
+	type PostUnbondingPoolsWindow = PostUnbondPoolsWindow;
+	type MaxMetadataLen = MaxPoolMetadataLen;
+	type MaxUnbonding = MaxPoolUnbonding;
+	type PalletId = NominationPoolsPalletId;
+	type MaxPointsToBalance = MaxPointsToBalance;
+}
+
 parameter_types! {
 	// phase durations. 1/4 of the last session for each.
 	pub const SignedPhase: u32 = EPOCH_DURATION_IN_BLOCKS / 4;
@@ -941,22 +1001,33 @@ impl pallet_vesting::Config for Runtime {
 
 parameter_types! {
 	pub Prefix: &'static [u8] = b"Pay xx coins to the xx network account:";
+	pub ClaimsChainName: &'static [u8] = b"xx network";
+	pub const MaxStatementLength: u32 = 4 * 1024;
 }
 
 impl claims::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type VestingSchedule = Vesting;
 	type Prefix = Prefix;
+	type ChainName = ClaimsChainName;
 	/// Tech committee unanimity can move a claim
 	type MoveClaimOrigin = EnsureTwoThirdsTechnical;
+	type MaxStatementLength = MaxStatementLength;
 	type RewardHandler = XXBetanetRewards;
+	// Unclaimed allocations are minted to the treasury
+	type UnclaimedHandler = Treasury;
 	type WeightInfo = weights::claims::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub const ChainId: u8 = 0;
 	pub const ProposalLifetime: BlockNumber = 1 * HOURS;
+	pub const ProposalRetention: BlockNumber = 7 * DAYS;
+	pub const MaxRelayers: u32 = 64;
+	pub const RateLimitWindow: BlockNumber = 1 * DAYS;
+	pub const MaxDepositDataLength: u32 = 1024;
 	pub const BridgePalletId: PalletId = PalletId(*b"cb/bridg");
+	pub const RefundTimeout: BlockNumber = 7 * DAYS;
     pub TokenID: chainbridge::ResourceId = chainbridge::derive_resource_id(0, &sha2_256(b"xx coin"));
 }
 
@@ -968,6 +1039,15 @@ impl chainbridge::Config for Runtime {
 	type Proposal = RuntimeCall;
 	type ChainId = ChainId;
 	type ProposalLifetime = ProposalLifetime;
+	type ProposalRetention = ProposalRetention;
+	type MaxRelayers = MaxRelayers;
+	type RateLimitWindow = RateLimitWindow;
+	type MaxDepositDataLength = MaxDepositDataLength;
+	type Currency = Balances;
+	type RelayerBond = RelayerBond;
+	type MaxMissedVotes = MaxMissedVotes;
+	type Slashed = Treasury;
+	type WeightInfo = weights::chainbridge::WeightInfo<Runtime>;
 }
 
 // Swap pallet
@@ -977,17 +1057,43 @@ impl swap::Config for Runtime {
 	type Currency = Balances;
 	type NativeTokenId = TokenID;
 	type AdminOrigin = EnsureTwoThirdsTechnical;
+	type RefundTimeout = RefundTimeout;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Nfts = Uniques;
+	type AssetId = u32;
+	type Assets = Assets;
 	type WeightInfo = weights::swap::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const MaxPauseNameLen: u32 = 64;
+}
+
+// Transaction pause, managed by the technical committee with a council override
+impl tx_pause::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PauseOrigin = EitherOfDiverse<EnsureTwoThirdsTechnical, EnsureTwoThirdsCouncil>;
+	// Only the council can resume, so it can reverse pauses of the technical committee
+	type ResumeOrigin = EnsureTwoThirdsCouncil;
+	type MaxNameLen = MaxPauseNameLen;
+	type WeightInfo = weights::tx_pause::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const BetanetStakingRewardsBlock: BlockNumber = 30 * DAYS;
+	pub const ClaimsRewardsProgram: xx_betanet_rewards::ProgramId = BETANET_PROGRAM;
+	pub const MaxRewardOptions: u32 = 16;
+	pub const RewardsEnactmentBatchSize: u32 = 200;
 }
 
 // xx betanet rewards
 impl xx_betanet_rewards::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type EnactmentBlock = BetanetStakingRewardsBlock;
+	// Claimed rewards are added to the betanet program
+	type ClaimsProgram = ClaimsRewardsProgram;
+	type MaxOptions = MaxRewardOptions;
+	type EnactmentBatchSize = RewardsEnactmentBatchSize;
 	type Reward = XXEconomics;
 	type WeightInfo = weights::xx_betanet_rewards::WeightInfo<Runtime>;
 }
@@ -1077,6 +1183,7 @@ construct_runtime!(
 		Staking: pallet_staking::{Pallet, Call, Config<T>, Storage, Event<T>} = 6,
 		ElectionProviderMultiPhase: pallet_election_provider_multi_phase::{Pallet, Call, Storage, Event<T>, ValidateUnsigned} = 7,
 		VoterList: pallet_bags_list::{Pallet, Call, Storage, Event<T>} = 41,
+		NominationPools: pallet_nomination_pools::{Pallet, Call, Storage, Event<T>, Config<T>} = 43,
 		Offences: pallet_offences::{Pallet, Storage, Event} = 8,
 		Historical: pallet_session_historical::{Pallet} = 25,
 		Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>} = 9,
@@ -1116,13 +1223,14 @@ construct_runtime!(
 		XXEconomics: xx_economics::{Pallet, Call, Storage, Config<T>, Event<T>} = 31,
 		XXCustody: xx_team_custody::{Pallet, Call, Storage, Config<T>, Event<T>} = 32,
 		XXBetanetRewards: xx_betanet_rewards::{Pallet, Call, Storage, Config<T>, Event<T>} = 33,
-		XXPublic: xx_public::{Pallet, Call, Storage, Config<T>, Event} = 34,
+		XXPublic: xx_public::{Pallet, Call, Storage, Config<T>, Event<T>, ValidateUnsigned} = 34,
 		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>} = 35,
 		Recovery: pallet_recovery::{Pallet, Call, Storage, Event<T>} = 36,
 		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>, Config<T>} = 37,
 		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>} = 38,
 		Preimage: pallet_preimage::{Pallet, Call, Storage, Event<T>} = 39,
 		ChildBounties: pallet_child_bounties = 40,
+		TxPause: tx_pause::{Pallet, Call, Storage, Config, Event} = 42,
 	}
 );
 
@@ -1177,6 +1285,20 @@ pub type Executive = frame_executive::Executive<
 		SchedulerMigrationV2ToV4,
 		pallet_democracy::migrations::v1::Migration<Runtime>,
 		pallet_multisig::migrations::v1::MigrateToV1<Runtime>,
+		migrations::custody::CustodyRewardsMigration<Runtime>,
+		migrations::betanet_rewards::BetanetRewardsProgramMigration<Runtime, BetanetStakingRewardsBlock>,
+		migrations::claims::ClaimsStatementsMigration<Runtime>,
+		migrations::chainbridge::ChainbridgeVotesMigration<Runtime>,
+		migrations::chainbridge::ChainbridgeRelayerSetsMigration<Runtime>,
+		migrations::swap::SwapFeeMigration<Runtime>,
+		migrations::swap::SwapReservesMigration<Runtime>,
+		migrations::tx_pause::TxPausePalletsMigration<Runtime>,
+		migrations::nomination_pools::NominationPoolsSetupMigration<
+			Runtime,
+			PoolsMinJoinBond,
+			PoolsMaxPools,
+			PoolsMaxMembers,
+		>,
 	),
 >;
 
@@ -1218,6 +1340,7 @@ mod benches {
 		[pallet_im_online, ImOnline]
 		[pallet_membership, TechnicalMembership]
 		[pallet_multisig, Multisig]
+		[pallet_nomination_pools, NominationPoolsBench::<Runtime>]
 		[pallet_offences, OffencesBench::<Runtime>]
 		[pallet_preimage, Preimage]
 		[pallet_proxy, Proxy]
@@ -1233,8 +1356,10 @@ mod benches {
 		[pallet_utility, Utility]
 		[pallet_vesting, Vesting]
 		// xx network
+		[chainbridge, ChainBridge]
 		[claims, Claims]
 		[swap, Swap]
+		[tx_pause, TxPause]
 		[xx_cmix, XXCmix]
 		[xx_public, XXPublic]
 		[xx_team_custody, XXCustody]
@@ -1395,6 +1520,59 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl xx_public_runtime_api::XXPublicApi<Block, AccountId, Balance, BlockNumber, Hash> for Runtime {
+		fn check_distribution(
+			source: xx_public::DistributionSource,
+			data: Vec<xx_public::TransferData<AccountId, Balance, BlockNumber>>,
+		) -> xx_public::DistributionCheck<AccountId, Balance, Hash> {
+			XXPublic::check_distribution(source, data)
+		}
+	}
+
+	impl chainbridge_runtime_api::ChainBridgeApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn deposit(
+			dest_id: chainbridge::ChainId,
+			nonce: chainbridge::DepositNonce,
+		) -> Option<chainbridge::DepositRecord<BlockNumber>> {
+			ChainBridge::deposits(dest_id, nonce)
+		}
+
+		fn deposits_by_nonce(
+			dest_id: chainbridge::ChainId,
+			start: chainbridge::DepositNonce,
+			count: u32,
+		) -> Vec<(chainbridge::DepositNonce, chainbridge::DepositRecord<BlockNumber>)> {
+			ChainBridge::deposits_by_nonce(dest_id, start, count)
+		}
+
+		fn deposits_by_block(
+			from: BlockNumber,
+			to: BlockNumber,
+		) -> Vec<(chainbridge::ChainId, chainbridge::DepositNonce, chainbridge::DepositRecord<BlockNumber>)> {
+			ChainBridge::deposits_by_block(from, to)
+		}
+
+		fn relayer_stats(relayer: AccountId) -> Option<chainbridge::RelayerStats<Balance>> {
+			ChainBridge::relayer_stats(&relayer)
+		}
+	}
+
+	impl swap_runtime_api::SwapApi<Block, Balance> for Runtime {
+		fn quote_fee(dest_id: chainbridge::ChainId, amount: Balance) -> Balance {
+			Swap::quote_fee(dest_id, amount)
+		}
+
+		fn reserves() -> swap_runtime_api::ReserveReport<Balance> {
+			Swap::reserve_report()
+		}
+	}
+
+	impl pallet_nomination_pools_runtime_api::NominationPoolsApi<Block, AccountId, Balance> for Runtime {
+		fn pending_rewards(member: AccountId) -> Balance {
+			NominationPools::pending_rewards(member).unwrap_or_default()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -1426,6 +1604,10 @@ impl_runtime_apis! {
 			// have a backtrace here. If any of the pre/post migration checks fail, we shall stop
 			// right here and right now.
 			let weight = Executive::try_runtime_upgrade(checks).unwrap();
+			// Swap uses decl_module, which can't implement the try-state hook, so its checks run here
+			if checks {
+				Swap::do_try_state().unwrap();
+			}
 			(weight, BlockWeights::get().max_block)
 		}
 
@@ -1435,9 +1617,19 @@ impl_runtime_apis! {
 			signature_check: bool,
 			select: frame_try_runtime::TryStateSelect
 		) -> Weight {
+			let swap_checks = match &select {
+				frame_try_runtime::TryStateSelect::None => false,
+				frame_try_runtime::TryStateSelect::Only(pallets) => pallets.iter().any(|p| p.as_slice() == b"Swap"),
+				_ => true,
+			};
 			// NOTE: intentional unwrap: we don't want to propagate the error backwards, and want to
 			// have a backtrace here.
-			Executive::try_execute_block(block, state_root_check, signature_check, select).unwrap()
+			let weight = Executive::try_execute_block(block, state_root_check, signature_check, select).unwrap();
+			// Swap uses decl_module, which can't implement the try-state hook, so its checks run here
+			if swap_checks {
+				Swap::do_try_state().unwrap();
+			}
+			weight
 		}
 	}
 
@@ -1453,6 +1645,7 @@ impl_runtime_apis! {
 			use pallet_session_benchmarking::Pallet as SessionBench;
 			use pallet_offences_benchmarking::Pallet as OffencesBench;
 			use pallet_election_provider_support_benchmarking::Pallet as EPSBench;
+			use pallet_nomination_pools_benchmarking::Pallet as NominationPoolsBench;
 			use frame_system_benchmarking::Pallet as SystemBench;
 			use baseline::Pallet as BaselineBench;
 
@@ -1469,12 +1662,14 @@ impl_runtime_apis! {
 			use pallet_session_benchmarking::Pallet as SessionBench;
 			use pallet_offences_benchmarking::Pallet as OffencesBench;
 			use pallet_election_provider_support_benchmarking::Pallet as EPSBench;
+			use pallet_nomination_pools_benchmarking::Pallet as NominationPoolsBench;
 			use frame_system_benchmarking::Pallet as SystemBench;
 			use baseline::Pallet as BaselineBench;
 
 			impl pallet_session_benchmarking::Config for Runtime {}
 			impl pallet_offences_benchmarking::Config for Runtime {}
 			impl pallet_election_provider_support_benchmarking::Config for Runtime {}
+			impl pallet_nomination_pools_benchmarking::Config for Runtime {}
 			impl frame_system_benchmarking::Config for Runtime {}
 			impl baseline::Config for Runtime {}
 
@@ -1505,4 +1700,70 @@ mod tests {
 
 		is_submit_signed_transaction::<Runtime>();
 	}
+
+	#[test]
+	fn xx_proxy_types_filter_their_pallets() {
+		let cmix = RuntimeCall::XXCmix(xx_cmix::Call::submit_cmix_points { data: vec![] });
+		let custody = RuntimeCall::XXCustody(xx_team_custody::Call::withdraw_rewards {});
+		let identity = RuntimeCall::Identity(pallet_identity::Call::clear_identity {});
+		let judgement = RuntimeCall::Identity(pallet_identity::Call::provide_judgement {
+			reg_index: 0,
+			target: AccountId::new([0u8; 32]).into(),
+			judgement: pallet_identity::Judgement::Reasonable,
+			identity: Default::default(),
+		});
+		let registrar_fee = RuntimeCall::Identity(pallet_identity::Call::set_fee { index: 0, fee: 1 });
+		let judgements = RuntimeCall::Utility(pallet_utility::Call::batch { calls: vec![judgement.clone()] });
+		let reject = RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement {
+			delegate: AccountId::new([0u8; 32]).into(),
+			call_hash: Default::default(),
+		});
+		let pools = RuntimeCall::NominationPools(pallet_nomination_pools::Call::chill { pool_id: 1 });
+		let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer {
+			dest: AccountId::new([0u8; 32]).into(),
+			value: 1,
+		});
+
+		assert!(ProxyType::Cmix.filter(&cmix));
+		assert!(!ProxyType::Cmix.filter(&custody));
+		assert!(ProxyType::Custody.filter(&custody));
+		assert!(!ProxyType::Custody.filter(&cmix));
+		assert!(ProxyType::IdentityJudgement.filter(&judgement));
+		assert!(ProxyType::IdentityJudgement.filter(&judgements));
+		assert!(!ProxyType::IdentityJudgement.filter(&identity));
+		assert!(!ProxyType::IdentityJudgement.filter(&registrar_fee));
+		assert!(!ProxyType::IdentityJudgement.filter(&reject));
+		assert!(ProxyType::CancelProxy.filter(&reject));
+		assert!(!ProxyType::CancelProxy.filter(&identity));
+		assert!(ProxyType::NominationPools.filter(&pools));
+		assert!(!ProxyType::NominationPools.filter(&cmix));
+		assert!(!ProxyType::Staking.filter(&pools));
+		for proxy_type in [
+			ProxyType::Cmix, ProxyType::Custody, ProxyType::IdentityJudgement, ProxyType::CancelProxy,
+			ProxyType::NominationPools,
+		] {
+			assert!(!proxy_type.filter(&transfer));
+		}
+		assert!(ProxyType::NonTransfer.filter(&cmix));
+		assert!(ProxyType::NonTransfer.filter(&custody));
+		assert!(ProxyType::NonTransfer.filter(&pools));
+	}
+
+	#[test]
+	fn xx_proxy_types_supersets() {
+		let xx_types = [
+			ProxyType::Cmix, ProxyType::Custody, ProxyType::IdentityJudgement, ProxyType::CancelProxy,
+			ProxyType::NominationPools,
+		];
+		for proxy_type in xx_types {
+			assert!(ProxyType::Any.is_superset(&proxy_type));
+			assert!(ProxyType::NonTransfer.is_superset(&proxy_type));
+			assert!(proxy_type.is_superset(&proxy_type));
+			assert!(!proxy_type.is_superset(&ProxyType::Any));
+			assert!(!proxy_type.is_superset(&ProxyType::NonTransfer));
+			for other in xx_types.iter().filter(|&&other| other != proxy_type) {
+				assert!(!proxy_type.is_superset(other));
+			}
+		}
+	}
 }
@@ -0,0 +1,204 @@
+
+//! Weights for `chainbridge`
+//!
+//! These weights were not generated by the benchmark CLI. Storage accesses are counted by hand
+//! from the worst cases of the benchmarks in `benchmarking.rs`. Execution time is derived from the
+//! generated weight of `swap::set_fee_destination`, 14.8 µs for a single write on 2021-11-15, with
+//! 1.5 µs added for each other storage access, as done for the swap weights.
+//!
+//! They should be replaced by the output of the command below once the benchmarks are run.
+
+// Command:
+// ./xxnetwork-chain
+// benchmark
+// --chain=xxnetwork-dev
+// --steps=50
+// --repeat=20
+// --pallet=chainbridge
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./weights/chainbridge.rs
+
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `chainbridge`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> chainbridge::WeightInfo for WeightInfo<T> {
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	fn set_threshold() -> Weight {
+		Weight::from_ref_time(16_268_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Resources (r:0 w:1)
+	fn set_resource() -> Weight {
+		Weight::from_ref_time(14_768_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Resources (r:0 w:1)
+	fn remove_resource() -> Weight {
+		Weight::from_ref_time(14_768_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge ChainNonces (r:1 w:1)
+	fn whitelist_chain() -> Weight {
+		Weight::from_ref_time(16_268_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Relayers (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ChainBridge ExpiredProposals (r:1 w:0)
+	// Storage: ChainBridge RelayerBonds (r:0 w:1)
+	// Storage: ChainBridge Participation (r:0 w:1)
+	fn add_relayer() -> Weight {
+		Weight::from_ref_time(26_768_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: ChainBridge Relayers (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	// Storage: ChainBridge RelayerBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ChainBridge Participation (r:0 w:1)
+	fn remove_relayer() -> Weight {
+		Weight::from_ref_time(26_768_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: ChainBridge Relayers (r:v w:0)
+	// Storage: ChainBridge RelayerSets (r:2 w:0)
+	// Storage: ChainBridge ChainNonces (r:1 w:0)
+	// Storage: ChainBridge Resources (r:1 w:0)
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: ChainBridge PrunedNonces (r:1 w:0)
+	// Storage: ChainBridge Votes (r:1 w:1)
+	// Storage: ChainBridge ResolvedProposals (r:1 w:1)
+	// Storage: ChainBridge Participation (r:v w:v)
+	// Storage: ChainBridge ProposalSource (r:0 w:1)
+	fn acknowledge_proposal(v: u32, ) -> Weight {
+		Weight::from_ref_time(31_268_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_500_000 as u64).saturating_mul(v as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(v as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(v as u64)))
+	}
+	// Storage: ChainBridge Relayers (r:v w:0)
+	// Storage: ChainBridge RelayerSets (r:2 w:0)
+	// Storage: ChainBridge ChainNonces (r:1 w:0)
+	// Storage: ChainBridge Resources (r:1 w:0)
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: ChainBridge PrunedNonces (r:1 w:0)
+	// Storage: ChainBridge Votes (r:1 w:1)
+	// Storage: ChainBridge ResolvedProposals (r:1 w:1)
+	// Storage: ChainBridge Participation (r:v w:v)
+	fn reject_proposal(v: u32, ) -> Weight {
+		Weight::from_ref_time(29_768_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_500_000 as u64).saturating_mul(v as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(v as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(v as u64)))
+	}
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: ChainBridge Votes (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:2 w:0)
+	// Storage: ChainBridge ResolvedProposals (r:1 w:1)
+	// Storage: ChainBridge Relayers (r:v w:0)
+	// Storage: ChainBridge Participation (r:v w:v)
+	// Storage: ChainBridge ProposalSource (r:0 w:1)
+	fn eval_vote_state(v: u32, ) -> Weight {
+		Weight::from_ref_time(26_768_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_500_000 as u64).saturating_mul(v as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(v as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(v as u64)))
+	}
+	// Storage: ChainBridge Paused (r:0 w:1)
+	fn set_bridge_paused() -> Weight {
+		Weight::from_ref_time(14_768_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge PausedChains (r:0 w:1)
+	fn set_chain_paused() -> Weight {
+		Weight::from_ref_time(14_768_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge RateLimits (r:0 w:1)
+	fn set_rate_limit() -> Weight {
+		Weight::from_ref_time(14_768_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Votes (r:1 w:0)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	// Storage: ChainBridge Relayers (r:v w:v)
+	// Storage: ChainBridge RelayerBonds (r:v w:v)
+	// Storage: System Account (r:v w:v)
+	// Storage: ChainBridge Participation (r:0 w:v)
+	fn slash_voters(v: u32, ) -> Weight {
+		Weight::from_ref_time(17_768_000 as u64)
+			.saturating_add(Weight::from_ref_time(10_500_000 as u64).saturating_mul(v as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(v as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(v as u64)))
+	}
+	// Storage: ChainBridge Relayers (r:1 w:1)
+	// Storage: ChainBridge Participation (r:1 w:1)
+	// Storage: ChainBridge ExpiredProposals (r:1 w:0)
+	// Storage: ChainBridge RelayerBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	fn slash_inactive_relayer() -> Weight {
+		Weight::from_ref_time(29_768_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: ChainBridge ChainNonces (r:1 w:0)
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	fn set_chain_threshold() -> Weight {
+		Weight::from_ref_time(17_768_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge RelayerSets (r:1 w:1)
+	// Storage: ChainBridge Relayers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ChainBridge ExpiredProposals (r:1 w:0)
+	// Storage: ChainBridge RelayerBonds (r:0 w:1)
+	// Storage: ChainBridge Participation (r:0 w:1)
+	fn add_chain_relayer() -> Weight {
+		Weight::from_ref_time(26_768_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: ChainBridge PruneCursor (r:1 w:1)
+	// Storage: ChainBridge Votes (r:n w:n)
+	// Storage: ChainBridge PrunedNonces (r:n w:n)
+	// Storage: ChainBridge RelayerSets (r:n w:0)
+	// Storage: ChainBridge ExpiredProposals (r:n w:n)
+	// Storage: ChainBridge Relayers (r:63n w:0)
+	// Storage: ChainBridge Participation (r:63n w:63n)
+	fn prune_proposals(n: u32, ) -> Weight {
+		Weight::from_ref_time(16_268_000 as u64)
+			.saturating_add(Weight::from_ref_time(294_000_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((130 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((66 as u64).saturating_mul(n as u64)))
+	}
+}
@@ -1,11 +1,25 @@
 
-//! Autogenerated weights for `claims`
+//! Weights for `claims`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2021-11-15, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("xxnetwork-dev"), DB CACHE: 128
+//! The weights of `claim`, `mint_claim`, `claim_attest`, `attest` and `move_claim` were generated
+//! by the benchmark CLI on 2021-11-15, on the `xxnetwork-dev` chain with 50 steps and 20 repeats.
+//!
+//! The weights of `claim_typed` and `claim_attest_typed` were not generated. They are the weights
+//! of `claim` and `claim_attest` with the hashing of the typed data added, about 6 µs, and a read of
+//! the genesis hash for the domain separator.
+//!
+//! The weights of `set_claim_deadline` and `sweep_claims` were not generated either. They are
+//! estimates based on the `set_claim_deadline` and `sweep_claims` benchmarks, with storage accesses
+//! counted by hand. The claim deadline read was added by hand to the weights of all claim calls.
+//!
+//! The weights of `add_statement` and `remove_statement` are estimates as well, based on their
+//! benchmarks. The attestation write was added by hand to the weights of all claim calls, and the
+//! statement read to those of the calls attesting to a statement.
+//!
+//! All weights should be replaced by the output of the command below once the benchmarks in the
+//! pallet are run.
 
-// Executed Command:
+// Command:
 // ./xxnetwork-chain
 // benchmark
 // --chain=xxnetwork-dev
@@ -29,6 +43,7 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `claims`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> claims::WeightInfo for WeightInfo<T> {
+	// Storage: Claims ClaimDeadline (r:1 w:0)
 	// Storage: Claims Claims (r:1 w:1)
 	// Storage: Claims Signing (r:1 w:1)
 	// Storage: Claims Total (r:1 w:1)
@@ -37,10 +52,11 @@ impl<T: frame_system::Config> claims::WeightInfo for WeightInfo<T> {
 	// Storage: System Account (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: Claims Rewards (r:1 w:0)
+	// Storage: Claims Attestations (r:0 w:1)
 	fn claim() -> Weight {
 		Weight::from_ref_time(439_488_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(8 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: Claims Total (r:1 w:1)
 	// Storage: Claims Vesting (r:0 w:1)
@@ -51,21 +67,26 @@ impl<T: frame_system::Config> claims::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
+	// Storage: Claims ClaimDeadline (r:1 w:0)
 	// Storage: Claims Claims (r:1 w:1)
 	// Storage: Claims Signing (r:1 w:1)
+	// Storage: Claims Statements (r:1 w:0)
 	// Storage: Claims Total (r:1 w:1)
 	// Storage: Claims Vesting (r:1 w:1)
 	// Storage: Vesting Vesting (r:1 w:1)
 	// Storage: System Account (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: Claims Rewards (r:1 w:0)
+	// Storage: Claims Attestations (r:0 w:1)
 	fn claim_attest() -> Weight {
 		Weight::from_ref_time(447_012_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(8 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
+	// Storage: Claims ClaimDeadline (r:1 w:0)
 	// Storage: Claims Preclaims (r:1 w:1)
 	// Storage: Claims Signing (r:1 w:1)
+	// Storage: Claims Statements (r:1 w:0)
 	// Storage: Claims Claims (r:1 w:1)
 	// Storage: Claims Total (r:1 w:1)
 	// Storage: Claims Vesting (r:1 w:1)
@@ -73,10 +94,11 @@ impl<T: frame_system::Config> claims::WeightInfo for WeightInfo<T> {
 	// Storage: System Account (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: Claims Rewards (r:1 w:0)
+	// Storage: Claims Attestations (r:0 w:1)
 	fn attest() -> Weight {
 		Weight::from_ref_time(108_304_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(9 as u64))
-			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: Claims Claims (r:1 w:2)
 	// Storage: Claims Vesting (r:1 w:2)
@@ -87,4 +109,73 @@ impl<T: frame_system::Config> claims::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
+	// Storage: Claims ClaimDeadline (r:1 w:0)
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: Claims Claims (r:1 w:1)
+	// Storage: Claims Signing (r:1 w:1)
+	// Storage: Claims Total (r:1 w:1)
+	// Storage: Claims Vesting (r:1 w:1)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Claims Rewards (r:1 w:0)
+	// Storage: Claims Attestations (r:0 w:1)
+	fn claim_typed() -> Weight {
+		Weight::from_ref_time(445_731_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: Claims ClaimDeadline (r:1 w:0)
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: Claims Claims (r:1 w:1)
+	// Storage: Claims Signing (r:1 w:1)
+	// Storage: Claims Statements (r:1 w:0)
+	// Storage: Claims Total (r:1 w:1)
+	// Storage: Claims Vesting (r:1 w:1)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Claims Rewards (r:1 w:0)
+	// Storage: Claims Attestations (r:0 w:1)
+	fn claim_attest_typed() -> Weight {
+		Weight::from_ref_time(452_964_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: Claims ClaimDeadline (r:1 w:1)
+	fn set_claim_deadline() -> Weight {
+		Weight::from_ref_time(9_874_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Claims ClaimDeadline (r:1 w:0)
+	// Storage: Claims Preclaims (r:2 w:1)
+	// Storage: Claims Claims (r:2 w:1)
+	// Storage: Claims Vesting (r:0 w:1)
+	// Storage: Claims Signing (r:0 w:1)
+	// Storage: Claims Rewards (r:0 w:1)
+	// Storage: Claims Total (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Claims Swept (r:1 w:1)
+	fn sweep_claims(n: u32, ) -> Weight {
+		Weight::from_ref_time(24_115_000 as u64)
+			.saturating_add(Weight::from_ref_time(11_902_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Claims Statements (r:1 w:1)
+	fn add_statement(l: u32, ) -> Weight {
+		Weight::from_ref_time(12_406_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Claims Statements (r:1 w:1)
+	fn remove_statement() -> Weight {
+		Weight::from_ref_time(11_733_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
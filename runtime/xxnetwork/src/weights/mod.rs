@@ -1,5 +1,6 @@
 //! A list of the different weight modules for the xx network runtime.
 
+pub mod chainbridge;
 pub mod claims;
 pub mod frame_system;
 pub mod pallet_assets;
@@ -21,6 +22,7 @@ pub mod pallet_uniques;
 pub mod pallet_utility;
 pub mod pallet_vesting;
 pub mod swap;
+pub mod tx_pause;
 pub mod xx_betanet_rewards;
 pub mod xx_cmix;
 pub mod xx_economics;
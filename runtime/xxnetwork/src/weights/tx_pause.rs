@@ -0,0 +1,59 @@
+
+//! Weights for `tx_pause`
+//!
+//! These weights were not generated by the benchmark CLI. Storage accesses are counted by hand
+//! from the benchmarks in `benchmarking.rs`. Execution time is derived from the generated weight
+//! of `swap::set_fee_destination`, 14.8 µs for a single write on 2021-11-15, with 1.5 µs added for the
+//! read, as done for the swap and chainbridge weights.
+//!
+//! They should be replaced by the output of the command below once the benchmarks are run.
+
+// Command:
+// ./xxnetwork-chain
+// benchmark
+// --chain=xxnetwork-dev
+// --steps=50
+// --repeat=20
+// --pallet=tx_pause
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./weights/tx_pause.rs
+
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `tx_pause`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> tx_pause::WeightInfo for WeightInfo<T> {
+	// Storage: TxPause PausedPallets (r:1 w:1)
+	fn pause_pallet() -> Weight {
+		Weight::from_ref_time(16_268_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: TxPause PausedPallets (r:1 w:1)
+	fn resume_pallet() -> Weight {
+		Weight::from_ref_time(16_268_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: TxPause PausedCalls (r:1 w:1)
+	fn pause_call() -> Weight {
+		Weight::from_ref_time(16_268_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: TxPause PausedCalls (r:1 w:1)
+	fn resume_call() -> Weight {
+		Weight::from_ref_time(16_268_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+}
@@ -1,11 +1,21 @@
 
-//! Autogenerated weights for `xx_betanet_rewards`
+//! Weights for `xx_betanet_rewards`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2021-11-15, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("xxnetwork-dev"), DB CACHE: 128
+//! The weights of `select_option` and `approve` were generated by the benchmark CLI on 2021-11-15. Reward
+//! programs added storage accesses to both since. These were counted by hand, and 1.5 µs was added to the
+//! generated time for each, to account for encoding and hashing.
+//!
+//! The other calls were added without running the benchmarks. Their weights are estimated the same
+//! way, from the generated call closest to them:
+//! - `create_program` and `add_accounts` from `approve`, with 1.5 µs per reward option and per added
+//!   account
+//! - `enact_accounts` from the time per recipient of `xx_public::sale_distribute`, which also pays out
+//!   and adds vesting schedules
+//! - `enact_claims` from `claims::mint_claim`, which writes the same claims storage
+//!
+//! All weights should be replaced by the output of the command below once the benchmarks are run.
 
-// Executed Command:
+// Command:
 // ./xxnetwork-chain
 // benchmark
 // --chain=xxnetwork-dev
@@ -29,15 +39,54 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `xx_betanet_rewards`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> xx_betanet_rewards::WeightInfo for WeightInfo<T> {
+	// Storage: XXBetanetRewards Programs (r:1 w:0)
 	// Storage: XXBetanetRewards Accounts (r:1 w:1)
 	fn select_option() -> Weight {
-		Weight::from_ref_time(22_071_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
+		Weight::from_ref_time(23_571_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
-	// Storage: XXBetanetRewards Approved (r:0 w:1)
+	// Storage: XXBetanetRewards Programs (r:1 w:1)
+	// Storage: XXBetanetRewards Enactments (r:1 w:1)
 	fn approve() -> Weight {
+		Weight::from_ref_time(19_909_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: XXBetanetRewards NextProgramId (r:1 w:1)
+	// Storage: XXBetanetRewards Programs (r:0 w:1)
+	fn create_program(o: u32, ) -> Weight {
+		Weight::from_ref_time(18_409_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_500_000 as u64).saturating_mul(o as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: XXBetanetRewards Programs (r:1 w:0)
+	// Storage: XXBetanetRewards Accounts (r:0 w:1)
+	fn add_accounts(n: u32, ) -> Weight {
 		Weight::from_ref_time(15_409_000 as u64)
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(Weight::from_ref_time(1_500_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: XXBetanetRewards Accounts (r:1 w:1)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn enact_accounts(n: u32, ) -> Weight {
+		Weight::from_ref_time(0 as u64)
+			.saturating_add(Weight::from_ref_time(122_442_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Claims Rewards (r:1 w:1)
+	// Storage: Claims Claims (r:1 w:1)
+	// Storage: Claims Total (r:1 w:1)
+	// Storage: Claims Vesting (r:1 w:1)
+	fn enact_claims(n: u32, ) -> Weight {
+		Weight::from_ref_time(0 as u64)
+			.saturating_add(Weight::from_ref_time(15_851_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
 	}
 }
@@ -1,11 +1,23 @@
 
-//! Autogenerated weights for `xx_public`
+//! Weights for `xx_public`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2021-11-15, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("xxnetwork-dev"), DB CACHE: 128
+//! The weights of `set_testnet_manager_account`, `set_sale_manager_account`, `testnet_distribute` and
+//! `sale_distribute` were generated by the benchmark CLI on 2021-11-15. The distribution IDs and the
+//! tracking of committed funds and added vesting schedules added storage accesses to the distribute
+//! calls since. These were counted by hand, and 1.5 µs was added to the generated time for each, to
+//! account for encoding and hashing.
+//!
+//! The claims calls were added without running the benchmarks. Their weights are estimated the same
+//! way, from the generated call closest to them:
+//! - `testnet_commit_claims` and `sale_commit_claims` from the matching `set_*_manager_account`
+//! - `sweep_claims` from `set_sale_manager_account`, with 1.5 µs per swept claim
+//! - `claim` from the time per recipient of `sale_distribute`. Each proof node is charged 1.5 µs, for
+//!   hashing. Each vesting schedule is charged a third of the time per recipient, since the distribute
+//!   benchmarks give every recipient three schedules, which overestimates it.
+//!
+//! All weights should be replaced by the output of the command below once the benchmarks are run.
 
-// Executed Command:
+// Command:
 // ./xxnetwork-chain
 // benchmark
 // --chain=xxnetwork-dev
@@ -40,29 +52,78 @@ impl<T: frame_system::Config> xx_public::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	// Storage: XXSale TestnetManager (r:1 w:0)
+	// Storage: XXSale Distributions (r:1 w:1)
+	// Storage: XXSale CommittedFunds (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Vesting Vesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
+	// Storage: XXSale AddedVestingSchedules (r:1 w:1)
 	fn testnet_distribute(n: u32, ) -> Weight {
-		Weight::from_ref_time(2_735_000 as u64)
-			// Standard Error: 56_000
-			.saturating_add(Weight::from_ref_time(118_728_000 as u64).saturating_mul(n as u64))
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
-			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+		Weight::from_ref_time(7_235_000 as u64)
+			.saturating_add(Weight::from_ref_time(121_728_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
 	}
 	// Storage: XXSale SaleManager (r:1 w:0)
+	// Storage: XXSale Distributions (r:1 w:1)
+	// Storage: XXSale CommittedFunds (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Vesting Vesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
+	// Storage: XXSale AddedVestingSchedules (r:1 w:1)
 	fn sale_distribute(n: u32, ) -> Weight {
-		Weight::from_ref_time(9_151_000 as u64)
-			// Standard Error: 37_000
-			.saturating_add(Weight::from_ref_time(119_442_000 as u64).saturating_mul(n as u64))
+		Weight::from_ref_time(13_651_000 as u64)
+			.saturating_add(Weight::from_ref_time(122_442_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: XXSale TestnetManager (r:1 w:0)
+	// Storage: XXSale Claims (r:1 w:1)
+	// Storage: XXSale Distributions (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: XXSale CommittedFunds (r:1 w:1)
+	fn testnet_commit_claims() -> Weight {
+		Weight::from_ref_time(23_918_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: XXSale SaleManager (r:1 w:0)
+	// Storage: XXSale Claims (r:1 w:1)
+	// Storage: XXSale Distributions (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: XXSale CommittedFunds (r:1 w:1)
+	fn sale_commit_claims() -> Weight {
+		Weight::from_ref_time(23_688_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: XXSale Claims (r:1 w:1)
+	// Storage: XXSale Claimed (r:1 w:1)
+	// Storage: XXSale CommittedFunds (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: XXSale AddedVestingSchedules (r:1 w:1)
+	fn claim(p: u32, s: u32, ) -> Weight {
+		Weight::from_ref_time(134_442_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_500_000 as u64).saturating_mul(p as u64))
+			.saturating_add(Weight::from_ref_time(39_814_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: XXSale Claims (r:1 w:1)
+	// Storage: XXSale CommittedFunds (r:1 w:1)
+	// Storage: XXSale Claimed (r:0 w:1)
+	// Storage: XXSale Distributions (r:0 w:1)
+	fn sweep_claims(n: u32, ) -> Weight {
+		Weight::from_ref_time(20_688_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_500_000 as u64).saturating_mul(n as u64))
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
-			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
 	}
 }
@@ -1,11 +1,19 @@
 
-//! Autogenerated weights for `xx_team_custody`
+//! Weights for `xx_team_custody`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2021-11-15, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("xxnetwork-dev"), DB CACHE: 128
+//! The weights of all calls but `withdraw_rewards`, `team_custody_set_proxy_type`,
+//! `add_team_proxy_type` and `remove_team_proxy_type` were generated by the benchmark CLI on 2021-11-15.
+//!
+//! These four calls were added without running the benchmarks. Their weights are estimated from the
+//! generated call closest to them, adding 1.5 µs for each storage access it doesn't make, to account
+//! for encoding and hashing, and removing as much for each one it makes but they don't:
+//! - `withdraw_rewards` from `payout`, which also updates the total custody
+//! - `team_custody_set_proxy_type` from `team_custody_set_proxy`, which doesn't check the proxy type
+//! - `add_team_proxy_type` and `remove_team_proxy_type` from `add_custodian` and `remove_custodian`
+//!
+//! All weights should be replaced by the output of the command below once the benchmarks are run.
 
-// Executed Command:
+// Command:
 // ./xxnetwork-chain
 // benchmark
 // --chain=xxnetwork-dev
@@ -88,6 +96,22 @@ impl<T: frame_system::Config> xx_team_custody::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
+	// Storage: XXCustody TeamAccounts (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	fn withdraw_rewards() -> Weight {
+		Weight::from_ref_time(101_056_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: XXCustody TeamProxyTypes (r:1 w:0)
+	// Storage: XXCustody TeamAccounts (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn team_custody_set_proxy_type() -> Weight {
+		Weight::from_ref_time(49_791_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
 	// Storage: XXCustody Custodians (r:0 w:1)
 	fn add_custodian() -> Weight {
 		Weight::from_ref_time(15_880_000 as u64)
@@ -104,4 +128,14 @@ impl<T: frame_system::Config> xx_team_custody::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
+	// Storage: XXCustody TeamProxyTypes (r:0 w:1)
+	fn add_team_proxy_type() -> Weight {
+		Weight::from_ref_time(15_880_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: XXCustody TeamProxyTypes (r:0 w:1)
+	fn remove_team_proxy_type() -> Weight {
+		Weight::from_ref_time(16_071_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
@@ -0,0 +1,20 @@
+//! Runtime API definition for the swap pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+pub use chainbridge::ChainId;
+pub use swap::{Reserve, ReserveReport};
+
+sp_api::decl_runtime_apis! {
+    pub trait SwapApi<Balance> where
+        Balance: Codec,
+    {
+        /// Swap fee charged to move an amount of the native token to a destination chain
+        fn quote_fee(dest_id: ChainId, amount: Balance) -> Balance;
+
+        /// Native token reserve of the bridge account, with the amounts locked and released per chain
+        fn reserves() -> ReserveReport<Balance>;
+    }
+}
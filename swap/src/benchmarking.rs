@@ -5,7 +5,7 @@ use super::*;
 
 use frame_benchmarking::{benchmarks, account, impl_benchmark_test_suite};
 use frame_system::RawOrigin;
-use frame_support::dispatch::UnfilteredDispatchable;
+use frame_support::{dispatch::UnfilteredDispatchable, traits::tokens::{fungibles, nonfungibles}};
 use sp_runtime::traits::Bounded;
 
 const SEED: u32 = 0;
@@ -17,28 +17,100 @@ fn account_from_index<T: Config>(index: u32) -> T::AccountId {
 	account("x", index, SEED)
 }
 
+// Holds an outbound transfer of a bridged asset for review, with the amount held by the bridge account
+fn queue_outbound_asset<T: Config>(kind: AssetKind) -> TransferId
+where
+	T::Assets: fungibles::Create<T::AccountId>,
+	<T as Config>::AssetId: Default,
+{
+	let (r_id, asset_id) = setup_asset::<T>(kind);
+	let amount = 100u32.into();
+	let bridge_id = <chainbridge::Module<T>>::account_id();
+	T::Assets::mint_into(asset_id, &bridge_id, amount).expect("Could not mint asset");
+	let id = <NextTransferId>::get();
+	<Module<T>>::queue_transfer(PendingTransfer::OutboundAsset {
+		from: account_from_index::<T>(1),
+		dest_id: TEST_DESTINATION_CHAIN,
+		r_id,
+		recipient: TEST_RECIPIENT_ADDR.to_vec(),
+		amount,
+	});
+	id
+}
+
+// Sets rate limits on a resource and the test chain, high enough to allow the benchmarked transfers
+fn set_rate_limits<T: Config>(direction: Direction, r_id: ResourceId) {
+	let limit = chainbridge::RateLimit { max_transfer: U256::MAX, max_volume: U256::MAX };
+	for target in [LimitTarget::Resource(r_id), LimitTarget::Chain(TEST_DESTINATION_CHAIN)] {
+		<chainbridge::RateLimits>::insert(direction, target, limit.clone());
+	}
+}
+
+// Bridges a resource with a new collection, owned by the bridge account
+fn setup_collection<T: Config>() -> (ResourceId, <T as Config>::CollectionId)
+where
+	T::Nfts: nonfungibles::Create<T::AccountId>,
+	<T as Config>::CollectionId: Default,
+{
+	let r_id = chainbridge::derive_resource_id(TEST_DESTINATION_CHAIN, b"nft");
+	let collection = Default::default();
+	let bridge_id = <chainbridge::Module<T>>::account_id();
+	let balance = <<T as Config>::Currency as Currency<T::AccountId>>::Balance::max_value() / 2u32.into();
+	<T as Config>::Currency::make_free_balance_be(&bridge_id, balance);
+	T::Nfts::create_collection(&collection, &bridge_id, &bridge_id).expect("Could not create collection");
+	<Collections<T>>::insert(r_id, collection);
+	(r_id, collection)
+}
+
+// Bridges a resource with a new asset, with a fee paid to the fee destination
+fn setup_asset<T: Config>(kind: AssetKind) -> (ResourceId, <T as Config>::AssetId)
+where
+	T::Assets: fungibles::Create<T::AccountId>,
+	<T as Config>::AssetId: Default,
+{
+	let r_id = chainbridge::derive_resource_id(TEST_DESTINATION_CHAIN, b"asset");
+	let asset_id = Default::default();
+	let bridge_id = <chainbridge::Module<T>>::account_id();
+	T::Assets::create(asset_id, bridge_id, true, 1u32.into()).expect("Could not create asset");
+	<BridgedAssets<T>>::insert(r_id, BridgedAsset { id: asset_id, kind, fee: 1u32.into() });
+	<FeeDestination<T>>::put(account_from_index::<T>(2));
+	(r_id, asset_id)
+}
+
 benchmarks!{
+	where_clause { where
+		T::Nfts: nonfungibles::Create<T::AccountId>,
+		<T as Config>::CollectionId: Default,
+		<T as Config>::ItemId: From<u32>,
+		T::Assets: fungibles::Create<T::AccountId>,
+		<T as Config>::AssetId: Default,
+	}
 
 	transfer_native {
 		// worst case should result in a call into the chainbridge pallet
 		// transfer_fungible
 		// - chain whitelisted
 		// - account has balance for fee and transfer
+		// - transfer counted against the rate limits of the token and chain
 
 		<chainbridge::Module<T>>::whitelist_chain(RawOrigin::Root.into(), TEST_DESTINATION_CHAIN)
 			.expect("Could not whitelist chain");
+		set_rate_limits::<T>(Direction::Outbound, T::NativeTokenId::get());
 
 		let payer = account_from_index::<T>(5);
 		let initial_balance = <<T as Config>::Currency as Currency<T::AccountId>>::Balance::max_value();
-		T::Currency::make_free_balance_be(&payer, initial_balance);
+		<T as Config>::Currency::make_free_balance_be(&payer, initial_balance);
 
-		let amount = T::Currency::minimum_balance() * 100u32.into();
+		let amount = <T as Config>::Currency::minimum_balance() * 100u32.into();
 
 	}: _(RawOrigin::Signed(payer), amount, TEST_RECIPIENT_ADDR.to_vec(), TEST_DESTINATION_CHAIN)
 
 
  	transfer {
-		let amount = T::Currency::minimum_balance() * 10u32.into();
+		// worst case is a transfer from a known chain, counted against the rate limits of the token and chain
+		set_rate_limits::<T>(Direction::Inbound, T::NativeTokenId::get());
+		<chainbridge::ProposalSource>::put((TEST_DESTINATION_CHAIN, 1));
+		let amount = <T as Config>::Currency::minimum_balance() * 10u32.into();
 		let dest = account_from_index::<T>(1);
 		let origin = T::BridgeOrigin::successful_origin();
 
@@ -51,12 +123,162 @@ benchmarks!{
 
 
 	set_swap_fee {
-		let new_fee = T::Currency::minimum_balance() * 10u32.into();
+		let new_fee = <T as Config>::Currency::minimum_balance() * 10u32.into();
 	}: _(RawOrigin::Root, new_fee)
 
 
+	set_default_fee {
+		let fee = <T as Config>::Currency::minimum_balance() * 10u32.into();
+		let model = FeeModel { fixed: fee, bps: 10, min: fee, max: Some(fee * 10u32.into()) };
+	}: _(RawOrigin::Root, model.clone())
+	verify {
+		assert_eq!(<DefaultFee<T>>::get(), model);
+	}
+
+
+	set_chain_fee {
+		let fee = <T as Config>::Currency::minimum_balance() * 10u32.into();
+		let model = FeeModel { fixed: fee, bps: 10, min: fee, max: Some(fee * 10u32.into()) };
+	}: _(RawOrigin::Root, TEST_DESTINATION_CHAIN, Some(model.clone()))
+	verify {
+		assert_eq!(<ChainFees<T>>::get(TEST_DESTINATION_CHAIN), Some(model));
+	}
+
+
 	set_fee_destination {
 	}: _(RawOrigin::Root, account_from_index::<T>(1))
+
+
+	approve_transfer {
+		// worst case is an outbound transfer of the native token, which is deposited into the
+		// chainbridge pallet and recorded for refunds and in the reserve
+		<chainbridge::Module<T>>::whitelist_chain(RawOrigin::Root.into(), TEST_DESTINATION_CHAIN)
+			.expect("Could not whitelist chain");
+		let id = <NextTransferId>::get();
+		<Module<T>>::queue_transfer(PendingTransfer::Outbound {
+			from: account_from_index::<T>(1),
+			dest_id: TEST_DESTINATION_CHAIN,
+			recipient: TEST_RECIPIENT_ADDR.to_vec(),
+			amount: <T as Config>::Currency::minimum_balance() * 100u32.into(),
+			fee: <T as Config>::Currency::minimum_balance() * 10u32.into(),
+		});
+	}: _(RawOrigin::Root, id)
+	verify {
+		assert!(<PendingTransfers<T>>::get(id).is_none());
+	}
+
+
+	reject_transfer {
+		// worst case is an outbound asset transfer, which is refunded
+		let id = queue_outbound_asset::<T>(AssetKind::Locked);
+	}: _(RawOrigin::Root, id)
+	verify {
+		assert!(<PendingTransfers<T>>::get(id).is_none());
+	}
+
+
+	transfer_nft {
+		<chainbridge::Module<T>>::whitelist_chain(RawOrigin::Root.into(), TEST_DESTINATION_CHAIN)
+			.expect("Could not whitelist chain");
+		let (r_id, collection) = setup_collection::<T>();
+		let owner = account_from_index::<T>(5);
+		let item = 0u32.into();
+		T::Nfts::mint_into(&collection, &item, &owner).expect("Could not mint item");
+	}: _(RawOrigin::Signed(owner), r_id, item, TEST_RECIPIENT_ADDR.to_vec(), TEST_DESTINATION_CHAIN)
+	verify {
+		assert_eq!(T::Nfts::owner(&collection, &item), Some(<chainbridge::Module<T>>::account_id()));
+	}
+
+
+	receive_nft {
+		// worst case is an item that doesn't exist on this chain, which is minted
+		let (r_id, collection) = setup_collection::<T>();
+		let dest = account_from_index::<T>(1);
+		let origin = T::BridgeOrigin::successful_origin();
+
+		let call = Call::<T>::receive_nft {
+			to: dest.clone(),
+			r_id,
+			token_id: U256::zero(),
+		};
+
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(T::Nfts::owner(&collection, &0u32.into()), Some(dest));
+	}
+
+
+	set_collection {
+		let r_id = chainbridge::derive_resource_id(TEST_DESTINATION_CHAIN, b"nft");
+	}: _(RawOrigin::Root, r_id, Some(Default::default()))
+
+
+	transfer_asset {
+		// worst case is an asset of this chain, with the fee paid to the fee destination and the
+		// transfer counted against the rate limits of the asset and chain
+		<chainbridge::Module<T>>::whitelist_chain(RawOrigin::Root.into(), TEST_DESTINATION_CHAIN)
+			.expect("Could not whitelist chain");
+		let (r_id, asset_id) = setup_asset::<T>(AssetKind::Locked);
+		set_rate_limits::<T>(Direction::Outbound, r_id);
+		let payer = account_from_index::<T>(5);
+		T::Assets::mint_into(asset_id, &payer, 1_000u32.into()).expect("Could not mint asset");
+	}: _(RawOrigin::Signed(payer), r_id, 100u32.into(), TEST_RECIPIENT_ADDR.to_vec(), TEST_DESTINATION_CHAIN)
+	verify {
+		assert_eq!(T::Assets::balance(asset_id, &<chainbridge::Module<T>>::account_id()), 100u32.into());
+	}
+
+
+	receive_asset {
+		// worst case is an asset of this chain from a known chain, which is unlocked from the bridge
+		// account after being counted against the rate limits of the asset and chain
+		let (r_id, asset_id) = setup_asset::<T>(AssetKind::Locked);
+		set_rate_limits::<T>(Direction::Inbound, r_id);
+		<chainbridge::ProposalSource>::put((TEST_DESTINATION_CHAIN, 1));
+		let bridge_id = <chainbridge::Module<T>>::account_id();
+		T::Assets::mint_into(asset_id, &bridge_id, 1_000u32.into()).expect("Could not mint asset");
+		let dest = account_from_index::<T>(1);
+		let origin = T::BridgeOrigin::successful_origin();
+
+		let call = Call::<T>::receive_asset {
+			to: dest.clone(),
+			r_id,
+			amount: 100u32.into(),
+		};
+
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(T::Assets::balance(asset_id, &dest), 100u32.into());
+	}
+
+
+	set_asset {
+		let r_id = chainbridge::derive_resource_id(TEST_DESTINATION_CHAIN, b"asset");
+		let asset = BridgedAsset { id: Default::default(), kind: AssetKind::Minted, fee: 1u32.into() };
+	}: _(RawOrigin::Root, r_id, Some(asset))
+
+
+	refund_native {
+		// worst case is a refund by the admin origin after the timeout, including the fee
+		<chainbridge::Module<T>>::whitelist_chain(RawOrigin::Root.into(), TEST_DESTINATION_CHAIN)
+			.expect("Could not whitelist chain");
+		let bridge_id = <chainbridge::Module<T>>::account_id();
+		let balance = <<T as Config>::Currency as Currency<T::AccountId>>::Balance::max_value() / 2u32.into();
+		<T as Config>::Currency::make_free_balance_be(&bridge_id, balance);
+
+		let from = account_from_index::<T>(5);
+		let amount = <T as Config>::Currency::minimum_balance() * 100u32.into();
+		let fee = <T as Config>::Currency::minimum_balance() * 10u32.into();
+		let fee_dest = account_from_index::<T>(6);
+		<T as Config>::Currency::make_free_balance_be(&fee_dest, fee * 2u32.into());
+		<FeeDestination<T>>::put(fee_dest);
+		<Module<T>>::deposit_native(from.clone(), TEST_DESTINATION_CHAIN, TEST_RECIPIENT_ADDR.to_vec(), amount, fee)
+			.expect("Could not deposit transfer");
+		let now = <frame_system::Pallet<T>>::block_number();
+		<frame_system::Pallet<T>>::set_block_number(now + T::RefundTimeout::get());
+	}: _(RawOrigin::Root, TEST_DESTINATION_CHAIN, 1, true)
+	verify {
+		assert_eq!(<T as Config>::Currency::free_balance(&from), amount + fee);
+	}
 }
 
 
@@ -1,13 +1,19 @@
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::traits::{Currency, EnsureOrigin, ExistenceRequirement::AllowDeath, Get};
+use frame_support::traits::{
+    tokens::{fungibles, nonfungibles},
+    Currency, EnsureOrigin, ExistenceRequirement::AllowDeath, Get,
+};
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure,
+    decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure, IterableStorageMap, Parameter,
 };
 use frame_system::{self as system, ensure_root, ensure_signed};
+use chainbridge::{ChainId, DepositNonce, Direction, LimitTarget};
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
 use sp_core::U256;
-use sp_runtime::traits::SaturatedConversion;
+use sp_runtime::{traits::{AtLeast32BitUnsigned, Member, Saturating, UniqueSaturatedInto, Zero}, Permill, RuntimeDebug};
 use sp_std::prelude::*;
 pub use weights::WeightInfo;
 
@@ -26,6 +32,140 @@ type ResourceId = chainbridge::ResourceId;
 type BalanceOf<T> =
     <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+type AssetBalanceOf<T> =
+    <<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+type BridgedAssetOf<T> = BridgedAsset<<T as Config>::AssetId, AssetBalanceOf<T>>;
+
+type FeeModelOf<T> = FeeModel<BalanceOf<T>>;
+
+type NativeDepositOf<T> = NativeDeposit<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+type PendingTransferOf<T> =
+    PendingTransfer<<T as frame_system::Config>::AccountId, BalanceOf<T>, AssetBalanceOf<T>>;
+
+/// Identifier of a transfer held for review
+pub type TransferId = u64;
+
+/// Maximum fee rate, in basis points
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+/// Fee charged when moving native tokens to a chain, a fixed part plus a part of the amount
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+pub struct FeeModel<Balance> {
+    /// Fixed part of the fee
+    pub fixed: Balance,
+    /// Part of the amount added to the fee, in basis points
+    pub bps: u16,
+    /// Lower bound of the fee
+    pub min: Balance,
+    /// Upper bound of the fee, if any
+    pub max: Option<Balance>,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> FeeModel<Balance> {
+    /// Fee charged to move `amount`
+    pub fn fee(&self, amount: Balance) -> Balance {
+        let variable = Permill::from_parts(u32::from(self.bps) * 100).mul_floor(amount);
+        let fee = self.fixed.saturating_add(variable).max(self.min);
+        match self.max {
+            Some(max) => fee.min(max),
+            None => fee,
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.bps <= MAX_FEE_BPS && self.max.map_or(true, |max| self.min <= max)
+    }
+}
+
+/// Native token transfer out of the chain, kept so it can be refunded
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct NativeDeposit<AccountId, Balance> {
+    /// Sender of the transfer
+    pub from: AccountId,
+    /// Amount held by the bridge account
+    pub amount: Balance,
+    /// Fee paid by the sender
+    pub fee: Balance,
+}
+
+/// Running totals of the native token moved across the bridge with a chain
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct Reserve<Balance> {
+    /// Amount locked in the bridge account by transfers to the chain
+    pub locked: Balance,
+    /// Amount released from the bridge account by transfers from the chain and refunds
+    pub released: Balance,
+}
+
+/// Native token reserve of the bridge account, as reported for monitoring
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ReserveReport<Balance> {
+    /// Amount the bridge account should hold, the genesis balance plus all locked minus all released amounts
+    pub expected: Balance,
+    /// Free balance of the bridge account
+    pub held: Balance,
+    /// Running totals of each chain
+    pub chains: Vec<(ChainId, Reserve<Balance>)>,
+}
+
+/// How a bridged asset moves across the bridge
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum AssetKind {
+    /// Asset of this chain, locked in the bridge account when leaving and unlocked when coming back
+    Locked,
+    /// Wrapped asset of another chain, burned when leaving and minted when coming back
+    Minted,
+}
+
+/// An asset bridged with a resource ID
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct BridgedAsset<AssetId, Balance> {
+    /// Asset ID on this chain
+    pub id: AssetId,
+    pub kind: AssetKind,
+    /// Fee charged in the asset when moving it out of the chain
+    pub fee: Balance,
+}
+
+/// A transfer over the bridge rate limits, held until it is approved or rejected by the admin
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum PendingTransfer<AccountId, Balance, AssetBalance> {
+    /// Transfer from the bridge account to an account on this chain
+    Inbound {
+        /// Source chain, if known
+        src_id: Option<ChainId>,
+        to: AccountId,
+        amount: Balance,
+    },
+    /// Transfer out of the chain, with the amount held by the bridge account
+    Outbound {
+        from: AccountId,
+        dest_id: ChainId,
+        recipient: Vec<u8>,
+        amount: Balance,
+        /// Fee already paid to the fee destination
+        fee: Balance,
+    },
+    /// Transfer of the asset bridged with `r_id` to an account on this chain
+    InboundAsset {
+        /// Source chain, if known
+        src_id: Option<ChainId>,
+        r_id: ResourceId,
+        to: AccountId,
+        amount: AssetBalance,
+    },
+    /// Transfer of the asset bridged with `r_id` out of the chain, with the amount held by the bridge account
+    OutboundAsset {
+        from: AccountId,
+        dest_id: ChainId,
+        r_id: ResourceId,
+        recipient: Vec<u8>,
+        amount: AssetBalance,
+    },
+}
+
 pub trait Config: system::Config + chainbridge::Config {
     /// The Event type
     type RuntimeEvent: From<Event<Self>> + Into<<Self as frame_system::Config>::RuntimeEvent>;
@@ -42,17 +182,68 @@ pub trait Config: system::Config + chainbridge::Config {
     /// Origin used to change fee and destination
     type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+    /// Number of blocks after a native token transfer before the admin origin can refund it
+    type RefundTimeout: Get<Self::BlockNumber>;
+
+    /// Identifier of the collections of non-fungible items that can be bridged
+    type CollectionId: Parameter + Member + Copy;
+
+    /// Identifier of the non-fungible items that can be bridged, converted to and from the token IDs of other chains
+    type ItemId: Parameter + Member + Copy + Into<U256> + TryFrom<U256>;
+
+    /// The non-fungible items mechanism
+    type Nfts: nonfungibles::Inspect<Self::AccountId, CollectionId = Self::CollectionId, ItemId = Self::ItemId>
+        + nonfungibles::Transfer<Self::AccountId>
+        + nonfungibles::Mutate<Self::AccountId>;
+
+    /// Identifier of the fungible assets that can be bridged
+    type AssetId: Parameter + Member + Copy;
+
+    /// The fungible assets mechanism
+    type Assets: fungibles::Inspect<Self::AccountId, AssetId = Self::AssetId>
+        + fungibles::Transfer<Self::AccountId>
+        + fungibles::Mutate<Self::AccountId>;
+
     /// Weight information for extrinsics in this pallet.
     type WeightInfo: WeightInfo;
 }
 
 decl_storage! {
     trait Store for Module<T: Config> as Swap {
-        /// Swap service fee charged when moving native tokens out of the chain
-        pub SwapFee get(fn swap_fee) config(): BalanceOf<T>;
+        /// Swap service fee charged when moving native tokens out of the chain,
+        /// unless the destination chain has its own fee
+        pub DefaultFee get(fn default_fee): FeeModelOf<T>;
+
+        /// Swap service fee charged when moving native tokens to a chain
+        pub ChainFees get(fn chain_fees): map hasher(twox_64_concat) ChainId => Option<FeeModelOf<T>>;
 
         /// Account to which the fee is paid to
         pub FeeDestination get(fn fee_destination): Option<T::AccountId>;
+
+        /// Transfers over the bridge rate limits, held for review
+        pub PendingTransfers get(fn pending_transfers):
+            map hasher(twox_64_concat) TransferId => Option<PendingTransferOf<T>>;
+
+        /// Identifier of the next transfer held for review
+        pub NextTransferId get(fn next_transfer_id): TransferId;
+
+        /// Collection of the non-fungible items bridged with a resource ID
+        pub Collections get(fn collections):
+            map hasher(blake2_128_concat) ResourceId => Option<<T as Config>::CollectionId>;
+
+        /// Fungible asset bridged with a resource ID, with its fee
+        pub BridgedAssets get(fn bridged_assets): map hasher(blake2_128_concat) ResourceId => Option<BridgedAssetOf<T>>;
+
+        /// Native token transfers to a chain that can be refunded, by deposit nonce
+        pub NativeDeposits get(fn native_deposits):
+            double_map hasher(twox_64_concat) ChainId, hasher(twox_64_concat) DepositNonce
+            => Option<NativeDepositOf<T>>;
+
+        /// Running totals of the native token locked and released by the bridge account, per chain
+        pub Reserves get(fn reserves): map hasher(twox_64_concat) ChainId => Reserve<BalanceOf<T>>;
+
+        /// Amount of the native token the bridge account should hold
+        pub ReserveBalance get(fn reserve_balance): BalanceOf<T>;
     }
 
     add_extra_genesis {
@@ -61,6 +252,7 @@ decl_storage! {
         config(resources): Vec<(ResourceId, Vec<u8>)>;
         config(threshold): u32;
         config(balance): BalanceOf<T>;
+        config(swap_fee): BalanceOf<T>;
         config(fee_destination): Option<T::AccountId>;
 
         build(|config: &GenesisConfig<T>| {
@@ -73,8 +265,10 @@ decl_storage! {
                 .expect("Could not set config on Chainbridge pallet");
             // Create chainbridge account and set the balance from genesis
             let account_id = <chainbridge::Module<T>>::account_id();
-            T::Currency::make_free_balance_be(&account_id, config.balance);
-            // Set fee destination
+            <T as Config>::Currency::make_free_balance_be(&account_id, config.balance);
+            <ReserveBalance<T>>::put(config.balance);
+            // Set fixed fee and fee destination
+            <DefaultFee<T>>::put(FeeModel { fixed: config.swap_fee, ..Default::default() });
             if let Some(dest) = &config.fee_destination {
                 <FeeDestination<T>>::put(dest);
             }
@@ -83,11 +277,36 @@ decl_storage! {
 }
 
 decl_event! {
-    pub enum Event<T> where Balance = BalanceOf<T>, <T as frame_system::Config>::AccountId {
+    pub enum Event<T> where
+        Balance = BalanceOf<T>,
+        <T as frame_system::Config>::AccountId,
+        CollectionId = <T as Config>::CollectionId,
+        AssetBalance = AssetBalanceOf<T>,
+        Asset = BridgedAssetOf<T>,
+        Fee = FeeModelOf<T>
+    {
         /// Swap service fee was changed
         FeeChanged(Balance),
+        /// Default swap fee model was changed
+        DefaultFeeChanged(Fee),
+        /// Swap fee model of a chain was changed, or removed if none
+        ChainFeeChanged(ChainId, Option<Fee>),
         /// Swap fee destination was changed
         FeeDestinationChanged(AccountId),
+        /// Transfer over the bridge rate limits was held for review (transfer_id, direction, amount)
+        TransferQueued(TransferId, Direction, Balance),
+        /// Transfer held for review was approved and executed
+        TransferApproved(TransferId),
+        /// Transfer held for review was rejected. Outbound transfers are refunded.
+        TransferRejected(TransferId),
+        /// Collection bridged with a resource ID was changed, or removed if none
+        CollectionChanged(ResourceId, Option<CollectionId>),
+        /// Asset transfer over the bridge rate limits was held for review (transfer_id, direction, resource_id, amount)
+        AssetTransferQueued(TransferId, Direction, ResourceId, AssetBalance),
+        /// Asset bridged with a resource ID was changed, or removed if none
+        AssetChanged(ResourceId, Option<Asset>),
+        /// Native token transfer was refunded to its sender (dest_id, nonce, sender, amount)
+        Refunded(ChainId, DepositNonce, AccountId, Balance),
     }
 }
 
@@ -95,6 +314,26 @@ decl_error! {
     pub enum Error for Module<T: Config> {
         DestinationNotWhitelisted,
         InsufficientBalance,
+        /// No transfer held for review with this ID
+        UnknownTransfer,
+        /// No collection is bridged with this resource ID
+        UnknownCollection,
+        /// Item isn't owned by the sender
+        NotItemOwner,
+        /// Item exists on this chain but isn't locked in the bridge account
+        ItemNotLocked,
+        /// Token ID doesn't map to an item ID of this chain
+        InvalidTokenId,
+        /// No asset is bridged with this resource ID
+        UnknownAsset,
+        /// Fee rate is over 100% or the minimum fee is over the maximum
+        InvalidFeeModel,
+        /// No refundable native token transfer with this nonce was made to the chain
+        UnknownDeposit,
+        /// Transfer can only be refunded by the admin origin once the refund timeout has passed
+        RefundTooEarly,
+        /// Fee can't be refunded without a fee destination to take it back from
+        NoFeeDestination,
     }
 }
 
@@ -102,6 +341,8 @@ decl_module! {
     pub struct Module<T: Config> for enum Call where origin: T::RuntimeOrigin {
         const NativeTokenId: ResourceId = T::NativeTokenId::get();
 
+        const RefundTimeout: T::BlockNumber = T::RefundTimeout::get();
+
         type Error = Error<T>;
 
         fn deposit_event() = default;
@@ -115,27 +356,91 @@ decl_module! {
         pub fn transfer_native(origin, amount: BalanceOf<T>, recipient: Vec<u8>, dest_id: chainbridge::ChainId) -> DispatchResult {
             let source = ensure_signed(origin)?;
 
-            // Ensure destination chain is whitelisted
+            // Ensure destination chain is whitelisted and not paused
             ensure!(<chainbridge::Module<T>>::chain_whitelisted(dest_id), Error::<T>::DestinationNotWhitelisted);
+            <chainbridge::Module<T>>::ensure_not_paused(dest_id)?;
 
             // Ensure account has enough balance to pay for both fee and transfer
-            let fee = <SwapFee<T>>::get();
-            let balance = T::Currency::free_balance(&source);
+            let fee = Self::quote_fee(dest_id, amount);
+            let balance = <T as Config>::Currency::free_balance(&source);
             ensure!(balance >= amount + fee, Error::<T>::InsufficientBalance);
 
             // Transfer fee to configured destination (if destination exists)
-            if let Some(dest) = <FeeDestination<T>>::get() {
-                T::Currency::transfer(&source, &dest, fee, AllowDeath)?;
+            let fee = match <FeeDestination<T>>::get() {
+                Some(dest) => {
+                    <T as Config>::Currency::transfer(&source, &dest, fee, AllowDeath)?;
+                    fee
+                },
+                None => Zero::zero(),
             };
 
             // Transfer amount to bridge
             let bridge_id = <chainbridge::Module<T>>::account_id();
-            T::Currency::transfer(&source, &bridge_id, amount, AllowDeath)?;
+            <T as Config>::Currency::transfer(&source, &bridge_id, amount, AllowDeath)?;
+
+            // Hold the transfer for review if it's over the rate limits
+            let targets = [LimitTarget::Resource(T::NativeTokenId::get()), LimitTarget::Chain(dest_id)];
+            if <chainbridge::Module<T>>::consume_limits(Direction::Outbound, &targets, Self::to_u256(amount)) {
+                Self::deposit_native(source, dest_id, recipient, amount, fee)
+            } else {
+                Self::queue_transfer(PendingTransfer::Outbound { from: source, dest_id, recipient, amount, fee });
+                Ok(())
+            }
+        }
 
-            let resource_id = T::NativeTokenId::get();
-            <chainbridge::Module<T>>::transfer_fungible(dest_id, resource_id, recipient,
-                U256::from(amount.saturated_into::<u128>()))?;
-            Ok(())
+        /// Transfers a non-fungible item to some recipient on a (whitelisted) destination chain.
+        /// The item is locked in the bridge account, and the deposit includes its metadata.
+        #[weight = <T as Config>::WeightInfo::transfer_nft()]
+        pub fn transfer_nft(origin, r_id: ResourceId, item: <T as Config>::ItemId, recipient: Vec<u8>, dest_id: chainbridge::ChainId) -> DispatchResult {
+            let source = ensure_signed(origin)?;
+
+            ensure!(<chainbridge::Module<T>>::chain_whitelisted(dest_id), Error::<T>::DestinationNotWhitelisted);
+            let collection = Self::collections(r_id).ok_or(Error::<T>::UnknownCollection)?;
+            ensure!(T::Nfts::owner(&collection, &item) == Some(source), Error::<T>::NotItemOwner);
+
+            // The empty attribute key holds the item metadata
+            let metadata = T::Nfts::attribute(&collection, &item, &[]).unwrap_or_default();
+
+            // Lock item in the bridge account
+            let bridge_id = <chainbridge::Module<T>>::account_id();
+            T::Nfts::transfer(&collection, &item, &bridge_id)?;
+
+            <chainbridge::Module<T>>::transfer_nonfungible(dest_id, r_id, Self::token_id(item), recipient, metadata)
+                .map(|_| ())
+        }
+
+        /// Transfers an amount of a bridged asset to some recipient on a (whitelisted) destination chain.
+        /// The asset fee is paid to the fee destination, in the asset.
+        #[weight = <T as Config>::WeightInfo::transfer_asset()]
+        pub fn transfer_asset(origin, r_id: ResourceId, amount: AssetBalanceOf<T>, recipient: Vec<u8>, dest_id: chainbridge::ChainId) -> DispatchResult {
+            let source = ensure_signed(origin)?;
+
+            // Ensure destination chain is whitelisted and not paused
+            ensure!(<chainbridge::Module<T>>::chain_whitelisted(dest_id), Error::<T>::DestinationNotWhitelisted);
+            <chainbridge::Module<T>>::ensure_not_paused(dest_id)?;
+            let asset = Self::bridged_assets(r_id).ok_or(Error::<T>::UnknownAsset)?;
+
+            // Ensure account has enough of the asset to pay for both fee and transfer
+            let balance = T::Assets::reducible_balance(asset.id, &source, false);
+            ensure!(balance >= amount.saturating_add(asset.fee), Error::<T>::InsufficientBalance);
+
+            // Transfer fee to configured destination (if destination exists)
+            if let Some(dest) = <FeeDestination<T>>::get() {
+                T::Assets::transfer(asset.id, &source, &dest, asset.fee, false)?;
+            };
+
+            // Transfer amount to bridge, wrapped assets are burned once deposited
+            let bridge_id = <chainbridge::Module<T>>::account_id();
+            T::Assets::transfer(asset.id, &source, &bridge_id, amount, false)?;
+
+            // Hold the transfer for review if it's over the rate limits
+            let targets = [LimitTarget::Resource(r_id), LimitTarget::Chain(dest_id)];
+            if <chainbridge::Module<T>>::consume_limits(Direction::Outbound, &targets, Self::to_u256(amount)) {
+                Self::deposit_asset(dest_id, r_id, recipient, amount)
+            } else {
+                Self::queue_transfer(PendingTransfer::OutboundAsset { from: source, dest_id, r_id, recipient, amount });
+                Ok(())
+            }
         }
 
         //
@@ -146,19 +451,90 @@ decl_module! {
         #[weight = <T as Config>::WeightInfo::transfer()]
         pub fn transfer(origin, to: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
             let source = T::BridgeOrigin::ensure_origin(origin)?;
-            T::Currency::transfer(&source, &to, amount, AllowDeath)?;
+
+            // Hold the transfer for review if it's over the rate limits
+            let src_id = <chainbridge::Module<T>>::proposal_source().map(|(id, _)| id);
+            let mut targets = vec![LimitTarget::Resource(T::NativeTokenId::get())];
+            targets.extend(src_id.map(LimitTarget::Chain));
+            if <chainbridge::Module<T>>::consume_limits(Direction::Inbound, &targets, Self::to_u256(amount)) {
+                <T as Config>::Currency::transfer(&source, &to, amount, AllowDeath)?;
+                Self::release_native(src_id, amount);
+            } else {
+                Self::queue_transfer(PendingTransfer::Inbound { src_id, to, amount });
+            }
             Ok(())
         }
 
-        /// Set swap fee
+        /// Executes a non-fungible transfer to an account on this chain.
+        /// The item is unlocked from the bridge account if it's held there, otherwise it's minted
+        /// in the collection bridged with the resource ID.
+        #[weight = <T as Config>::WeightInfo::receive_nft()]
+        pub fn receive_nft(origin, to: T::AccountId, r_id: ResourceId, token_id: U256) -> DispatchResult {
+            let source = T::BridgeOrigin::ensure_origin(origin)?;
+
+            let collection = Self::collections(r_id).ok_or(Error::<T>::UnknownCollection)?;
+            let item = <T as Config>::ItemId::try_from(token_id).map_err(|_| Error::<T>::InvalidTokenId)?;
+            match T::Nfts::owner(&collection, &item) {
+                Some(owner) => {
+                    ensure!(owner == source, Error::<T>::ItemNotLocked);
+                    T::Nfts::transfer(&collection, &item, &to)
+                },
+                None => T::Nfts::mint_into(&collection, &item, &to),
+            }
+        }
+
+        /// Executes a transfer of a bridged asset to an account on this chain
+        #[weight = <T as Config>::WeightInfo::receive_asset()]
+        pub fn receive_asset(origin, to: T::AccountId, r_id: ResourceId, amount: AssetBalanceOf<T>) -> DispatchResult {
+            T::BridgeOrigin::ensure_origin(origin)?;
+            ensure!(<BridgedAssets<T>>::contains_key(r_id), Error::<T>::UnknownAsset);
+
+            // Hold the transfer for review if it's over the rate limits
+            let src_id = <chainbridge::Module<T>>::proposal_source().map(|(id, _)| id);
+            let mut targets = vec![LimitTarget::Resource(r_id)];
+            targets.extend(src_id.map(LimitTarget::Chain));
+            if <chainbridge::Module<T>>::consume_limits(Direction::Inbound, &targets, Self::to_u256(amount)) {
+                Self::release_asset(r_id, &to, amount)
+            } else {
+                Self::queue_transfer(PendingTransfer::InboundAsset { src_id, r_id, to, amount });
+                Ok(())
+            }
+        }
+
+        /// Set the fixed part of the default swap fee
         #[weight = <T as Config>::WeightInfo::set_swap_fee()]
         pub fn set_swap_fee(origin, #[compact] fee: BalanceOf<T>) -> DispatchResult {
             Self::ensure_admin(origin)?;
-            <SwapFee<T>>::put(fee);
+            <DefaultFee<T>>::mutate(|model| model.fixed = fee);
             Self::deposit_event(RawEvent::FeeChanged(fee));
             Ok(())
         }
 
+        /// Set the default swap fee model, used for chains without their own
+        #[weight = <T as Config>::WeightInfo::set_default_fee()]
+        pub fn set_default_fee(origin, model: FeeModelOf<T>) -> DispatchResult {
+            Self::ensure_admin(origin)?;
+            ensure!(model.is_valid(), Error::<T>::InvalidFeeModel);
+            <DefaultFee<T>>::put(&model);
+            Self::deposit_event(RawEvent::DefaultFeeChanged(model));
+            Ok(())
+        }
+
+        /// Set the swap fee model of a destination chain, or remove it if none
+        #[weight = <T as Config>::WeightInfo::set_chain_fee()]
+        pub fn set_chain_fee(origin, dest_id: ChainId, model: Option<FeeModelOf<T>>) -> DispatchResult {
+            Self::ensure_admin(origin)?;
+            match &model {
+                Some(model) => {
+                    ensure!(model.is_valid(), Error::<T>::InvalidFeeModel);
+                    <ChainFees<T>>::insert(dest_id, model);
+                },
+                None => <ChainFees<T>>::remove(dest_id),
+            }
+            Self::deposit_event(RawEvent::ChainFeeChanged(dest_id, model));
+            Ok(())
+        }
+
         /// Set fee destination
         #[weight = <T as Config>::WeightInfo::set_fee_destination()]
         pub fn set_fee_destination(origin, dest: T::AccountId) -> DispatchResult {
@@ -167,6 +543,113 @@ decl_module! {
             Self::deposit_event(RawEvent::FeeDestinationChanged(dest));
             Ok(())
         }
+
+        /// Set the collection bridged with a resource ID, or remove it if none
+        #[weight = <T as Config>::WeightInfo::set_collection()]
+        pub fn set_collection(origin, r_id: ResourceId, collection: Option<<T as Config>::CollectionId>) -> DispatchResult {
+            Self::ensure_admin(origin)?;
+            match collection {
+                Some(collection) => <Collections<T>>::insert(r_id, collection),
+                None => <Collections<T>>::remove(r_id),
+            }
+            Self::deposit_event(RawEvent::CollectionChanged(r_id, collection));
+            Ok(())
+        }
+
+        /// Set the asset bridged with a resource ID and its fee, or remove it if none
+        #[weight = <T as Config>::WeightInfo::set_asset()]
+        pub fn set_asset(origin, r_id: ResourceId, asset: Option<BridgedAssetOf<T>>) -> DispatchResult {
+            Self::ensure_admin(origin)?;
+            match &asset {
+                Some(asset) => <BridgedAssets<T>>::insert(r_id, asset),
+                None => <BridgedAssets<T>>::remove(r_id),
+            }
+            Self::deposit_event(RawEvent::AssetChanged(r_id, asset));
+            Ok(())
+        }
+
+        /// Approve a transfer held for review, executing it regardless of the rate limits
+        #[weight = <T as Config>::WeightInfo::approve_transfer()]
+        pub fn approve_transfer(origin, id: TransferId) -> DispatchResult {
+            Self::ensure_admin(origin)?;
+            let transfer = <PendingTransfers<T>>::take(id).ok_or(Error::<T>::UnknownTransfer)?;
+            match transfer {
+                PendingTransfer::Inbound { src_id, to, amount } => {
+                    let bridge_id = <chainbridge::Module<T>>::account_id();
+                    <T as Config>::Currency::transfer(&bridge_id, &to, amount, AllowDeath)?;
+                    Self::release_native(src_id, amount);
+                },
+                PendingTransfer::Outbound { from, dest_id, recipient, amount, fee } => {
+                    Self::deposit_native(from, dest_id, recipient, amount, fee)?;
+                },
+                PendingTransfer::InboundAsset { r_id, to, amount, .. } => {
+                    Self::release_asset(r_id, &to, amount)?;
+                },
+                PendingTransfer::OutboundAsset { dest_id, r_id, recipient, amount, .. } => {
+                    Self::deposit_asset(dest_id, r_id, recipient, amount)?;
+                },
+            }
+            Self::deposit_event(RawEvent::TransferApproved(id));
+            Ok(())
+        }
+
+        /// Reject a transfer held for review. Outbound transfers are refunded, without the fee.
+        #[weight = <T as Config>::WeightInfo::reject_transfer()]
+        pub fn reject_transfer(origin, id: TransferId) -> DispatchResult {
+            Self::ensure_admin(origin)?;
+            let transfer = <PendingTransfers<T>>::take(id).ok_or(Error::<T>::UnknownTransfer)?;
+            let bridge_id = <chainbridge::Module<T>>::account_id();
+            match transfer {
+                PendingTransfer::Outbound { from, amount, .. } => {
+                    <T as Config>::Currency::transfer(&bridge_id, &from, amount, AllowDeath)?;
+                },
+                PendingTransfer::OutboundAsset { from, r_id, amount, .. } => {
+                    let asset = Self::bridged_assets(r_id).ok_or(Error::<T>::UnknownAsset)?;
+                    T::Assets::transfer(asset.id, &bridge_id, &from, amount, false)?;
+                },
+                _ => {},
+            }
+            Self::deposit_event(RawEvent::TransferRejected(id));
+            Ok(())
+        }
+
+        /// Refund a native token transfer that wasn't executed on the destination chain, optionally with its fee.
+        /// The amount is returned by the bridge account and the fee by the fee destination.
+        ///
+        /// Relayers refund a transfer through a bridge proposal for its deposit nonce. The admin origin can
+        /// only refund it once `RefundTimeout` blocks have passed since the transfer.
+        #[weight = <T as Config>::WeightInfo::refund_native()]
+        pub fn refund_native(origin, dest_id: ChainId, nonce: DepositNonce, refund_fee: bool) -> DispatchResult {
+            let by_admin = match T::BridgeOrigin::try_origin(origin) {
+                Ok(_) => false,
+                Err(origin) => {
+                    Self::ensure_admin(origin)?;
+                    true
+                },
+            };
+
+            let record = <chainbridge::Module<T>>::deposits(dest_id, nonce).ok_or(Error::<T>::UnknownDeposit)?;
+            ensure!(!record.refunded, chainbridge::Error::<T>::DepositAlreadyRefunded);
+            if by_admin {
+                let now = <frame_system::Pallet<T>>::block_number();
+                ensure!(now >= record.block.saturating_add(T::RefundTimeout::get()), Error::<T>::RefundTooEarly);
+            }
+            let deposit = <NativeDeposits<T>>::take(dest_id, nonce).ok_or(Error::<T>::UnknownDeposit)?;
+            <chainbridge::Module<T>>::mark_refunded(dest_id, nonce)?;
+
+            // Only the amount is held by the bridge account, the fee is taken back from the fee destination
+            let bridge_id = <chainbridge::Module<T>>::account_id();
+            <T as Config>::Currency::transfer(&bridge_id, &deposit.from, deposit.amount, AllowDeath)?;
+            Self::release_native(Some(dest_id), deposit.amount);
+            let mut refunded = deposit.amount;
+            if refund_fee && !deposit.fee.is_zero() {
+                let fee_dest = <FeeDestination<T>>::get().ok_or(Error::<T>::NoFeeDestination)?;
+                <T as Config>::Currency::transfer(&fee_dest, &deposit.from, deposit.fee, AllowDeath)?;
+                refunded = refunded.saturating_add(deposit.fee);
+            }
+            Self::deposit_event(RawEvent::Refunded(dest_id, nonce, deposit.from, refunded));
+            Ok(())
+        }
     }
 }
 
@@ -182,8 +665,9 @@ impl<T: Config> Module<T> {
             <chainbridge::Module<T>>::whitelist(*c)?;
         }
 
+        // Genesis relayers are registered without a bond
         for rs in relayers {
-            <chainbridge::Module<T>>::register_relayer(rs.clone())?;
+            <chainbridge::Module<T>>::register_relayer(rs.clone(), Zero::zero())?;
         }
 
         for &(ref re, ref m) in resources.iter() {
@@ -193,6 +677,123 @@ impl<T: Config> Module<T> {
         <chainbridge::Module<T>>::set_relayer_threshold(*threshold)
     }
 
+    /// Fixed part of the default swap fee
+    pub fn swap_fee() -> BalanceOf<T> {
+        Self::default_fee().fixed
+    }
+
+    /// Swap fee charged to move an amount of the native token to a destination chain
+    pub fn quote_fee(dest_id: ChainId, amount: BalanceOf<T>) -> BalanceOf<T> {
+        Self::chain_fees(dest_id).unwrap_or_else(Self::default_fee).fee(amount)
+    }
+
+    /// Deposit an amount of the native token held by the bridge account to a destination chain,
+    /// recording the sender and fee so the transfer can be refunded
+    fn deposit_native(
+        from: T::AccountId,
+        dest_id: ChainId,
+        recipient: Vec<u8>,
+        amount: BalanceOf<T>,
+        fee: BalanceOf<T>,
+    ) -> DispatchResult {
+        let nonce = <chainbridge::Module<T>>::transfer_fungible(
+            dest_id,
+            T::NativeTokenId::get(),
+            recipient,
+            Self::to_u256(amount),
+        )?;
+        <NativeDeposits<T>>::insert(dest_id, nonce, NativeDeposit { from, amount, fee });
+        <Reserves<T>>::mutate(dest_id, |reserve| reserve.locked = reserve.locked.saturating_add(amount));
+        <ReserveBalance<T>>::mutate(|balance| *balance = balance.saturating_add(amount));
+        Ok(())
+    }
+
+    /// Account for an amount of the native token released by the bridge account.
+    /// Releases from an unknown chain only count towards the reserve balance.
+    fn release_native(src_id: Option<ChainId>, amount: BalanceOf<T>) {
+        if let Some(id) = src_id {
+            <Reserves<T>>::mutate(id, |reserve| reserve.released = reserve.released.saturating_add(amount));
+        }
+        <ReserveBalance<T>>::mutate(|balance| *balance = balance.saturating_sub(amount));
+    }
+
+    /// Native token reserve of the bridge account, with the running totals of each chain
+    pub fn reserve_report() -> ReserveReport<BalanceOf<T>> {
+        let bridge_id = <chainbridge::Module<T>>::account_id();
+        ReserveReport {
+            expected: Self::reserve_balance(),
+            held: <T as Config>::Currency::free_balance(&bridge_id),
+            chains: <Reserves<T>>::iter().collect(),
+        }
+    }
+
+    /// Checks the bridge account holds at least the native token reserve.
+    /// It can hold more, from outbound transfers held for review or direct transfers to the account.
+    /// Run by the try-runtime checks of the runtimes, since `decl_module` has no try-state hook.
+    pub fn do_try_state() -> Result<(), &'static str> {
+        let report = Self::reserve_report();
+        ensure!(report.held >= report.expected, "Bridge account holds less than the native token reserve");
+        Ok(())
+    }
+
+    /// Deposit an amount of a bridged asset held by the bridge account to a destination chain.
+    /// Wrapped assets are burned, assets of this chain stay locked in the bridge account.
+    fn deposit_asset(dest_id: ChainId, r_id: ResourceId, recipient: Vec<u8>, amount: AssetBalanceOf<T>) -> DispatchResult {
+        let asset = Self::bridged_assets(r_id).ok_or(Error::<T>::UnknownAsset)?;
+        if asset.kind == AssetKind::Minted {
+            let bridge_id = <chainbridge::Module<T>>::account_id();
+            T::Assets::burn_from(asset.id, &bridge_id, amount)?;
+        }
+        <chainbridge::Module<T>>::transfer_fungible(dest_id, r_id, recipient, Self::to_u256(amount)).map(|_| ())
+    }
+
+    /// Release an amount of a bridged asset to an account on this chain.
+    /// Wrapped assets are minted, assets of this chain are unlocked from the bridge account.
+    fn release_asset(r_id: ResourceId, to: &T::AccountId, amount: AssetBalanceOf<T>) -> DispatchResult {
+        let asset = Self::bridged_assets(r_id).ok_or(Error::<T>::UnknownAsset)?;
+        match asset.kind {
+            AssetKind::Minted => T::Assets::mint_into(asset.id, to, amount),
+            AssetKind::Locked => {
+                let bridge_id = <chainbridge::Module<T>>::account_id();
+                T::Assets::transfer(asset.id, &bridge_id, to, amount, false).map(|_| ())
+            },
+        }
+    }
+
+    /// Hold a transfer for review
+    fn queue_transfer(transfer: PendingTransferOf<T>) {
+        let id = <NextTransferId>::mutate(|id| {
+            let current = *id;
+            *id += 1;
+            current
+        });
+        let event = match &transfer {
+            PendingTransfer::Inbound { amount, .. } =>
+                RawEvent::TransferQueued(id, Direction::Inbound, *amount),
+            PendingTransfer::Outbound { amount, .. } =>
+                RawEvent::TransferQueued(id, Direction::Outbound, *amount),
+            PendingTransfer::InboundAsset { r_id, amount, .. } =>
+                RawEvent::AssetTransferQueued(id, Direction::Inbound, *r_id, *amount),
+            PendingTransfer::OutboundAsset { r_id, amount, .. } =>
+                RawEvent::AssetTransferQueued(id, Direction::Outbound, *r_id, *amount),
+        };
+        <PendingTransfers<T>>::insert(id, transfer);
+        Self::deposit_event(event);
+    }
+
+    fn to_u256<B: UniqueSaturatedInto<u128>>(amount: B) -> U256 {
+        let amount: u128 = amount.unique_saturated_into();
+        U256::from(amount)
+    }
+
+    /// Token ID of an item on other chains, as 32 bytes big endian
+    fn token_id(item: <T as Config>::ItemId) -> Vec<u8> {
+        let id: U256 = item.into();
+        let mut token_id = [0u8; 32];
+        id.to_big_endian(&mut token_id);
+        token_id.to_vec()
+    }
+
     fn ensure_admin(o: T::RuntimeOrigin) -> DispatchResult {
         <T as Config>::AdminOrigin::try_origin(o)
             .map(|_| ())
@@ -2,7 +2,7 @@
 
 use super::*;
 
-use frame_support::{parameter_types, weights::Weight, PalletId};
+use frame_support::{parameter_types, traits::{AsEnsureOriginWithArg, ConstU32, ConstU64}, weights::Weight, PalletId};
 use frame_system::{self as system};
 use sp_core::hashing::blake2_128;
 use sp_core::H256;
@@ -68,11 +68,60 @@ impl pallet_balances::Config for Test {
     type ReserveIdentifier = [u8; 8];
 }
 
+impl pallet_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = u64;
+    type AssetId = u32;
+    type AssetIdParameter = codec::Compact<u32>;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+    type ForceOrigin = frame_system::EnsureRoot<u64>;
+    type AssetDeposit = ConstU64<1>;
+    type AssetAccountDeposit = ConstU64<1>;
+    type MetadataDepositBase = ConstU64<1>;
+    type MetadataDepositPerByte = ConstU64<1>;
+    type ApprovalDeposit = ConstU64<1>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type WeightInfo = ();
+    type RemoveItemsLimit = ConstU32<1000>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+impl pallet_uniques::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type ForceOrigin = frame_system::EnsureRoot<u64>;
+    type CollectionDeposit = ConstU64<2>;
+    type ItemDeposit = ConstU64<1>;
+    type MetadataDepositBase = ConstU64<1>;
+    type AttributeDepositBase = ConstU64<1>;
+    type DepositPerByte = ConstU64<1>;
+    type StringLimit = ConstU32<50>;
+    type KeyLimit = ConstU32<50>;
+    type ValueLimit = ConstU32<50>;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+    type Locker = ();
+}
+
 const PALLET_ID: PalletId = PalletId(*b"cb/bridg");
 
 parameter_types! {
     pub const TestChainId: u8 = 5;
     pub const ProposalLifetime: u64 = 100;
+    pub const ProposalRetention: u64 = 100;
+    pub const MaxRelayers: u32 = 16;
+    pub const RateLimitWindow: u64 = 100;
+    pub const MaxDepositDataLength: u32 = 256;
+    pub const RelayerBond: u64 = 0;
+    pub const MaxMissedVotes: u32 = 2;
     pub const ChainbridgePalletId: PalletId = PALLET_ID;
 }
 
@@ -82,11 +131,21 @@ impl bridge::Config for Test {
     type Proposal = RuntimeCall;
     type ChainId = TestChainId;
     type ProposalLifetime = ProposalLifetime;
+    type ProposalRetention = ProposalRetention;
+    type MaxRelayers = MaxRelayers;
+    type RateLimitWindow = RateLimitWindow;
+    type MaxDepositDataLength = MaxDepositDataLength;
+    type Currency = Balances;
+    type RelayerBond = RelayerBond;
+    type MaxMissedVotes = MaxMissedVotes;
+    type Slashed = ();
+    type WeightInfo = ();
     type PalletId = ChainbridgePalletId;
 }
 
 parameter_types! {
     pub NativeTokenId: bridge::ResourceId = bridge::derive_resource_id(1, &blake2_128(b"DAV"));
+    pub const RefundTimeout: u64 = 100;
 }
 
 impl Config for Test {
@@ -95,6 +154,12 @@ impl Config for Test {
     type AdminOrigin = bridge::EnsureBridge<Test>;
     type Currency = Balances;
     type NativeTokenId = NativeTokenId;
+    type RefundTimeout = RefundTimeout;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Nfts = Uniques;
+    type AssetId = u32;
+    type Assets = Assets;
     type WeightInfo = weights::SubstrateWeight<Self>;
 }
 
@@ -113,6 +178,8 @@ frame_support::construct_runtime!(
         System: system::{Pallet, Call, Event<T>},
         Balances: balances::{Pallet, Call, Storage, Config<T>, Event<T>},
         Bridge: bridge::{Pallet, Call, Storage, Event<T>},
+        Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
+        Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
         Swap: swap::{Pallet, Call, Event<T>}
     }
 );
@@ -10,7 +10,7 @@ use super::*;
 use super::mock::RuntimeEvent;
 
 use frame_support::dispatch::DispatchError;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, StorageValue};
 
 
 ///////////////////////////////////////////
@@ -82,6 +82,120 @@ fn transfer_native_successful() {
     })
 }
 
+#[test]
+fn transfer_native_fails_when_paused() {
+    let amount: u64 = 1000;
+    new_test_ext(&[(ACCOUNT_A, amount + SWAP_FEE)]).execute_with(|| {
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN));
+        assert_ok!(Bridge::set_chain_paused(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN, true));
+        assert_noop!(
+            Swap::transfer_native(
+                RuntimeOrigin::signed(ACCOUNT_A),
+                amount,
+                TEST_RECIPIENT_ADDR.to_vec(),
+                TEST_DESTINATION_CHAIN,
+            ),
+            bridge::Error::<Test>::ChainPaused
+        );
+    })
+}
+
+fn set_limit(direction: bridge::Direction, target: bridge::LimitTarget, max_transfer: u64, max_volume: u64) {
+    assert_ok!(Bridge::set_rate_limit(
+        RuntimeOrigin::root(),
+        direction,
+        target,
+        Some(bridge::RateLimit { max_transfer: max_transfer.into(), max_volume: max_volume.into() }),
+    ));
+}
+
+#[test]
+fn transfer_native_over_limit_is_queued_and_approved() {
+    let amount: u64 = 1000;
+    new_test_ext(&[(ACCOUNT_A, 2 * (amount + SWAP_FEE)), (FEE_DESTINATION, 0)]).execute_with(|| {
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN));
+        set_limit(bridge::Direction::Outbound, bridge::LimitTarget::Chain(TEST_DESTINATION_CHAIN), amount, amount);
+
+        // First transfer is within the limits
+        assert_ok!(Swap::transfer_native(
+            RuntimeOrigin::signed(ACCOUNT_A),
+            amount,
+            TEST_RECIPIENT_ADDR.to_vec(),
+            TEST_DESTINATION_CHAIN,
+        ));
+
+        // Second transfer is over the volume limit, so it's held for review
+        assert_ok!(Swap::transfer_native(
+            RuntimeOrigin::signed(ACCOUNT_A),
+            amount,
+            TEST_RECIPIENT_ADDR.to_vec(),
+            TEST_DESTINATION_CHAIN,
+        ));
+        expect_event(RawEvent::TransferQueued(0, bridge::Direction::Outbound, amount));
+        assert_eq!(Balances::free_balance(Bridge::account_id()), 2 * amount);
+        assert_eq!(Bridge::chains(TEST_DESTINATION_CHAIN), Some(1));
+        assert_eq!(
+            Swap::pending_transfers(0),
+            Some(PendingTransfer::Outbound {
+                from: ACCOUNT_A,
+                dest_id: TEST_DESTINATION_CHAIN,
+                recipient: TEST_RECIPIENT_ADDR.to_vec(),
+                amount,
+                fee: SWAP_FEE,
+            })
+        );
+
+        assert_ok!(Swap::approve_transfer(RuntimeOrigin::root(), 0));
+        assert_eq!(Swap::pending_transfers(0), None);
+        // Deposit is recorded with the fee paid when the transfer was queued, so it can be refunded
+        assert_eq!(
+            Swap::native_deposits(TEST_DESTINATION_CHAIN, 2),
+            Some(NativeDeposit { from: ACCOUNT_A, amount, fee: SWAP_FEE })
+        );
+        assert_events(vec![
+            RuntimeEvent::Bridge(bridge::RawEvent::FungibleTransfer(
+                TEST_DESTINATION_CHAIN,
+                2,
+                NativeTokenId::get(),
+                amount.into(),
+                TEST_RECIPIENT_ADDR.to_vec(),
+            )),
+            RuntimeEvent::Swap(RawEvent::TransferApproved(0)),
+        ]);
+
+        assert_noop!(
+            Swap::approve_transfer(RuntimeOrigin::root(), 0),
+            Error::<Test>::UnknownTransfer
+        );
+    })
+}
+
+#[test]
+fn transfer_native_over_limit_is_refunded_when_rejected() {
+    let amount: u64 = 1000;
+    new_test_ext(&[(ACCOUNT_A, amount + SWAP_FEE), (FEE_DESTINATION, 0)]).execute_with(|| {
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN));
+        set_limit(bridge::Direction::Outbound, bridge::LimitTarget::Resource(NativeTokenId::get()), amount - 1, 10 * amount);
+
+        // Transfer is over the maximum amount, so it's held for review
+        assert_ok!(Swap::transfer_native(
+            RuntimeOrigin::signed(ACCOUNT_A),
+            amount,
+            TEST_RECIPIENT_ADDR.to_vec(),
+            TEST_DESTINATION_CHAIN,
+        ));
+        expect_event(RawEvent::TransferQueued(0, bridge::Direction::Outbound, amount));
+        assert_eq!(Balances::free_balance(ACCOUNT_A), 0);
+
+        // Amount is refunded, fee is not
+        assert_ok!(Swap::reject_transfer(RuntimeOrigin::root(), 0));
+        expect_event(RawEvent::TransferRejected(0));
+        assert_eq!(Balances::free_balance(ACCOUNT_A), amount);
+        assert_eq!(Balances::free_balance(Bridge::account_id()), 0);
+        assert_eq!(Bridge::chains(TEST_DESTINATION_CHAIN), Some(0));
+    })
+}
+
 ///////////////////////////////////////////
 //                transfer              ///
 ///////////////////////////////////////////
@@ -118,6 +232,377 @@ fn transfer_successful_with_bridge_origin() {
     })
 }
 
+#[test]
+fn transfer_over_limit_is_queued_and_approved() {
+    let amount: u64 = 1000;
+    new_test_ext(&[(Bridge::account_id(), amount), (ACCOUNT_A, 0)]).execute_with(|| {
+        set_limit(bridge::Direction::Inbound, bridge::LimitTarget::Resource(NativeTokenId::get()), amount - 1, 10 * amount);
+
+        assert_ok!(Swap::transfer(RuntimeOrigin::signed(Bridge::account_id()), ACCOUNT_A, amount));
+        expect_event(RawEvent::TransferQueued(0, bridge::Direction::Inbound, amount));
+        assert_eq!(
+            Swap::pending_transfers(0),
+            Some(PendingTransfer::Inbound { src_id: None, to: ACCOUNT_A, amount })
+        );
+        assert_eq!(Balances::free_balance(&ACCOUNT_A), 0);
+
+        assert_ok!(Swap::approve_transfer(RuntimeOrigin::root(), 0));
+        assert_eq!(Balances::free_balance(&ACCOUNT_A), amount);
+        assert_eq!(Balances::free_balance(&Bridge::account_id()), 0);
+    })
+}
+
+///////////////////////////////////////////
+//          non-fungible transfers      ///
+///////////////////////////////////////////
+
+const TEST_COLLECTION: u32 = 0;
+const TEST_ITEM: u32 = 42;
+
+fn nft_resource() -> bridge::ResourceId {
+    bridge::derive_resource_id(1, b"nft")
+}
+
+// Creates a collection owned by the bridge account and bridges it with the NFT resource
+fn setup_collection() {
+    assert_ok!(Uniques::create(RuntimeOrigin::signed(Bridge::account_id()), TEST_COLLECTION, Bridge::account_id()));
+    assert_ok!(Swap::set_collection(RuntimeOrigin::root(), nft_resource(), Some(TEST_COLLECTION)));
+}
+
+fn item_owner(item: u32) -> Option<AccountId> {
+    <Uniques as nonfungibles::Inspect<AccountId>>::owner(&TEST_COLLECTION, &item)
+}
+
+#[test]
+fn set_collection_fails_for_non_admin_origin() {
+    new_test_ext(&[]).execute_with(|| {
+        assert_noop!(
+            Swap::set_collection(RuntimeOrigin::signed(ACCOUNT_A), nft_resource(), Some(TEST_COLLECTION)),
+            DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn set_collection_successful_with_admin_origin() {
+    new_test_ext(&[]).execute_with(|| {
+        assert_ok!(Swap::set_collection(RuntimeOrigin::root(), nft_resource(), Some(TEST_COLLECTION)));
+        assert_eq!(Swap::collections(nft_resource()), Some(TEST_COLLECTION));
+        expect_event(swap::RawEvent::CollectionChanged(nft_resource(), Some(TEST_COLLECTION)));
+
+        assert_ok!(Swap::set_collection(RuntimeOrigin::root(), nft_resource(), None));
+        assert_eq!(Swap::collections(nft_resource()), None);
+        expect_event(swap::RawEvent::CollectionChanged(nft_resource(), None));
+    })
+}
+
+#[test]
+fn transfer_nft_fails_for_unknown_collection_or_other_owner() {
+    new_test_ext(&[(Bridge::account_id(), 100)]).execute_with(|| {
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN));
+        assert_noop!(
+            Swap::transfer_nft(RuntimeOrigin::signed(ACCOUNT_A), nft_resource(), TEST_ITEM, TEST_RECIPIENT_ADDR.to_vec(), TEST_DESTINATION_CHAIN),
+            Error::<Test>::UnknownCollection
+        );
+
+        setup_collection();
+        assert_ok!(Uniques::mint(RuntimeOrigin::signed(Bridge::account_id()), TEST_COLLECTION, TEST_ITEM, FEE_DESTINATION));
+        assert_noop!(
+            Swap::transfer_nft(RuntimeOrigin::signed(ACCOUNT_A), nft_resource(), TEST_ITEM, TEST_RECIPIENT_ADDR.to_vec(), TEST_DESTINATION_CHAIN),
+            Error::<Test>::NotItemOwner
+        );
+    })
+}
+
+#[test]
+fn transfer_nft_locks_item_in_bridge_account() {
+    let metadata = b"ipfs://item".to_vec();
+    new_test_ext(&[(Bridge::account_id(), 100)]).execute_with(|| {
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN));
+        setup_collection();
+        assert_ok!(Uniques::mint(RuntimeOrigin::signed(Bridge::account_id()), TEST_COLLECTION, TEST_ITEM, ACCOUNT_A));
+        assert_ok!(Uniques::set_metadata(
+            RuntimeOrigin::signed(Bridge::account_id()),
+            TEST_COLLECTION,
+            TEST_ITEM,
+            metadata.clone().try_into().unwrap(),
+            false,
+        ));
+
+        assert_ok!(Swap::transfer_nft(
+            RuntimeOrigin::signed(ACCOUNT_A),
+            nft_resource(),
+            TEST_ITEM,
+            TEST_RECIPIENT_ADDR.to_vec(),
+            TEST_DESTINATION_CHAIN,
+        ));
+        assert_eq!(item_owner(TEST_ITEM), Some(Bridge::account_id()));
+
+        // Token ID is the item ID as 32 bytes big endian
+        let mut token_id = [0u8; 32];
+        token_id[31] = TEST_ITEM as u8;
+        expect_event(bridge::RawEvent::NonFungibleTransfer(
+            TEST_DESTINATION_CHAIN,
+            1,
+            nft_resource(),
+            token_id.to_vec(),
+            TEST_RECIPIENT_ADDR.to_vec(),
+            metadata,
+        ));
+    })
+}
+
+#[test]
+fn receive_nft_fails_for_non_bridge_origin() {
+    new_test_ext(&[]).execute_with(|| {
+        assert_noop!(
+            Swap::receive_nft(RuntimeOrigin::signed(ACCOUNT_A), ACCOUNT_A, nft_resource(), TEST_ITEM.into()),
+            DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn receive_nft_mints_or_unlocks_item() {
+    new_test_ext(&[(Bridge::account_id(), 100)]).execute_with(|| {
+        let bridge_origin = RuntimeOrigin::signed(Bridge::account_id());
+        assert_noop!(
+            Swap::receive_nft(bridge_origin.clone(), ACCOUNT_A, nft_resource(), TEST_ITEM.into()),
+            Error::<Test>::UnknownCollection
+        );
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN));
+        setup_collection();
+        assert_noop!(
+            Swap::receive_nft(bridge_origin.clone(), ACCOUNT_A, nft_resource(), U256::MAX),
+            Error::<Test>::InvalidTokenId
+        );
+
+        // Items that don't exist on this chain are minted
+        assert_ok!(Swap::receive_nft(bridge_origin.clone(), ACCOUNT_A, nft_resource(), TEST_ITEM.into()));
+        assert_eq!(item_owner(TEST_ITEM), Some(ACCOUNT_A));
+
+        // Items owned by other accounts can't be taken
+        assert_noop!(
+            Swap::receive_nft(bridge_origin.clone(), FEE_DESTINATION, nft_resource(), TEST_ITEM.into()),
+            Error::<Test>::ItemNotLocked
+        );
+
+        // Items locked in the bridge account are unlocked
+        assert_ok!(Swap::transfer_nft(
+            RuntimeOrigin::signed(ACCOUNT_A),
+            nft_resource(),
+            TEST_ITEM,
+            TEST_RECIPIENT_ADDR.to_vec(),
+            TEST_DESTINATION_CHAIN,
+        ));
+        assert_ok!(Swap::receive_nft(bridge_origin, FEE_DESTINATION, nft_resource(), TEST_ITEM.into()));
+        assert_eq!(item_owner(TEST_ITEM), Some(FEE_DESTINATION));
+    })
+}
+
+///////////////////////////////////////////
+//            asset transfers           ///
+///////////////////////////////////////////
+
+const TEST_ASSET: u32 = 7;
+const ASSET_FEE: u64 = 10;
+
+fn asset_resource() -> bridge::ResourceId {
+    bridge::derive_resource_id(1, b"asset")
+}
+
+// Creates an asset and bridges it with the asset resource
+fn setup_asset(kind: AssetKind) {
+    assert_ok!(<Assets as fungibles::Create<AccountId>>::create(TEST_ASSET, Bridge::account_id(), true, 1));
+    assert_ok!(Swap::set_asset(
+        RuntimeOrigin::root(),
+        asset_resource(),
+        Some(BridgedAsset { id: TEST_ASSET, kind, fee: ASSET_FEE }),
+    ));
+}
+
+fn mint_asset(who: AccountId, amount: u64) {
+    assert_ok!(<Assets as fungibles::Mutate<AccountId>>::mint_into(TEST_ASSET, &who, amount));
+}
+
+fn asset_balance(who: AccountId) -> u64 {
+    <Assets as fungibles::Inspect<AccountId>>::balance(TEST_ASSET, &who)
+}
+
+#[test]
+fn set_asset_fails_for_non_admin_origin() {
+    new_test_ext(&[]).execute_with(|| {
+        assert_noop!(
+            Swap::set_asset(
+                RuntimeOrigin::signed(ACCOUNT_A),
+                asset_resource(),
+                Some(BridgedAsset { id: TEST_ASSET, kind: AssetKind::Minted, fee: ASSET_FEE }),
+            ),
+            DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn set_asset_successful_with_admin_origin() {
+    let asset = BridgedAsset { id: TEST_ASSET, kind: AssetKind::Minted, fee: ASSET_FEE };
+    new_test_ext(&[]).execute_with(|| {
+        assert_ok!(Swap::set_asset(RuntimeOrigin::root(), asset_resource(), Some(asset.clone())));
+        assert_eq!(Swap::bridged_assets(asset_resource()), Some(asset.clone()));
+        expect_event(swap::RawEvent::AssetChanged(asset_resource(), Some(asset)));
+
+        assert_ok!(Swap::set_asset(RuntimeOrigin::root(), asset_resource(), None));
+        assert_eq!(Swap::bridged_assets(asset_resource()), None);
+        expect_event(swap::RawEvent::AssetChanged(asset_resource(), None));
+    })
+}
+
+#[test]
+fn transfer_asset_fails_for_unknown_asset_or_insufficient_balance() {
+    let amount: u64 = 100;
+    new_test_ext(&[]).execute_with(|| {
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN));
+        assert_noop!(
+            Swap::transfer_asset(RuntimeOrigin::signed(ACCOUNT_A), asset_resource(), amount, TEST_RECIPIENT_ADDR.to_vec(), TEST_DESTINATION_CHAIN),
+            Error::<Test>::UnknownAsset
+        );
+
+        setup_asset(AssetKind::Locked);
+        mint_asset(ACCOUNT_A, amount + ASSET_FEE - 1);
+        assert_noop!(
+            Swap::transfer_asset(RuntimeOrigin::signed(ACCOUNT_A), asset_resource(), amount, TEST_RECIPIENT_ADDR.to_vec(), TEST_DESTINATION_CHAIN),
+            Error::<Test>::InsufficientBalance
+        );
+    })
+}
+
+#[test]
+fn transfer_asset_locks_asset_of_this_chain() {
+    let amount: u64 = 100;
+    new_test_ext(&[]).execute_with(|| {
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN));
+        setup_asset(AssetKind::Locked);
+        mint_asset(ACCOUNT_A, amount + ASSET_FEE);
+
+        assert_ok!(Swap::transfer_asset(
+            RuntimeOrigin::signed(ACCOUNT_A),
+            asset_resource(),
+            amount,
+            TEST_RECIPIENT_ADDR.to_vec(),
+            TEST_DESTINATION_CHAIN,
+        ));
+        // fee paid in the asset, amount locked in the bridge account
+        assert_eq!(asset_balance(ACCOUNT_A), 0);
+        assert_eq!(asset_balance(FEE_DESTINATION), ASSET_FEE);
+        assert_eq!(asset_balance(Bridge::account_id()), amount);
+        expect_event(bridge::RawEvent::FungibleTransfer(
+            TEST_DESTINATION_CHAIN,
+            1,
+            asset_resource(),
+            amount.into(),
+            TEST_RECIPIENT_ADDR.to_vec(),
+        ));
+    })
+}
+
+#[test]
+fn transfer_asset_burns_wrapped_asset() {
+    let amount: u64 = 100;
+    new_test_ext(&[]).execute_with(|| {
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN));
+        setup_asset(AssetKind::Minted);
+        mint_asset(ACCOUNT_A, amount + ASSET_FEE);
+
+        assert_ok!(Swap::transfer_asset(
+            RuntimeOrigin::signed(ACCOUNT_A),
+            asset_resource(),
+            amount,
+            TEST_RECIPIENT_ADDR.to_vec(),
+            TEST_DESTINATION_CHAIN,
+        ));
+        assert_eq!(asset_balance(Bridge::account_id()), 0);
+        assert_eq!(<Assets as fungibles::Inspect<AccountId>>::total_issuance(TEST_ASSET), ASSET_FEE);
+    })
+}
+
+#[test]
+fn transfer_asset_over_limit_is_refunded_when_rejected() {
+    let amount: u64 = 100;
+    new_test_ext(&[]).execute_with(|| {
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN));
+        setup_asset(AssetKind::Minted);
+        mint_asset(ACCOUNT_A, amount + ASSET_FEE);
+        set_limit(bridge::Direction::Outbound, bridge::LimitTarget::Resource(asset_resource()), amount - 1, 10 * amount);
+
+        // Transfer is over the maximum amount, so it's held for review
+        assert_ok!(Swap::transfer_asset(
+            RuntimeOrigin::signed(ACCOUNT_A),
+            asset_resource(),
+            amount,
+            TEST_RECIPIENT_ADDR.to_vec(),
+            TEST_DESTINATION_CHAIN,
+        ));
+        expect_event(RawEvent::AssetTransferQueued(0, bridge::Direction::Outbound, asset_resource(), amount));
+        assert_eq!(asset_balance(Bridge::account_id()), amount);
+
+        // Amount is refunded, fee is not
+        assert_ok!(Swap::reject_transfer(RuntimeOrigin::root(), 0));
+        assert_eq!(asset_balance(ACCOUNT_A), amount);
+        assert_eq!(asset_balance(Bridge::account_id()), 0);
+        assert_eq!(Bridge::chains(TEST_DESTINATION_CHAIN), Some(0));
+    })
+}
+
+#[test]
+fn receive_asset_fails_for_non_bridge_origin() {
+    new_test_ext(&[]).execute_with(|| {
+        assert_noop!(
+            Swap::receive_asset(RuntimeOrigin::signed(ACCOUNT_A), ACCOUNT_A, asset_resource(), 100),
+            DispatchError::BadOrigin
+        );
+    })
+}
+
+#[test]
+fn receive_asset_mints_wrapped_asset() {
+    let amount: u64 = 100;
+    new_test_ext(&[]).execute_with(|| {
+        let bridge_origin = RuntimeOrigin::signed(Bridge::account_id());
+        assert_noop!(
+            Swap::receive_asset(bridge_origin.clone(), ACCOUNT_A, asset_resource(), amount),
+            Error::<Test>::UnknownAsset
+        );
+
+        setup_asset(AssetKind::Minted);
+        assert_ok!(Swap::receive_asset(bridge_origin, ACCOUNT_A, asset_resource(), amount));
+        assert_eq!(asset_balance(ACCOUNT_A), amount);
+        assert_eq!(<Assets as fungibles::Inspect<AccountId>>::total_issuance(TEST_ASSET), amount);
+    })
+}
+
+#[test]
+fn receive_asset_over_limit_is_queued_and_approved() {
+    let amount: u64 = 100;
+    new_test_ext(&[]).execute_with(|| {
+        setup_asset(AssetKind::Locked);
+        mint_asset(Bridge::account_id(), amount);
+        set_limit(bridge::Direction::Inbound, bridge::LimitTarget::Resource(asset_resource()), amount - 1, 10 * amount);
+
+        assert_ok!(Swap::receive_asset(RuntimeOrigin::signed(Bridge::account_id()), ACCOUNT_A, asset_resource(), amount));
+        expect_event(RawEvent::AssetTransferQueued(0, bridge::Direction::Inbound, asset_resource(), amount));
+        assert_eq!(
+            Swap::pending_transfers(0),
+            Some(PendingTransfer::InboundAsset { src_id: None, r_id: asset_resource(), to: ACCOUNT_A, amount })
+        );
+        assert_eq!(asset_balance(ACCOUNT_A), 0);
+
+        // Asset of this chain is unlocked from the bridge account
+        assert_ok!(Swap::approve_transfer(RuntimeOrigin::root(), 0));
+        assert_eq!(asset_balance(ACCOUNT_A), amount);
+        assert_eq!(asset_balance(Bridge::account_id()), 0);
+    })
+}
+
 ///////////////////////////////////////////
 //              set_swap_fee            ///
 ///////////////////////////////////////////
@@ -153,6 +638,228 @@ fn set_swap_fee_successful_with_admin_origin() {
     })
 }
 
+///////////////////////////////////////////
+//               fee models             ///
+///////////////////////////////////////////
+
+#[test]
+fn fee_model_is_clamped() {
+    let model = FeeModel { fixed: 10u64, bps: 100, min: 15, max: Some(50) };
+    assert_eq!(model.fee(100), 15);
+    assert_eq!(model.fee(2_000), 30);
+    assert_eq!(model.fee(10_000), 50);
+
+    let unbounded = FeeModel { max: None, ..model };
+    assert_eq!(unbounded.fee(10_000), 110);
+}
+
+#[test]
+fn set_chain_fee_fails_for_invalid_model() {
+    new_test_ext(&[]).execute_with(|| {
+        let model = FeeModel { fixed: 10, bps: 100, min: 15, max: Some(50) };
+        assert_noop!(
+            Swap::set_chain_fee(RuntimeOrigin::signed(ACCOUNT_A), TEST_DESTINATION_CHAIN, Some(model.clone())),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Swap::set_chain_fee(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN, Some(FeeModel { bps: MAX_FEE_BPS + 1, ..model.clone() })),
+            Error::<Test>::InvalidFeeModel
+        );
+        assert_noop!(
+            Swap::set_default_fee(RuntimeOrigin::root(), FeeModel { min: 51, ..model }),
+            Error::<Test>::InvalidFeeModel
+        );
+    })
+}
+
+#[test]
+fn transfer_native_pays_fee_of_destination_chain() {
+    let amount: u64 = 1000;
+    let model = FeeModel { fixed: 10, bps: 100, min: 0, max: None };
+    new_test_ext(&[(ACCOUNT_A, amount + 20), (FEE_DESTINATION, 0)]).execute_with(|| {
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN));
+        assert_ok!(Swap::set_chain_fee(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN, Some(model.clone())));
+        expect_event(swap::RawEvent::ChainFeeChanged(TEST_DESTINATION_CHAIN, Some(model)));
+
+        // Other chains use the default fee
+        assert_eq!(Swap::quote_fee(TEST_DESTINATION_CHAIN, amount), 20);
+        assert_eq!(Swap::quote_fee(TEST_DESTINATION_CHAIN + 1, amount), SWAP_FEE);
+
+        assert_ok!(Swap::transfer_native(
+            RuntimeOrigin::signed(ACCOUNT_A),
+            amount,
+            TEST_RECIPIENT_ADDR.to_vec(),
+            TEST_DESTINATION_CHAIN,
+        ));
+        assert_eq!(Balances::free_balance(FEE_DESTINATION), 20);
+        assert_eq!(Balances::free_balance(Bridge::account_id()), amount);
+
+        // Removing the chain fee falls back to the default fee
+        assert_ok!(Swap::set_chain_fee(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN, None));
+        assert_eq!(Swap::quote_fee(TEST_DESTINATION_CHAIN, amount), SWAP_FEE);
+    })
+}
+
+#[test]
+fn set_swap_fee_keeps_default_fee_model() {
+    let model = FeeModel { fixed: 10, bps: 100, min: 15, max: Some(50) };
+    new_test_ext(&[]).execute_with(|| {
+        assert_ok!(Swap::set_default_fee(RuntimeOrigin::root(), model.clone()));
+        expect_event(swap::RawEvent::DefaultFeeChanged(model.clone()));
+
+        assert_ok!(Swap::set_swap_fee(RuntimeOrigin::root(), 20));
+        assert_eq!(Swap::default_fee(), FeeModel { fixed: 20, ..model });
+    })
+}
+
+///////////////////////////////////////////
+//              refund_native           ///
+///////////////////////////////////////////
+
+// Deposits a native token transfer of `amount` from ACCOUNT_A
+fn deposit_refundable_transfer(amount: u64) {
+    assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN));
+    assert_ok!(Swap::transfer_native(
+        RuntimeOrigin::signed(ACCOUNT_A),
+        amount,
+        TEST_RECIPIENT_ADDR.to_vec(),
+        TEST_DESTINATION_CHAIN,
+    ));
+    assert_eq!(
+        Swap::native_deposits(TEST_DESTINATION_CHAIN, 1),
+        Some(NativeDeposit { from: ACCOUNT_A, amount, fee: SWAP_FEE })
+    );
+}
+
+#[test]
+fn refund_native_by_relayers_with_fee() {
+    let amount: u64 = 1000;
+    new_test_ext(&[(ACCOUNT_A, amount + SWAP_FEE), (FEE_DESTINATION, 0)]).execute_with(|| {
+        deposit_refundable_transfer(amount);
+        assert_eq!(Balances::free_balance(FEE_DESTINATION), SWAP_FEE);
+
+        // Bridge proposals can refund right away, the fee is taken back from the fee destination
+        assert_ok!(Swap::refund_native(RuntimeOrigin::signed(Bridge::account_id()), TEST_DESTINATION_CHAIN, 1, true));
+        assert_eq!(Balances::free_balance(ACCOUNT_A), amount + SWAP_FEE);
+        assert_eq!(Balances::free_balance(Bridge::account_id()), 0);
+        assert_eq!(Balances::free_balance(FEE_DESTINATION), 0);
+        assert_eq!(Swap::reserve_balance(), 0);
+        assert_eq!(Swap::reserves(TEST_DESTINATION_CHAIN), Reserve { locked: amount, released: amount });
+        assert!(Bridge::deposits(TEST_DESTINATION_CHAIN, 1).unwrap().refunded);
+        assert_eq!(Swap::native_deposits(TEST_DESTINATION_CHAIN, 1), None);
+        expect_event(swap::RawEvent::Refunded(TEST_DESTINATION_CHAIN, 1, ACCOUNT_A, amount + SWAP_FEE));
+
+        // Deposits are only refunded once
+        assert_noop!(
+            Swap::refund_native(RuntimeOrigin::signed(Bridge::account_id()), TEST_DESTINATION_CHAIN, 1, true),
+            bridge::Error::<Test>::DepositAlreadyRefunded
+        );
+    })
+}
+
+#[test]
+fn refund_native_by_admin_after_timeout() {
+    let amount: u64 = 1000;
+    new_test_ext(&[(ACCOUNT_A, amount + SWAP_FEE), (FEE_DESTINATION, 0)]).execute_with(|| {
+        deposit_refundable_transfer(amount);
+
+        assert_noop!(
+            Swap::refund_native(RuntimeOrigin::signed(ACCOUNT_A), TEST_DESTINATION_CHAIN, 1, false),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Swap::refund_native(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN, 2, false),
+            Error::<Test>::UnknownDeposit
+        );
+
+        // Admin origin has to wait for the refund timeout
+        System::set_block_number(RefundTimeout::get());
+        assert_noop!(
+            Swap::refund_native(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN, 1, false),
+            Error::<Test>::RefundTooEarly
+        );
+        System::set_block_number(1 + RefundTimeout::get());
+        assert_ok!(Swap::refund_native(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN, 1, false));
+
+        // Fee isn't refunded
+        assert_eq!(Balances::free_balance(ACCOUNT_A), amount);
+        assert_eq!(Balances::free_balance(FEE_DESTINATION), SWAP_FEE);
+        assert!(Bridge::deposits(TEST_DESTINATION_CHAIN, 1).unwrap().refunded);
+    })
+}
+
+#[test]
+fn refund_native_fails_for_other_deposits() {
+    new_test_ext(&[]).execute_with(|| {
+        assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), TEST_DESTINATION_CHAIN));
+        assert_ok!(Bridge::transfer_generic(TEST_DESTINATION_CHAIN, [1; 32], vec![]));
+        assert_noop!(
+            Swap::refund_native(RuntimeOrigin::signed(Bridge::account_id()), TEST_DESTINATION_CHAIN, 1, false),
+            Error::<Test>::UnknownDeposit
+        );
+    })
+}
+
+///////////////////////////////////////////
+//            reserve accounting        ///
+///////////////////////////////////////////
+
+#[test]
+fn reserves_track_locked_and_released_amounts() {
+    let amount: u64 = 1000;
+    new_test_ext(&[(ACCOUNT_A, amount + SWAP_FEE), (FEE_DESTINATION, 0)]).execute_with(|| {
+        deposit_refundable_transfer(amount);
+        assert_eq!(Swap::reserves(TEST_DESTINATION_CHAIN), Reserve { locked: amount, released: 0 });
+        assert_eq!(Swap::reserve_balance(), amount);
+        assert_ok!(Swap::do_try_state());
+
+        // Inbound transfer from the chain of the executing proposal
+        <bridge::ProposalSource>::put((TEST_DESTINATION_CHAIN, 1));
+        assert_ok!(Swap::transfer(RuntimeOrigin::signed(Bridge::account_id()), ACCOUNT_A, 300));
+        <bridge::ProposalSource>::kill();
+        assert_eq!(Swap::reserves(TEST_DESTINATION_CHAIN), Reserve { locked: amount, released: 300 });
+
+        // Inbound transfer from an unknown chain only reduces the reserve balance
+        assert_ok!(Swap::transfer(RuntimeOrigin::signed(Bridge::account_id()), ACCOUNT_A, 200));
+        assert_eq!(Swap::reserves(TEST_DESTINATION_CHAIN), Reserve { locked: amount, released: 300 });
+
+        assert_eq!(
+            Swap::reserve_report(),
+            ReserveReport {
+                expected: 500,
+                held: 500,
+                chains: vec![(TEST_DESTINATION_CHAIN, Reserve { locked: amount, released: 300 })],
+            }
+        );
+        assert_ok!(Swap::do_try_state());
+    })
+}
+
+#[test]
+fn reserves_count_refunds_as_released() {
+    let amount: u64 = 1000;
+    new_test_ext(&[(ACCOUNT_A, amount + SWAP_FEE), (FEE_DESTINATION, 0)]).execute_with(|| {
+        deposit_refundable_transfer(amount);
+        assert_ok!(Swap::refund_native(RuntimeOrigin::signed(Bridge::account_id()), TEST_DESTINATION_CHAIN, 1, false));
+        assert_eq!(Swap::reserves(TEST_DESTINATION_CHAIN), Reserve { locked: amount, released: amount });
+        assert_eq!(Swap::reserve_balance(), 0);
+        assert_ok!(Swap::do_try_state());
+    })
+}
+
+#[test]
+fn try_state_fails_when_bridge_account_is_drained() {
+    let amount: u64 = 1000;
+    new_test_ext(&[(ACCOUNT_A, amount + SWAP_FEE), (FEE_DESTINATION, 0)]).execute_with(|| {
+        deposit_refundable_transfer(amount);
+
+        // Funds leaving the bridge account outside of the swap pallet
+        assert_ok!(Balances::transfer(RuntimeOrigin::signed(Bridge::account_id()), ACCOUNT_A, 100));
+        assert_eq!(Swap::reserve_report().held, amount - 100);
+        assert!(Swap::do_try_state().is_err());
+    })
+}
+
 ///////////////////////////////////////////
 //           set_fee_destination        ///
 ///////////////////////////////////////////
@@ -324,6 +1031,7 @@ fn config_sets_expected_storage_items() {
         assert!(Bridge::is_relayer(&RELAYER_B));
         assert!(Bridge::resource_exists(resource_id));
         assert_eq!(Bridge::relayer_threshold(), 2);
+        assert_eq!(Swap::reserve_balance(), initial_bridge_balance);
     });
 
 }
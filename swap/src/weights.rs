@@ -15,13 +15,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for swap
+//! Weights for swap
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2021-09-13, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("xxnetwork-dev"), DB CACHE: 128
+//! The weights of `transfer_native`, `transfer`, `set_swap_fee` and `set_fee_destination` were
+//! generated by the benchmark CLI on 2021-09-13. The bridge pause, rate limits, fee models and reserve
+//! tracking added storage accesses to all but `set_fee_destination` since. These were counted by hand,
+//! and 1.5 µs was added to the generated time for each, to account for encoding and hashing.
+//!
+//! The other calls were added without running the benchmarks. Their weights are estimated the same
+//! way, from the generated call closest to them:
+//! - `transfer_nft` and `transfer_asset` from `transfer_native`
+//! - `receive_nft`, `receive_asset`, `approve_transfer`, `reject_transfer` and `refund_native`
+//!   from `transfer`
+//! - `set_default_fee`, `set_chain_fee`, `set_collection` and `set_asset` from `set_fee_destination`
+//!
+//! All weights should be replaced by the output of the command below once the benchmarks are run.
 
-// Executed Command:
+// Command:
 // target/release/xxnetwork-chain
 // benchmark
 // --chain=xxnetwork-dev
@@ -48,7 +58,18 @@ pub trait WeightInfo {
 	fn transfer_native() -> Weight;
 	fn transfer() -> Weight;
 	fn set_swap_fee() -> Weight;
+	fn set_default_fee() -> Weight;
+	fn set_chain_fee() -> Weight;
 	fn set_fee_destination() -> Weight;
+	fn approve_transfer() -> Weight;
+	fn reject_transfer() -> Weight;
+	fn transfer_nft() -> Weight;
+	fn receive_nft() -> Weight;
+	fn set_collection() -> Weight;
+	fn transfer_asset() -> Weight;
+	fn receive_asset() -> Weight;
+	fn set_asset() -> Weight;
+	fn refund_native() -> Weight;
 }
 
 /// Weights for swap using the Substrate node and recommended hardware.
@@ -57,21 +78,47 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: ChainBridge ChainNonces (r:1 w:1)
 	// Storage: Swap FeeDestination (r:1 w:0)
 	// Storage: System Account (r:3 w:3)
-	// Storage: Swap SwapFee (r:1 w:0)
+	// Storage: Swap ChainFees (r:1 w:0)
+	// Storage: Swap DefaultFee (r:1 w:0)
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: ChainBridge RateLimits (r:2 w:0)
+	// Storage: ChainBridge Volumes (r:2 w:2)
+	// Storage: ChainBridge Deposits (r:0 w:1)
+	// Storage: ChainBridge BlockDeposits (r:0 w:1)
+	// Storage: Swap NativeDeposits (r:0 w:1)
+	// Storage: Swap Reserves (r:1 w:1)
+	// Storage: Swap ReserveBalance (r:1 w:1)
 	fn transfer_native() -> Weight {
-		Weight::from_ref_time(112_387_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(6 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+		Weight::from_ref_time(136_387_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
 	}
 	// Storage: System Account (r:2 w:2)
+	// Storage: ChainBridge ProposalSource (r:1 w:0)
+	// Storage: ChainBridge RateLimits (r:2 w:0)
+	// Storage: ChainBridge Volumes (r:2 w:2)
+	// Storage: Swap Reserves (r:1 w:1)
+	// Storage: Swap ReserveBalance (r:1 w:1)
 	fn transfer() -> Weight {
-		Weight::from_ref_time(68_909_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+		Weight::from_ref_time(85_409_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
-	// Storage: Swap SwapFee (r:0 w:1)
+	// Storage: Swap DefaultFee (r:1 w:1)
 	fn set_swap_fee() -> Weight {
-		Weight::from_ref_time(19_255_000 as u64)
+		Weight::from_ref_time(20_755_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Swap DefaultFee (r:0 w:1)
+	fn set_default_fee() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Swap ChainFees (r:0 w:1)
+	fn set_chain_fee() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	// Storage: Swap FeeDestination (r:0 w:1)
@@ -79,6 +126,107 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_ref_time(19_658_000 as u64)
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: Swap PendingTransfers (r:1 w:1)
+	// Storage: ChainBridge ChainNonces (r:1 w:1)
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: ChainBridge Deposits (r:0 w:1)
+	// Storage: ChainBridge BlockDeposits (r:0 w:1)
+	// Storage: Swap NativeDeposits (r:0 w:1)
+	// Storage: Swap Reserves (r:1 w:1)
+	// Storage: Swap ReserveBalance (r:1 w:1)
+	fn approve_transfer() -> Weight {
+		Weight::from_ref_time(82_409_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: Swap PendingTransfers (r:1 w:1)
+	// Storage: Swap BridgedAssets (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn reject_transfer() -> Weight {
+		Weight::from_ref_time(79_409_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: ChainBridge ChainNonces (r:1 w:1)
+	// Storage: Swap Collections (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques ItemMetadataOf (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: ChainBridge Deposits (r:0 w:1)
+	// Storage: ChainBridge BlockDeposits (r:0 w:1)
+	fn transfer_nft() -> Weight {
+		Weight::from_ref_time(118_387_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: Swap Collections (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	fn receive_nft() -> Weight {
+		Weight::from_ref_time(76_409_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Swap Collections (r:0 w:1)
+	fn set_collection() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge ChainNonces (r:1 w:1)
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: Swap BridgedAssets (r:1 w:0)
+	// Storage: Swap FeeDestination (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:3 w:3)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ChainBridge RateLimits (r:2 w:0)
+	// Storage: ChainBridge Volumes (r:2 w:2)
+	// Storage: ChainBridge Deposits (r:0 w:1)
+	// Storage: ChainBridge BlockDeposits (r:0 w:1)
+	fn transfer_asset() -> Weight {
+		Weight::from_ref_time(136_387_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+	}
+	// Storage: Swap BridgedAssets (r:1 w:0)
+	// Storage: ChainBridge ProposalSource (r:1 w:0)
+	// Storage: ChainBridge RateLimits (r:2 w:0)
+	// Storage: ChainBridge Volumes (r:2 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn receive_asset() -> Weight {
+		Weight::from_ref_time(86_909_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Swap BridgedAssets (r:0 w:1)
+	fn set_asset() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Deposits (r:1 w:1)
+	// Storage: Swap NativeDeposits (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Swap Reserves (r:1 w:1)
+	// Storage: Swap ReserveBalance (r:1 w:1)
+	// Storage: Swap FeeDestination (r:1 w:0)
+	fn refund_native() -> Weight {
+		Weight::from_ref_time(85_409_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -86,21 +234,47 @@ impl WeightInfo for () {
 	// Storage: ChainBridge ChainNonces (r:1 w:1)
 	// Storage: Swap FeeDestination (r:1 w:0)
 	// Storage: System Account (r:3 w:3)
-	// Storage: Swap SwapFee (r:1 w:0)
+	// Storage: Swap ChainFees (r:1 w:0)
+	// Storage: Swap DefaultFee (r:1 w:0)
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: ChainBridge RateLimits (r:2 w:0)
+	// Storage: ChainBridge Volumes (r:2 w:2)
+	// Storage: ChainBridge Deposits (r:0 w:1)
+	// Storage: ChainBridge BlockDeposits (r:0 w:1)
+	// Storage: Swap NativeDeposits (r:0 w:1)
+	// Storage: Swap Reserves (r:1 w:1)
+	// Storage: Swap ReserveBalance (r:1 w:1)
 	fn transfer_native() -> Weight {
-		Weight::from_ref_time(112_387_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(6 as u64))
-			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+		Weight::from_ref_time(136_387_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
 	}
 	// Storage: System Account (r:2 w:2)
+	// Storage: ChainBridge ProposalSource (r:1 w:0)
+	// Storage: ChainBridge RateLimits (r:2 w:0)
+	// Storage: ChainBridge Volumes (r:2 w:2)
+	// Storage: Swap Reserves (r:1 w:1)
+	// Storage: Swap ReserveBalance (r:1 w:1)
 	fn transfer() -> Weight {
-		Weight::from_ref_time(68_909_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+		Weight::from_ref_time(85_409_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
-	// Storage: Swap SwapFee (r:0 w:1)
+	// Storage: Swap DefaultFee (r:1 w:1)
 	fn set_swap_fee() -> Weight {
-		Weight::from_ref_time(19_255_000 as u64)
+		Weight::from_ref_time(20_755_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Swap DefaultFee (r:0 w:1)
+	fn set_default_fee() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Swap ChainFees (r:0 w:1)
+	fn set_chain_fee() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
 	// Storage: Swap FeeDestination (r:0 w:1)
@@ -108,4 +282,105 @@ impl WeightInfo for () {
 		Weight::from_ref_time(19_658_000 as u64)
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	// Storage: Swap PendingTransfers (r:1 w:1)
+	// Storage: ChainBridge ChainNonces (r:1 w:1)
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: ChainBridge Deposits (r:0 w:1)
+	// Storage: ChainBridge BlockDeposits (r:0 w:1)
+	// Storage: Swap NativeDeposits (r:0 w:1)
+	// Storage: Swap Reserves (r:1 w:1)
+	// Storage: Swap ReserveBalance (r:1 w:1)
+	fn approve_transfer() -> Weight {
+		Weight::from_ref_time(82_409_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: Swap PendingTransfers (r:1 w:1)
+	// Storage: Swap BridgedAssets (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn reject_transfer() -> Weight {
+		Weight::from_ref_time(79_409_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: ChainBridge ChainNonces (r:1 w:1)
+	// Storage: Swap Collections (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques ItemMetadataOf (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: ChainBridge Deposits (r:0 w:1)
+	// Storage: ChainBridge BlockDeposits (r:0 w:1)
+	fn transfer_nft() -> Weight {
+		Weight::from_ref_time(118_387_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: Swap Collections (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	fn receive_nft() -> Weight {
+		Weight::from_ref_time(76_409_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Swap Collections (r:0 w:1)
+	fn set_collection() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge ChainNonces (r:1 w:1)
+	// Storage: ChainBridge Paused (r:1 w:0)
+	// Storage: ChainBridge PausedChains (r:1 w:0)
+	// Storage: Swap BridgedAssets (r:1 w:0)
+	// Storage: Swap FeeDestination (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:3 w:3)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ChainBridge RateLimits (r:2 w:0)
+	// Storage: ChainBridge Volumes (r:2 w:2)
+	// Storage: ChainBridge Deposits (r:0 w:1)
+	// Storage: ChainBridge BlockDeposits (r:0 w:1)
+	fn transfer_asset() -> Weight {
+		Weight::from_ref_time(136_387_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
+	}
+	// Storage: Swap BridgedAssets (r:1 w:0)
+	// Storage: ChainBridge ProposalSource (r:1 w:0)
+	// Storage: ChainBridge RateLimits (r:2 w:0)
+	// Storage: ChainBridge Volumes (r:2 w:2)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn receive_asset() -> Weight {
+		Weight::from_ref_time(86_909_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Swap BridgedAssets (r:0 w:1)
+	fn set_asset() -> Weight {
+		Weight::from_ref_time(19_658_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Deposits (r:1 w:1)
+	// Storage: Swap NativeDeposits (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Swap Reserves (r:1 w:1)
+	// Storage: Swap ReserveBalance (r:1 w:1)
+	// Storage: Swap FeeDestination (r:1 w:0)
+	fn refund_native() -> Weight {
+		Weight::from_ref_time(85_409_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
 }
@@ -22,12 +22,14 @@ use crate::keyring::*;
 use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
 use xxnetwork_runtime::{
 	GenesisConfig, BalancesConfig, SessionConfig, StakingConfig, SystemConfig,
-	GrandpaConfig, SwapConfig, wasm_binary_unwrap,
+	GrandpaConfig, SwapConfig, TxPauseConfig, NominationPoolsConfig, wasm_binary_unwrap,
 	AccountId, StakerStatus, BabeConfig, BABE_GENESIS_EPOCH_CONFIG,
 };
 use runtime_common::constants::currency::UNITS;
+use runtime_common::{PoolsMaxMembers, PoolsMaxPools, PoolsMinJoinBond};
 use node_primitives::Hash;
 use sp_runtime::Perbill;
+use sp_core::Get;
 
 /// Create genesis runtime configuration for tests.
 pub fn config(code: Option<&[u8]>) -> GenesisConfig {
@@ -115,5 +117,15 @@ pub fn config_endowed(
 		xx_betanet_rewards: Default::default(),
 		xx_public: Default::default(),
 		assets: Default::default(),
+		nomination_pools: NominationPoolsConfig {
+			min_join_bond: PoolsMinJoinBond::get(),
+			max_pools: Some(PoolsMaxPools::get()),
+			max_members_per_pool: None,
+			max_members: Some(PoolsMaxMembers::get()),
+			..Default::default()
+		},
+		tx_pause: TxPauseConfig {
+			paused_pallets: vec![b"ChainBridge".to_vec(), b"Swap".to_vec()],
+		},
 	}
 }
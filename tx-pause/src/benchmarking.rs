@@ -0,0 +1,56 @@
+use super::*;
+use crate::Module as TxPause;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::{dispatch::UnfilteredDispatchable, traits::EnsureOrigin};
+
+// Longest names, as names are hashed into the storage keys
+fn pallet_name<T: Config>() -> Vec<u8> {
+    vec![b'p'; T::MaxNameLen::get() as usize]
+}
+
+fn call_name<T: Config>() -> Vec<u8> {
+    vec![b'c'; T::MaxNameLen::get() as usize]
+}
+
+benchmarks!{
+    pause_pallet {
+        let origin = T::PauseOrigin::successful_origin();
+        let call = Call::<T>::pause_pallet { pallet_name: pallet_name::<T>() };
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(TxPause::<T>::pallet_paused(pallet_name::<T>()));
+    }
+
+    resume_pallet {
+        let origin = T::ResumeOrigin::successful_origin();
+        PausedPallets::insert(pallet_name::<T>(), true);
+        let call = Call::<T>::resume_pallet { pallet_name: pallet_name::<T>() };
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(!TxPause::<T>::pallet_paused(pallet_name::<T>()));
+    }
+
+    pause_call {
+        let origin = T::PauseOrigin::successful_origin();
+        let call = Call::<T>::pause_call { pallet_name: pallet_name::<T>(), call_name: call_name::<T>() };
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(TxPause::<T>::call_paused(pallet_name::<T>(), call_name::<T>()));
+    }
+
+    resume_call {
+        let origin = T::ResumeOrigin::successful_origin();
+        PausedCalls::insert(pallet_name::<T>(), call_name::<T>(), true);
+        let call = Call::<T>::resume_call { pallet_name: pallet_name::<T>(), call_name: call_name::<T>() };
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(!TxPause::<T>::call_paused(pallet_name::<T>(), call_name::<T>()));
+    }
+}
+
+impl_benchmark_test_suite!(
+    TxPause,
+    crate::mock::ExtBuilder::default().build(),
+    crate::mock::Test,
+);
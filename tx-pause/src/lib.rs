@@ -0,0 +1,173 @@
+//! # Transaction pause pallet
+//!
+//! Lets governance pause whole pallets or single calls without a runtime upgrade.
+//!
+//! Pausing and resuming use separate origins, so that a body able to pause quickly in an emergency
+//! doesn't have the final say: only `ResumeOrigin` can resume, whoever paused.
+//!
+//! Paused pallets and calls are kept in state by name, as given by the call metadata of the runtime.
+//! The pallet doesn't filter calls by itself: the runtime base call filter should reject the calls
+//! for which [`PausedFilter`] returns true, while keeping governance and this pallet callable so
+//! that paused calls can always be resumed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+pub mod weights;
+
+use frame_support::traits::{CallMetadata, Contains, EnsureOrigin, Get, GetCallMetadata, PalletInfo};
+use frame_support::{decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure};
+use sp_std::prelude::*;
+pub use weights::WeightInfo;
+
+pub trait Config: frame_system::Config {
+    /// The Event type
+    type RuntimeEvent: From<Event> + Into<<Self as frame_system::Config>::RuntimeEvent>;
+
+    /// Origin that can pause pallets and calls
+    type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+    /// Origin that can resume paused pallets and calls, whichever origin paused them
+    type ResumeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+    /// Maximum length of a pallet or call name
+    type MaxNameLen: Get<u32>;
+
+    /// Weight information for extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
+}
+
+decl_storage! {
+    trait Store for Module<T: Config> as TxPause {
+        /// Pallets with all their calls paused, by pallet name
+        pub PausedPallets get(fn pallet_paused): map hasher(blake2_128_concat) Vec<u8> => bool;
+
+        /// Calls paused on their own, by pallet and call name
+        pub PausedCalls get(fn call_paused):
+            double_map hasher(blake2_128_concat) Vec<u8>, hasher(blake2_128_concat) Vec<u8> => bool;
+    }
+
+    add_extra_genesis {
+        config(paused_pallets): Vec<Vec<u8>>;
+
+        build(|config: &GenesisConfig| {
+            for pallet in &config.paused_pallets {
+                PausedPallets::insert(pallet, true);
+            }
+        });
+    }
+}
+
+decl_event! {
+    pub enum Event {
+        /// All calls of a pallet were paused (pallet_name)
+        PalletPaused(Vec<u8>),
+        /// Calls of a pallet were resumed (pallet_name)
+        PalletResumed(Vec<u8>),
+        /// Call was paused (pallet_name, call_name)
+        CallPaused(Vec<u8>, Vec<u8>),
+        /// Call was resumed (pallet_name, call_name)
+        CallResumed(Vec<u8>, Vec<u8>),
+    }
+}
+
+decl_error! {
+    pub enum Error for Module<T: Config> {
+        /// Pallet or call name is longer than the maximum length
+        NameTooLong,
+        /// Calls of this pallet can't be paused
+        CannotPause,
+        /// Pallet or call is already paused
+        AlreadyPaused,
+        /// Pallet or call isn't paused
+        NotPaused,
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Config> for enum Call where origin: T::RuntimeOrigin {
+        const MaxNameLen: u32 = T::MaxNameLen::get();
+
+        type Error = Error<T>;
+
+        fn deposit_event() = default;
+
+        /// Pause all calls of a pallet
+        #[weight = T::WeightInfo::pause_pallet()]
+        pub fn pause_pallet(origin, pallet_name: Vec<u8>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+            Self::ensure_pausable(&pallet_name)?;
+            ensure!(!Self::pallet_paused(&pallet_name), Error::<T>::AlreadyPaused);
+            PausedPallets::insert(&pallet_name, true);
+            Self::deposit_event(Event::PalletPaused(pallet_name));
+            Ok(())
+        }
+
+        /// Resume the calls of a paused pallet. Calls paused on their own stay paused.
+        #[weight = T::WeightInfo::resume_pallet()]
+        pub fn resume_pallet(origin, pallet_name: Vec<u8>) -> DispatchResult {
+            T::ResumeOrigin::ensure_origin(origin)?;
+            ensure!(Self::pallet_paused(&pallet_name), Error::<T>::NotPaused);
+            PausedPallets::remove(&pallet_name);
+            Self::deposit_event(Event::PalletResumed(pallet_name));
+            Ok(())
+        }
+
+        /// Pause a single call of a pallet
+        #[weight = T::WeightInfo::pause_call()]
+        pub fn pause_call(origin, pallet_name: Vec<u8>, call_name: Vec<u8>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+            Self::ensure_pausable(&pallet_name)?;
+            ensure!(call_name.len() <= T::MaxNameLen::get() as usize, Error::<T>::NameTooLong);
+            ensure!(!Self::call_paused(&pallet_name, &call_name), Error::<T>::AlreadyPaused);
+            PausedCalls::insert(&pallet_name, &call_name, true);
+            Self::deposit_event(Event::CallPaused(pallet_name, call_name));
+            Ok(())
+        }
+
+        /// Resume a paused call. The call stays paused if its whole pallet is paused.
+        #[weight = T::WeightInfo::resume_call()]
+        pub fn resume_call(origin, pallet_name: Vec<u8>, call_name: Vec<u8>) -> DispatchResult {
+            T::ResumeOrigin::ensure_origin(origin)?;
+            ensure!(Self::call_paused(&pallet_name, &call_name), Error::<T>::NotPaused);
+            PausedCalls::remove(&pallet_name, &call_name);
+            Self::deposit_event(Event::CallResumed(pallet_name, call_name));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Module<T> {
+    /// Returns true if the call is paused, on its own or with its whole pallet
+    pub fn is_paused(pallet_name: &[u8], call_name: &[u8]) -> bool {
+        Self::pallet_paused(pallet_name) || Self::call_paused(pallet_name, call_name)
+    }
+
+    /// Checks the name length, and that the pallet isn't this one
+    fn ensure_pausable(pallet_name: &[u8]) -> DispatchResult {
+        ensure!(pallet_name.len() <= T::MaxNameLen::get() as usize, Error::<T>::NameTooLong);
+        let own_name = <T as frame_system::Config>::PalletInfo::name::<Self>();
+        ensure!(own_name.map_or(true, |name| name.as_bytes() != pallet_name), Error::<T>::CannotPause);
+        Ok(())
+    }
+}
+
+/// Matches the calls that are paused, either on their own or with their whole pallet
+pub struct PausedFilter<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> Contains<<T as frame_system::Config>::RuntimeCall> for PausedFilter<T>
+where
+    <T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+{
+    fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+        let CallMetadata { pallet_name, function_name } = call.get_call_metadata();
+        Module::<T>::is_paused(pallet_name.as_bytes(), function_name.as_bytes())
+    }
+}
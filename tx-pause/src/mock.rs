@@ -0,0 +1,159 @@
+use crate as tx_pause;
+use crate::*;
+
+use frame_support::{
+    parameter_types,
+    ord_parameter_types,
+    traits::{ConstU32, EitherOfDiverse, GenesisBuild},
+    weights::constants::RocksDbWeight,
+};
+use frame_system::EnsureSignedBy;
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+
+pub(crate) type AccountId = u64;
+pub(crate) type Balance = u64;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        TxPause: tx_pause::{Pallet, Call, Storage, Config, Event},
+    }
+);
+
+/// Filter as used by the runtime, with this pallet and System never paused
+pub struct BaseFilter;
+impl Contains<RuntimeCall> for BaseFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        match call {
+            RuntimeCall::System(_) | RuntimeCall::TxPause(_) => true,
+            RuntimeCall::Balances(_) => !PausedFilter::<Test>::contains(call),
+        }
+    }
+}
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = BaseFilter;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = RocksDbWeight;
+    type RuntimeOrigin = RuntimeOrigin;
+    type Index = u64;
+    type BlockNumber = u64;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ();
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+}
+
+ord_parameter_types! {
+    pub const PauseAccount: AccountId = 99;
+    pub const ResumeAccount: AccountId = 98;
+}
+
+impl tx_pause::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    // Both accounts can pause, as in the runtime, but only one can resume
+    type PauseOrigin = EitherOfDiverse<
+        EnsureSignedBy<PauseAccount, AccountId>,
+        EnsureSignedBy<ResumeAccount, AccountId>,
+    >;
+    type ResumeOrigin = EnsureSignedBy<ResumeAccount, AccountId>;
+    type MaxNameLen = ConstU32<32>;
+    type WeightInfo = ();
+}
+
+pub const ACCOUNT_A: AccountId = 1;
+pub const ACCOUNT_B: AccountId = 2;
+
+#[derive(Default)]
+pub struct ExtBuilder {
+    paused_pallets: Vec<Vec<u8>>,
+}
+
+impl ExtBuilder {
+    pub fn with_paused_pallet(mut self, pallet_name: &[u8]) -> Self {
+        self.paused_pallets.push(pallet_name.to_vec());
+        self
+    }
+
+    pub fn build(self) -> sp_io::TestExternalities {
+        let mut storage = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+
+        pallet_balances::GenesisConfig::<Test> {
+            balances: vec![(ACCOUNT_A, 100), (ACCOUNT_B, 100)],
+        }.assimilate_storage(&mut storage).unwrap();
+
+        tx_pause::GenesisConfig {
+            paused_pallets: self.paused_pallets,
+        }.assimilate_storage::<Test>(&mut storage).unwrap();
+
+        let mut ext = sp_io::TestExternalities::from(storage);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+
+    pub fn build_and_execute(self, test: impl FnOnce() -> ()) {
+        self.build().execute_with(test)
+    }
+}
+
+pub(crate) fn tx_pause_events() -> Vec<tx_pause::Event> {
+    System::events()
+        .into_iter()
+        .map(|r| r.event)
+        .filter_map(|e| {
+            if let RuntimeEvent::TxPause(inner) = e {
+                Some(inner)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,240 @@
+use super::*;
+use mock::*;
+
+use frame_support::{assert_noop, assert_ok, dispatch::Dispatchable};
+use sp_runtime::{
+    traits::BadOrigin,
+    DispatchError,
+};
+
+fn transfer_call() -> RuntimeCall {
+    RuntimeCall::Balances(pallet_balances::Call::transfer { dest: ACCOUNT_B, value: 10 })
+}
+
+fn remark_call() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn admin() -> RuntimeOrigin {
+    RuntimeOrigin::signed(PauseAccount::get())
+}
+
+fn resumer() -> RuntimeOrigin {
+    RuntimeOrigin::signed(ResumeAccount::get())
+}
+
+// pause_pallet / resume_pallet
+
+#[test]
+fn pause_pallet_called_by_non_admin_fails() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_noop!(
+                TxPause::pause_pallet(RuntimeOrigin::signed(ACCOUNT_A), b"Balances".to_vec()),
+                BadOrigin
+            );
+        })
+}
+
+#[test]
+fn pause_pallet_filters_its_calls() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_ok!(TxPause::pause_pallet(admin(), b"Balances".to_vec()));
+            assert!(TxPause::pallet_paused(b"Balances".to_vec()));
+            assert!(PausedFilter::<Test>::contains(&transfer_call()));
+            assert!(!PausedFilter::<Test>::contains(&remark_call()));
+            assert_noop!(
+                transfer_call().dispatch(RuntimeOrigin::signed(ACCOUNT_A)),
+                DispatchError::from(frame_system::Error::<Test>::CallFiltered)
+            );
+            assert_eq!(
+                tx_pause_events(),
+                vec![Event::PalletPaused(b"Balances".to_vec())]
+            );
+        })
+}
+
+#[test]
+fn resume_pallet_allows_its_calls() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_ok!(TxPause::pause_pallet(admin(), b"Balances".to_vec()));
+            assert_ok!(TxPause::resume_pallet(resumer(), b"Balances".to_vec()));
+            assert!(!PausedFilter::<Test>::contains(&transfer_call()));
+            assert_ok!(transfer_call().dispatch(RuntimeOrigin::signed(ACCOUNT_A)));
+            assert_eq!(Balances::free_balance(ACCOUNT_B), 110);
+            assert_eq!(
+                tx_pause_events(),
+                vec![
+                    Event::PalletPaused(b"Balances".to_vec()),
+                    Event::PalletResumed(b"Balances".to_vec()),
+                ]
+            );
+        })
+}
+
+#[test]
+fn resume_pallet_called_by_pause_origin_fails() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_ok!(TxPause::pause_pallet(admin(), b"Balances".to_vec()));
+            assert_noop!(
+                TxPause::resume_pallet(admin(), b"Balances".to_vec()),
+                BadOrigin
+            );
+            assert_noop!(
+                TxPause::resume_pallet(RuntimeOrigin::signed(ACCOUNT_A), b"Balances".to_vec()),
+                BadOrigin
+            );
+        })
+}
+
+#[test]
+fn resume_origin_can_pause_and_resume() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_ok!(TxPause::pause_pallet(resumer(), b"Balances".to_vec()));
+            assert_ok!(TxPause::resume_pallet(resumer(), b"Balances".to_vec()));
+            assert!(!TxPause::pallet_paused(b"Balances".to_vec()));
+        })
+}
+
+#[test]
+fn pause_pallet_fails_when_already_paused() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_ok!(TxPause::pause_pallet(admin(), b"Balances".to_vec()));
+            assert_noop!(
+                TxPause::pause_pallet(admin(), b"Balances".to_vec()),
+                Error::<Test>::AlreadyPaused
+            );
+        })
+}
+
+#[test]
+fn resume_pallet_fails_when_not_paused() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_noop!(
+                TxPause::resume_pallet(resumer(), b"Balances".to_vec()),
+                Error::<Test>::NotPaused
+            );
+        })
+}
+
+#[test]
+fn pause_pallet_fails_for_own_pallet() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_noop!(
+                TxPause::pause_pallet(admin(), b"TxPause".to_vec()),
+                Error::<Test>::CannotPause
+            );
+            assert_noop!(
+                TxPause::pause_call(admin(), b"TxPause".to_vec(), b"resume_pallet".to_vec()),
+                Error::<Test>::CannotPause
+            );
+        })
+}
+
+#[test]
+fn pause_pallet_fails_for_long_name() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_noop!(
+                TxPause::pause_pallet(admin(), vec![b'p'; 33]),
+                Error::<Test>::NameTooLong
+            );
+        })
+}
+
+// pause_call / resume_call
+
+#[test]
+fn pause_call_called_by_non_admin_fails() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_noop!(
+                TxPause::pause_call(RuntimeOrigin::signed(ACCOUNT_A), b"Balances".to_vec(), b"transfer".to_vec()),
+                BadOrigin
+            );
+        })
+}
+
+#[test]
+fn pause_call_filters_only_that_call() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_ok!(TxPause::pause_call(admin(), b"Balances".to_vec(), b"transfer".to_vec()));
+            assert!(TxPause::is_paused(b"Balances", b"transfer"));
+            assert!(!TxPause::is_paused(b"Balances", b"transfer_keep_alive"));
+            assert_noop!(
+                transfer_call().dispatch(RuntimeOrigin::signed(ACCOUNT_A)),
+                DispatchError::from(frame_system::Error::<Test>::CallFiltered)
+            );
+            let keep_alive = RuntimeCall::Balances(
+                pallet_balances::Call::transfer_keep_alive { dest: ACCOUNT_B, value: 10 }
+            );
+            assert_ok!(keep_alive.dispatch(RuntimeOrigin::signed(ACCOUNT_A)));
+            assert_eq!(
+                tx_pause_events(),
+                vec![Event::CallPaused(b"Balances".to_vec(), b"transfer".to_vec())]
+            );
+        })
+}
+
+#[test]
+fn resume_call_called_by_pause_origin_fails() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_ok!(TxPause::pause_call(admin(), b"Balances".to_vec(), b"transfer".to_vec()));
+            assert_noop!(
+                TxPause::resume_call(admin(), b"Balances".to_vec(), b"transfer".to_vec()),
+                BadOrigin
+            );
+            assert_ok!(TxPause::resume_call(resumer(), b"Balances".to_vec(), b"transfer".to_vec()));
+            assert!(!TxPause::is_paused(b"Balances", b"transfer"));
+        })
+}
+
+#[test]
+fn pause_call_fails_for_long_name() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_noop!(
+                TxPause::pause_call(admin(), b"Balances".to_vec(), vec![b'c'; 33]),
+                Error::<Test>::NameTooLong
+            );
+        })
+}
+
+#[test]
+fn resume_call_keeps_paused_pallet() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_ok!(TxPause::pause_call(admin(), b"Balances".to_vec(), b"transfer".to_vec()));
+            assert_ok!(TxPause::pause_pallet(admin(), b"Balances".to_vec()));
+            assert_ok!(TxPause::resume_call(resumer(), b"Balances".to_vec(), b"transfer".to_vec()));
+            assert!(PausedFilter::<Test>::contains(&transfer_call()));
+            assert_noop!(
+                TxPause::resume_call(resumer(), b"Balances".to_vec(), b"transfer".to_vec()),
+                Error::<Test>::NotPaused
+            );
+        })
+}
+
+// genesis
+
+#[test]
+fn genesis_pauses_pallets() {
+    ExtBuilder::default()
+        .with_paused_pallet(b"Balances")
+        .build_and_execute(|| {
+            assert!(TxPause::pallet_paused(b"Balances".to_vec()));
+            assert!(PausedFilter::<Test>::contains(&transfer_call()));
+            // Governance can still resume the pallet
+            assert_ok!(TxPause::resume_pallet(resumer(), b"Balances".to_vec()));
+            assert!(!PausedFilter::<Test>::contains(&transfer_call()));
+        })
+}
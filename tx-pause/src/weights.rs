@@ -0,0 +1,112 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for tx_pause
+//!
+//! These weights were not generated by the benchmark CLI. Storage accesses are counted by hand
+//! from the benchmarks in `benchmarking.rs`. Execution time is derived from the generated weight
+//! of `swap::set_fee_destination`, 19.7 µs for a single write on 2021-09-13, with 1.5 µs added for the
+//! read, as done for the swap and chainbridge weights.
+//!
+//! They should be replaced by the output of the command below once the benchmarks are run.
+
+// Command:
+// target/release/xxnetwork-chain
+// benchmark
+// --chain=xxnetwork-dev
+// --steps=50
+// --repeat=20
+// --pallet=tx-pause
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./tx-pause/src/weights.rs
+// --template=./scripts/frame-weight-template.hbs
+
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for tx_pause.
+pub trait WeightInfo {
+	fn pause_pallet() -> Weight;
+	fn resume_pallet() -> Weight;
+	fn pause_call() -> Weight;
+	fn resume_call() -> Weight;
+}
+
+/// Weights for tx_pause using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: TxPause PausedPallets (r:1 w:1)
+	fn pause_pallet() -> Weight {
+		Weight::from_ref_time(21_158_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: TxPause PausedPallets (r:1 w:1)
+	fn resume_pallet() -> Weight {
+		Weight::from_ref_time(21_158_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: TxPause PausedCalls (r:1 w:1)
+	fn pause_call() -> Weight {
+		Weight::from_ref_time(21_158_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: TxPause PausedCalls (r:1 w:1)
+	fn resume_call() -> Weight {
+		Weight::from_ref_time(21_158_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: TxPause PausedPallets (r:1 w:1)
+	fn pause_pallet() -> Weight {
+		Weight::from_ref_time(21_158_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: TxPause PausedPallets (r:1 w:1)
+	fn resume_pallet() -> Weight {
+		Weight::from_ref_time(21_158_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: TxPause PausedCalls (r:1 w:1)
+	fn pause_call() -> Weight {
+		Weight::from_ref_time(21_158_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: TxPause PausedCalls (r:1 w:1)
+	fn resume_call() -> Weight {
+		Weight::from_ref_time(21_158_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+}
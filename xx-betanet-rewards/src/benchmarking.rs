@@ -1,18 +1,94 @@
 use super::*;
 
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, account as benchmark_account};
 use frame_system::RawOrigin;
 
-fn account<T: Config>() -> T::AccountId { <Accounts<T>>::iter().next().expect("No accounts set in genesis config").0 }
+const SEED: u32 = 0;
+const MAX_ACCOUNTS: u32 = 1000;
+
+fn account<T: Config>() -> T::AccountId {
+    <Accounts<T>>::iter_prefix(BETANET_PROGRAM).next().expect("No accounts set in genesis config").0
+}
+
+fn program_options<T: Config>(o: u32) -> Vec<RewardOption<T::BlockNumber>> {
+    (0 .. o).map(|i| RewardOption {
+        vesting_period: (i * 1000).into(),
+        principal_lock: Perbill::from_percent(i),
+        rewards: Perbill::from_percent(i),
+        extra_rewards: Perbill::from_percent(i),
+    }).collect()
+}
+
+fn start_enactment<T: Config>(program: ProgramId, phase: EnactmentPhase) {
+    let mut info = Programs::<T>::get(BETANET_PROGRAM).expect("No program set in genesis config");
+    info.status = ProgramStatus::Enacting;
+    Programs::<T>::insert(program, info);
+    EnactmentProgress::insert(program, EnactmentState { phase, accounts: 0, claims: 0 });
+    EnactmentQueue::put(vec![program]);
+}
+
+fn ethereum_address(i: u32) -> claims::EthereumAddress {
+    let mut address = claims::EthereumAddress::default();
+    address.0[..4].copy_from_slice(&i.to_le_bytes());
+    address
+}
 
 benchmarks!{
     select_option {
         let account = account::<T>();
-    }: _(RawOrigin::Signed(account), RewardOption::Vesting9Month)
+    }: _(RawOrigin::Signed(account), BETANET_PROGRAM, 4)
 
     approve {
+        let mut program = Programs::<T>::get(BETANET_PROGRAM).expect("No program set in genesis config");
+        program.status = ProgramStatus::Proposed;
+        Programs::<T>::insert(BETANET_PROGRAM, program);
+    }: _(RawOrigin::Root, BETANET_PROGRAM)
+
+    create_program {
+        let o in 1 .. T::MaxOptions::get();
+        let options = program_options::<T>(o);
+    }: _(RawOrigin::Root, options, 0, 10u32.into(), 20u32.into(), 30u32.into())
+    verify {
+        assert!(Programs::<T>::contains_key(NextProgramId::get() - 1));
+    }
+
+    add_accounts {
+        let n in 1 .. MAX_ACCOUNTS;
+        let accounts = (0 .. n).map(|i| {
+            (benchmark_account("x", i, SEED), 100u32.into(), 10u32.into())
+        }).collect::<Vec<(T::AccountId, BalanceOf<T>, BalanceOf<T>)>>();
+    }: _(RawOrigin::Root, BETANET_PROGRAM, accounts)
+
+    enact_accounts {
+        let n in 1 .. T::EnactmentBatchSize::get();
+        let program = NextProgramId::get();
+        (0 .. n).for_each(|i| {
+            <Accounts<T>>::insert(program, benchmark_account::<T::AccountId>("x", i, SEED), UserInfo {
+                principal: 100u32.into(),
+                reward: 10u32.into(),
+                option: BETANET_DEFAULT_OPTION,
+            });
+        });
+        start_enactment::<T>(program, EnactmentPhase::Accounts);
+    }: { Module::<T>::process_enactments() }
+    verify {
+        assert_eq!(<Accounts<T>>::iter_prefix(program).count(), 0);
+    }
 
-    }: _(RawOrigin::Root)
+    enact_claims {
+        let n in 1 .. T::EnactmentBatchSize::get();
+        let program = T::ClaimsProgram::get();
+        let _ = <Accounts<T>>::clear_prefix(program, u32::MAX, None);
+        (0 .. n).for_each(|i| {
+            let address = ethereum_address(i);
+            <claims::Claims<T>>::insert(&address, <BalanceOf<T>>::from(100u32));
+            <claims::Rewards<T>>::insert(&address, <BalanceOf<T>>::from(10u32));
+        });
+        start_enactment::<T>(program, EnactmentPhase::Claims);
+    }: { Module::<T>::process_enactments() }
+    verify {
+        assert_eq!(<claims::Rewards<T>>::iter().count(), 0);
+    }
 }
 
 impl_benchmark_test_suite!(
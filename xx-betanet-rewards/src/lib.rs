@@ -13,7 +13,7 @@ pub mod weights;
 use frame_support::traits::{Currency, Get, OnUnbalanced, VestingSchedule};
 use frame_support::{
     decl_event, decl_error, decl_module, decl_storage, ensure, weights::Weight,
-    StorageValue, StorageMap, IterableStorageMap,
+    StorageValue, StorageMap, StorageDoubleMap, IterableStorageDoubleMap, IterableStorageMap,
 };
 use sp_runtime::{PerThing, Perbill, RuntimeDebug, traits::{Saturating, Zero, SaturatedConversion}};
 use frame_system::{ensure_root, ensure_signed};
@@ -27,71 +27,167 @@ pub use weights::WeightInfo;
 
 type PositiveImbalanceOf<T> = <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::PositiveImbalance;
 
+/// Identifier of a rewards program
+pub type ProgramId = u32;
+
+/// Identifier of a reward option, which is its index in the program options table
+pub type OptionId = u8;
+
+/// Program of the BetaNet Staking Rewards
+pub const BETANET_PROGRAM: ProgramId = 0;
+
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
-pub enum RewardOption {
-    /// No Vesting, pays 2% rewards
-    NoVesting,
-    /// 1 month vest for 100% principal, pays 12% rewards
-    Vesting1Month,
-    /// 3 month vest for 90% principal, pays 45% rewards
-    Vesting3Month,
-    /// 6 month vest for 80% principal, pays 100% rewards (Default)
-    Vesting6Month,
-    /// 9 month vest for 100% principal, pays 120% rewards
-    Vesting9Month,
+pub struct RewardOption<BlockNumber> {
+    /// Vesting period in blocks, zero means no vesting
+    pub vesting_period: BlockNumber,
+    /// Percentage of principal that is locked
+    pub principal_lock: Perbill,
+    /// Percentage of rewards that are paid
+    pub rewards: Perbill,
+    /// Extra percentage of rewards that are paid
+    pub extra_rewards: Perbill,
 }
 
-impl Default for RewardOption {
-    fn default() -> Self {
-        RewardOption::Vesting6Month
+impl<BlockNumber> RewardOption<BlockNumber> {
+    /// Compute the reward amount paid for the given reward
+    pub fn reward_amount<Balance>(&self, reward: Balance) -> Balance
+    where Balance: Clone + sp_runtime::traits::AtLeast32BitUnsigned {
+        if self.extra_rewards.is_zero() {
+            self.rewards * reward
+        } else {
+            (self.rewards * reward.clone()) + (self.extra_rewards * reward)
+        }
     }
 }
 
-impl RewardOption {
-    /// Return the vesting period of the given reward option
-    /// Assumes 6s blocks
-    pub fn vesting_period(&self) -> u32 {
-        match self {
-            RewardOption::NoVesting => 0,
-            RewardOption::Vesting1Month => 432000,
-            RewardOption::Vesting3Month => 1296000,
-            RewardOption::Vesting6Month => 2592000,
-            RewardOption::Vesting9Month => 3888000,
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProgramStatus {
+    /// Program is defined, but not approved yet
+    Proposed,
+    /// Program is approved and will be enacted at the enactment block
+    Approved,
+    /// Program has been enacted
+    Enacted,
+    /// Program enactment block was reached and rewards are being paid out
+    Enacting,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+pub enum EnactmentPhase {
+    /// Paying out rewards of program accounts
+    Accounts,
+    /// Adding rewards to leftover claims, only for the claims program
+    Claims,
+}
+
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+pub struct EnactmentState {
+    /// Current phase of the enactment
+    pub phase: EnactmentPhase,
+    /// Number of accounts processed so far
+    pub accounts: u32,
+    /// Number of leftover claims processed so far
+    pub claims: u32,
+}
+
+impl Default for EnactmentState {
+    fn default() -> Self {
+        EnactmentState {
+            phase: EnactmentPhase::Accounts,
+            accounts: 0,
+            claims: 0,
         }
     }
+}
 
-    /// Return the percentage of principal that is locked
-    pub fn principal_lock(&self) -> Perbill {
-        match self {
-            RewardOption::NoVesting => PerThing::zero(),
-            RewardOption::Vesting1Month => Perbill::from_rational(1u32, 1u32),
-            RewardOption::Vesting3Month => Perbill::from_rational(9u32, 10u32),
-            RewardOption::Vesting6Month => Perbill::from_rational(8u32, 10u32),
-            RewardOption::Vesting9Month => Perbill::from_rational(1u32, 1u32),
-        }
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProgramInfo<BlockNumber> {
+    /// Reward options table
+    pub options: Vec<RewardOption<BlockNumber>>,
+    /// Option applied to accounts that don't select one
+    pub default_option: OptionId,
+    /// First block in which options can be selected
+    pub enrollment_start: BlockNumber,
+    /// Block from which options can no longer be selected
+    pub enrollment_end: BlockNumber,
+    /// Block in which the rewards are paid out, if approved
+    pub enactment_block: BlockNumber,
+    /// Status of the program
+    pub status: ProgramStatus,
+}
+
+impl<BlockNumber> ProgramInfo<BlockNumber> {
+    /// Get the given reward option
+    pub fn option(&self, option: OptionId) -> Option<&RewardOption<BlockNumber>> {
+        self.options.get(option as usize)
     }
 
-    /// Compute the percentage of rewards that are paid
-    pub fn rewards(&self) -> Perbill {
-        match self {
-            RewardOption::NoVesting => Perbill::from_rational(2u32, 100u32),
-            RewardOption::Vesting1Month => Perbill::from_rational(12u32, 100u32),
-            RewardOption::Vesting3Month => Perbill::from_rational(45u32, 100u32),
-            RewardOption::Vesting6Month => Perbill::from_rational(1u32, 1u32),
-            RewardOption::Vesting9Month => Perbill::from_rational(1u32, 1u32),
-        }
+    /// Get the default reward option
+    pub fn default_reward_option(&self) -> Option<&RewardOption<BlockNumber>> {
+        self.option(self.default_option)
     }
+}
 
-    /// Return the extra percentage rewards that are paid (only for 9 month vest)
-    pub fn extra_rewards(&self) -> Perbill {
-        match self {
-            RewardOption::NoVesting => PerThing::zero(),
-            RewardOption::Vesting1Month => PerThing::zero(),
-            RewardOption::Vesting3Month => PerThing::zero(),
-            RewardOption::Vesting6Month => PerThing::zero(),
-            RewardOption::Vesting9Month => Perbill::from_rational(2u32, 10u32),
-        }
+/// Reward options of the BetaNet Staking Rewards program
+/// Assumes 6s blocks
+pub fn betanet_options<BlockNumber: From<u32>>() -> Vec<RewardOption<BlockNumber>> {
+    vec![
+        // No Vesting, pays 2% rewards
+        RewardOption {
+            vesting_period: 0u32.into(),
+            principal_lock: PerThing::zero(),
+            rewards: Perbill::from_rational(2u32, 100u32),
+            extra_rewards: PerThing::zero(),
+        },
+        // 1 month vest for 100% principal, pays 12% rewards
+        RewardOption {
+            vesting_period: 432000u32.into(),
+            principal_lock: Perbill::from_rational(1u32, 1u32),
+            rewards: Perbill::from_rational(12u32, 100u32),
+            extra_rewards: PerThing::zero(),
+        },
+        // 3 month vest for 90% principal, pays 45% rewards
+        RewardOption {
+            vesting_period: 1296000u32.into(),
+            principal_lock: Perbill::from_rational(9u32, 10u32),
+            rewards: Perbill::from_rational(45u32, 100u32),
+            extra_rewards: PerThing::zero(),
+        },
+        // 6 month vest for 80% principal, pays 100% rewards (Default)
+        RewardOption {
+            vesting_period: 2592000u32.into(),
+            principal_lock: Perbill::from_rational(8u32, 10u32),
+            rewards: Perbill::from_rational(1u32, 1u32),
+            extra_rewards: PerThing::zero(),
+        },
+        // 9 month vest for 100% principal, pays 120% rewards
+        RewardOption {
+            vesting_period: 3888000u32.into(),
+            principal_lock: Perbill::from_rational(1u32, 1u32),
+            rewards: Perbill::from_rational(1u32, 1u32),
+            extra_rewards: Perbill::from_rational(2u32, 10u32),
+        },
+    ]
+}
+
+/// Default option of the BetaNet Staking Rewards program (6 month vest)
+pub const BETANET_DEFAULT_OPTION: OptionId = 3;
+
+/// BetaNet Staking Rewards program, with enrollment open until the enactment block
+pub fn betanet_program<BlockNumber: From<u32> + Clone>(
+    enactment_block: BlockNumber,
+    status: ProgramStatus,
+) -> ProgramInfo<BlockNumber> {
+    ProgramInfo {
+        options: betanet_options(),
+        default_option: BETANET_DEFAULT_OPTION,
+        enrollment_start: 0u32.into(),
+        enrollment_end: enactment_block.clone(),
+        enactment_block,
+        status,
     }
 }
 
@@ -103,28 +199,24 @@ pub struct UserInfo<Balance> {
     /// Reward amount for this user
     pub reward: Balance,
     /// Option selected by the user
-    pub option: RewardOption,
-}
-
-impl<Balance: Zero> Default for UserInfo<Balance> {
-    fn default() -> Self {
-        UserInfo {
-            principal: Zero::zero(),
-            reward: Zero::zero(),
-            option: Default::default(),
-        }
-    }
+    pub option: OptionId,
 }
 
-pub trait Config: frame_system::Config + claims::Config {
+pub trait Config: frame_system::Config + claims::Config + pallet_vesting::Config {
 
     /// The Event type.
     type RuntimeEvent: From<Event<Self>> + Into<<Self as frame_system::Config>::RuntimeEvent>;
 
-    /// The enactment block to payout betanet staking rewards if approved
-    type EnactmentBlock: Get<Self::BlockNumber>;
+    /// The program that accounts with claimed rewards are added to
+    type ClaimsProgram: Get<ProgramId>;
 
-    /// The reward handler for paying out betanet rewards
+    /// The maximum number of reward options of a program
+    type MaxOptions: Get<u32>;
+
+    /// The maximum number of accounts and leftover claims processed per block during enactment
+    type EnactmentBatchSize: Get<u32>;
+
+    /// The reward handler for paying out program rewards
     type Reward: OnUnbalanced<PositiveImbalanceOf<Self>>;
 
     /// Weight information for extrinsics in this pallet.
@@ -134,12 +226,41 @@ pub trait Config: frame_system::Config + claims::Config {
 decl_storage! {
     trait Store for Module<T: Config> as XXBetanetRewards {
 
-        /// Store user info
-        pub Accounts get(fn accounts) config(): map hasher(twox_64_concat)
-            T::AccountId => UserInfo<BalanceOf<T>>;
+        /// Rewards programs
+        pub Programs get(fn programs) config(): map hasher(twox_64_concat)
+            ProgramId => Option<ProgramInfo<T::BlockNumber>>;
+
+        /// ID of the next rewards program
+        pub NextProgramId get(fn next_program_id) config(): ProgramId;
+
+        /// Store user info of each program
+        pub Accounts get(fn accounts): double_map hasher(twox_64_concat) ProgramId,
+            hasher(twox_64_concat) T::AccountId => Option<UserInfo<BalanceOf<T>>>;
+
+        /// Programs to be enacted at each block
+        pub Enactments get(fn enactments): map hasher(twox_64_concat)
+            T::BlockNumber => Vec<ProgramId>;
+
+        /// Programs being enacted, in order, the first one is in progress
+        pub EnactmentQueue get(fn enactment_queue): Vec<ProgramId>;
 
-        /// Store if the rewards program is approved
-        pub Approved get (fn approved): bool;
+        /// Enactment progress of each program being enacted
+        pub EnactmentProgress get(fn enactment_progress): map hasher(twox_64_concat)
+            ProgramId => Option<EnactmentState>;
+    }
+    add_extra_genesis {
+        config(accounts): Vec<(ProgramId, T::AccountId, UserInfo<BalanceOf<T>>)>;
+        build(|config| {
+            // Schedule enactment of approved programs
+            config.programs.iter().for_each(|(id, program)| {
+                if program.status == ProgramStatus::Approved {
+                    <Enactments<T>>::append(program.enactment_block, id);
+                }
+            });
+            config.accounts.iter().for_each(|(id, account, info)| {
+                <Accounts<T>>::insert(id, account, info);
+            });
+        });
     }
 }
 
@@ -147,12 +268,18 @@ decl_event! {
     pub enum Event<T> where
         AccountId = <T as frame_system::Config>::AccountId
     {
+        /// Rewards program has been created
+        ProgramCreated(ProgramId),
+        /// Accounts have been added to a rewards program
+        AccountsAdded(ProgramId, u32),
         /// Reward option has been selected
-        OptionSelected(AccountId, RewardOption),
-        /// BetaNet Staking Program has been approved
-        ProgramApproved,
-        /// BetaNet Staking Program has been enacted
-        ProgramEnacted,
+        OptionSelected(ProgramId, AccountId, OptionId),
+        /// Rewards program has been approved
+        ProgramApproved(ProgramId),
+        /// Rewards program enactment is in progress \[program, accounts, claims\]
+        EnactmentProgress(ProgramId, u32, u32),
+        /// Rewards program has been enacted
+        ProgramEnacted(ProgramId),
     }
 }
 
@@ -162,6 +289,18 @@ decl_error! {
         NoRewards,
         /// Enactment block has passed
         EnactmentBlockHasPassed,
+        /// Rewards program doesn't exist
+        UnknownProgram,
+        /// Reward option doesn't exist in the program
+        InvalidOption,
+        /// Program must have between one and `MaxOptions` options
+        InvalidOptionsTable,
+        /// Enrollment window must end before the enactment block, which must be in the future
+        InvalidSchedule,
+        /// Options can't be selected outside the enrollment window
+        EnrollmentClosed,
+        /// Program was already approved
+        AlreadyApproved,
 	}
 }
 
@@ -171,95 +310,267 @@ decl_module! {
 
 	    fn deposit_event() = default;
 
-	    const EnactmentBlock: T::BlockNumber = T::EnactmentBlock::get();
+	    const ClaimsProgram: ProgramId = T::ClaimsProgram::get();
+
+	    const MaxOptions: u32 = T::MaxOptions::get();
 
-	    /// Select desired option for BetaNet Staking Rewards
+	    const EnactmentBatchSize: u32 = T::EnactmentBatchSize::get();
+
+	    /// Select desired option for a rewards program
         ///
-        /// Only callable by accounts that have rewards and no vesting period
+        /// Only callable by accounts that have rewards in the program,
+        /// during the enrollment window
         ///
         #[weight = <T as Config>::WeightInfo::select_option()]
-        pub fn select_option(origin, option: RewardOption) {
+        pub fn select_option(origin, program: ProgramId, option: OptionId) {
             let who = ensure_signed(origin)?;
-            ensure!(<Accounts<T>>::contains_key(&who), Error::<T>::NoRewards);
+            let info = Self::programs(program).ok_or(Error::<T>::UnknownProgram)?;
+            ensure!(<Accounts<T>>::contains_key(program, &who), Error::<T>::NoRewards);
+            let block = <frame_system::Pallet<T>>::block_number();
+            ensure!(block < info.enactment_block, Error::<T>::EnactmentBlockHasPassed);
+            ensure!(
+                block >= info.enrollment_start && block < info.enrollment_end,
+                Error::<T>::EnrollmentClosed
+            );
+            ensure!(info.option(option).is_some(), Error::<T>::InvalidOption);
+            <Accounts<T>>::mutate(program, &who, |maybe_user| {
+                if let Some(user) = maybe_user {
+                    user.option = option;
+                }
+            });
+            Self::deposit_event(RawEvent::OptionSelected(program, who, option))
+        }
+
+        /// Create a rewards program
+        ///
+        /// The dispatch origin must be Root.
+        ///
+        #[weight = <T as Config>::WeightInfo::create_program(options.len() as u32)]
+        pub fn create_program(origin,
+            options: Vec<RewardOption<T::BlockNumber>>,
+            default_option: OptionId,
+            enrollment_start: T::BlockNumber,
+            enrollment_end: T::BlockNumber,
+            enactment_block: T::BlockNumber,
+        ) {
+            ensure_root(origin)?;
+            ensure!(
+                !options.is_empty() && options.len() as u32 <= T::MaxOptions::get(),
+                Error::<T>::InvalidOptionsTable
+            );
+            ensure!((default_option as usize) < options.len(), Error::<T>::InvalidOption);
             let block = <frame_system::Pallet<T>>::block_number();
-            ensure!(block < T::EnactmentBlock::get(), Error::<T>::EnactmentBlockHasPassed);
-            <Accounts<T>>::mutate(&who, |info| {
-                let curr_opt = &mut info.option;
-                *curr_opt = option.clone();
+            ensure!(
+                enrollment_start <= enrollment_end
+                    && enrollment_end <= enactment_block
+                    && block < enactment_block,
+                Error::<T>::InvalidSchedule
+            );
+            let id = NextProgramId::get();
+            <Programs<T>>::insert(id, ProgramInfo {
+                options,
+                default_option,
+                enrollment_start,
+                enrollment_end,
+                enactment_block,
+                status: ProgramStatus::Proposed,
             });
-            Self::deposit_event(RawEvent::OptionSelected(who, option))
+            NextProgramId::put(id.saturating_add(1));
+            Self::deposit_event(RawEvent::ProgramCreated(id))
         }
 
-        /// Approve the BetaNet Staking Rewards program
+        /// Add accounts to a rewards program, with the default option
+        ///
+        /// Existing accounts are replaced.
+        /// The dispatch origin must be Root.
+        ///
+        #[weight = <T as Config>::WeightInfo::add_accounts(accounts.len() as u32)]
+        pub fn add_accounts(origin,
+            program: ProgramId,
+            accounts: Vec<(T::AccountId, BalanceOf<T>, BalanceOf<T>)>,
+        ) {
+            ensure_root(origin)?;
+            let info = Self::programs(program).ok_or(Error::<T>::UnknownProgram)?;
+            let block = <frame_system::Pallet<T>>::block_number();
+            ensure!(block < info.enactment_block, Error::<T>::EnactmentBlockHasPassed);
+            let count = accounts.len() as u32;
+            accounts.into_iter().for_each(|(account, principal, reward)| {
+                <Accounts<T>>::insert(program, account, UserInfo {
+                    principal,
+                    reward,
+                    option: info.default_option,
+                });
+            });
+            Self::deposit_event(RawEvent::AccountsAdded(program, count))
+        }
+
+        /// Approve a rewards program
         ///
         /// The dispatch origin must be Root.
         ///
         #[weight = <T as Config>::WeightInfo::approve()]
-        pub fn approve(origin) {
+        pub fn approve(origin, program: ProgramId) {
             ensure_root(origin)?;
+            let mut info = Self::programs(program).ok_or(Error::<T>::UnknownProgram)?;
             let block = <frame_system::Pallet<T>>::block_number();
-            ensure!(block < T::EnactmentBlock::get(), Error::<T>::EnactmentBlockHasPassed);
-            <Approved>::put(true);
-            Self::deposit_event(RawEvent::ProgramApproved)
+            ensure!(block < info.enactment_block, Error::<T>::EnactmentBlockHasPassed);
+            ensure!(info.status == ProgramStatus::Proposed, Error::<T>::AlreadyApproved);
+            info.status = ProgramStatus::Approved;
+            <Enactments<T>>::append(info.enactment_block, program);
+            <Programs<T>>::insert(program, info);
+            Self::deposit_event(RawEvent::ProgramApproved(program))
         }
 
-        /// When enactment block is reached
+        /// Start enactment of approved programs when their enactment block is reached,
+        /// and continue the enactment of queued programs
         fn on_initialize(n: T::BlockNumber) -> Weight {
-            if n == T::EnactmentBlock::get() {
-                let approved = Approved::get();
-                if approved {
-                    Self::enact_program();
-                    Self::deposit_event(RawEvent::ProgramEnacted);
-                    T::BlockWeights::get().max_block
-                } else {
-                    Weight::zero()
-                }
-            } else {
-                Weight::zero()
+            let mut weight = T::DbWeight::get().reads(2);
+            let programs = <Enactments<T>>::take(n);
+            if !programs.is_empty() {
+                weight = weight.saturating_add(Self::start_enactments(programs));
+            }
+            if EnactmentQueue::get().is_empty() {
+                return weight
             }
+            weight.saturating_add(Self::process_enactments())
         }
     }
 }
 
 /// Implement reward handler
 impl<T: Config> claims::RewardHandler<T::AccountId, BalanceOf<T>> for Module<T> {
-    /// Add a claimed account to rewards
+    /// Add a claimed account to the rewards of the claims program
     fn add_claimed(dest: T::AccountId, claim: BalanceOf<T>, reward: BalanceOf<T>) {
+        let program = T::ClaimsProgram::get();
+        let info = match Self::programs(program) {
+            Some(info) => info,
+            None => return,
+        };
         let block = <frame_system::Pallet<T>>::block_number();
-        if block >= T::EnactmentBlock::get() {
-            return
-        }
-        let info = UserInfo {
+        let user = UserInfo {
             principal: claim,
             reward,
-            option: Default::default(),
+            option: info.default_option,
         };
-        <Accounts<T>>::insert(&dest, info);
+        if block >= info.enactment_block {
+            // Leftover claim could still be pending in the enactment, so pay out directly
+            if info.status == ProgramStatus::Enacting {
+                Self::process_account(&info, dest, user);
+            }
+            return
+        }
+        <Accounts<T>>::insert(program, &dest, user);
     }
 }
 
 impl<T: Config> Module<T> {
-    /// Enact program if approved
-    fn enact_program() {
-        // 1. Process rewards
-        Self::process_rewards();
-        // 2. Process leftover claims
-        Self::process_claims()
+    /// Queue programs for enactment
+    fn start_enactments(programs: Vec<ProgramId>) -> Weight {
+        let count = programs.len() as u64;
+        programs.into_iter().for_each(|program| {
+            <Programs<T>>::mutate(program, |maybe_info| {
+                if let Some(info) = maybe_info {
+                    // 1. Update program status
+                    info.status = ProgramStatus::Enacting;
+                    // 2. Start enactment progress
+                    EnactmentProgress::insert(program, EnactmentState::default());
+                    // 3. Add program to the queue
+                    EnactmentQueue::append(program);
+                }
+            });
+        });
+        T::DbWeight::get().reads_writes(count, count.saturating_mul(3).saturating_add(1))
+    }
+
+    /// Process queued enactments, up to `EnactmentBatchSize` accounts and claims
+    fn process_enactments() -> Weight {
+        let mut queue = EnactmentQueue::get();
+        let mut budget = T::EnactmentBatchSize::get();
+        let mut accounts = 0u32;
+        let mut claims = 0u32;
+        let mut weight = T::DbWeight::get().writes(1);
+        while let Some(&program) = queue.first() {
+            weight = weight.saturating_add(T::DbWeight::get().reads(2));
+            let info = match Self::programs(program) {
+                Some(info) => info,
+                None => {
+                    EnactmentProgress::remove(program);
+                    queue.remove(0);
+                    continue
+                }
+            };
+            let mut state = Self::enactment_progress(program).unwrap_or_default();
+            // 1. Process a batch of the current phase
+            let done = match state.phase {
+                EnactmentPhase::Accounts => {
+                    let processed = Self::process_rewards(program, &info, budget);
+                    state.accounts = state.accounts.saturating_add(processed);
+                    accounts = accounts.saturating_add(processed);
+                    budget = budget.saturating_sub(processed);
+                    // Leftover claims are only processed for the claims program
+                    if budget > 0 && program == T::ClaimsProgram::get() {
+                        state.phase = EnactmentPhase::Claims;
+                        false
+                    } else {
+                        budget > 0
+                    }
+                }
+                EnactmentPhase::Claims => {
+                    let processed = Self::process_claims(&info, budget);
+                    state.claims = state.claims.saturating_add(processed);
+                    claims = claims.saturating_add(processed);
+                    budget = budget.saturating_sub(processed);
+                    budget > 0
+                }
+            };
+            // 2. Finish the program, or save progress if budget is exhausted
+            if done {
+                Self::finish_enactment(program, info);
+                queue.remove(0);
+                weight = weight.saturating_add(T::DbWeight::get().writes(2));
+            } else if budget == 0 {
+                EnactmentProgress::insert(program, state.clone());
+                Self::deposit_event(RawEvent::EnactmentProgress(program, state.accounts, state.claims));
+                weight = weight.saturating_add(T::DbWeight::get().writes(1));
+                break
+            } else {
+                EnactmentProgress::insert(program, state);
+            }
+        }
+        EnactmentQueue::put(queue);
+        weight
+            .saturating_add(<T as Config>::WeightInfo::enact_accounts(accounts))
+            .saturating_add(<T as Config>::WeightInfo::enact_claims(claims))
+    }
+
+    /// Finish the enactment of a program
+    fn finish_enactment(program: ProgramId, mut info: ProgramInfo<T::BlockNumber>) {
+        info.status = ProgramStatus::Enacted;
+        <Programs<T>>::insert(program, info);
+        EnactmentProgress::remove(program);
+        Self::deposit_event(RawEvent::ProgramEnacted(program));
+    }
+
+    /// Convert a vesting period into a balance
+    fn period_as_balance(period: T::BlockNumber) -> BalanceOf<T> {
+        <BalanceOf<T>>::try_from(period.saturated_into::<u128>()).ok().unwrap_or(Zero::zero())
     }
 
     /// Process a single account
     fn process_account(
+        program: &ProgramInfo<T::BlockNumber>,
         account: T::AccountId,
         info: UserInfo<BalanceOf<T>>)
     {
-        // 1. Compute reward amount based on option
-        let extra = info.option.extra_rewards();
-        let reward_amount = if extra.is_zero() {
-            info.option.rewards() * info.reward.clone()
-        } else {
-            (info.option.rewards() * info.reward.clone()) + (extra * info.reward)
+        // Fallback to the default option, in case the selected option doesn't exist
+        let option = match program.option(info.option).or(program.default_reward_option()) {
+            Some(option) => option,
+            None => return,
         };
 
+        // 1. Compute reward amount based on option
+        let reward_amount = option.reward_amount(info.reward);
+
         // 2. Payout reward
         // Deposit rewards in account, creating positive imbalance
         let imbalance = <CurrencyOf<T>>::deposit_creating(&account, reward_amount.clone());
@@ -267,35 +578,48 @@ impl<T: Config> Module<T> {
         T::Reward::on_unbalanced(imbalance);
 
         // 3. Add vesting schedule
-        match info.option {
-            RewardOption::NoVesting => (),
-            _ => {
-                // Compute vesting parameters based on option
-                let desired_lock = (info.option.principal_lock() * info.principal) + reward_amount;
-                // If account has any vesting schedule, adjust lock
-                let current_locked = <T as claims::Config>::VestingSchedule::vesting_balance(&account);
-                let lock = if let Some(l) = current_locked {
-                    desired_lock.saturating_sub(l)
-                } else {
-                    desired_lock
-                };
-                if !lock.is_zero() {
-                    let per_block = lock.clone() / info.option.vesting_period().into();
-                    let _ = <T as claims::Config>::VestingSchedule::add_vesting_schedule(
-                        &account,
-                        lock,
-                        per_block,
-                        T::EnactmentBlock::get(),
-                    );
-                }
+        let period = Self::period_as_balance(option.vesting_period);
+        if !period.is_zero() {
+            // Compute vesting parameters based on option
+            let desired_lock = (option.principal_lock * info.principal) + reward_amount;
+            // If account has any vesting schedule, adjust lock
+            let current_locked = Self::vesting_locked_at(&account, program.enactment_block);
+            let lock = if let Some(l) = current_locked {
+                desired_lock.saturating_sub(l)
+            } else {
+                desired_lock
+            };
+            if !lock.is_zero() {
+                let per_block = lock.clone() / period;
+                let _ = <T as claims::Config>::VestingSchedule::add_vesting_schedule(
+                    &account,
+                    lock,
+                    per_block,
+                    program.enactment_block,
+                );
             }
         }
     }
 
+    /// Amount locked in the vesting schedules of an account at the given block
+    /// Enactment can span several blocks, so locks are always computed at the enactment block
+    fn vesting_locked_at(who: &T::AccountId, block: T::BlockNumber) -> Option<BalanceOf<T>> {
+        <pallet_vesting::Pallet<T>>::vesting(who).map(|schedules| {
+            let locked = schedules.iter().fold(0u128, |total, schedule| {
+                schedule.locked_at::<<T as pallet_vesting::Config>::BlockNumberToBalance>(block)
+                    .saturated_into::<u128>()
+                    .saturating_add(total)
+            });
+            <BalanceOf<T>>::try_from(locked).ok().unwrap_or(Zero::zero())
+        })
+    }
+
     /// Compute claims vesting lock based on desired value and existing schedules
-    fn compute_claims_vesting_lock(who: &claims::EthereumAddress, desired: BalanceOf<T>)
-        -> BalanceOf<T> {
-        let block = T::EnactmentBlock::get();
+    fn compute_claims_vesting_lock(
+        who: &claims::EthereumAddress,
+        desired: BalanceOf<T>,
+        block: T::BlockNumber,
+    ) -> BalanceOf<T> {
         let mut lock = desired;
         // Get existing vesting schedules from claims pallet
         if let Some(schedules) = <claims::Vesting<T>>::get(who) {
@@ -313,10 +637,15 @@ impl<T: Config> Module<T> {
 
     /// Process a leftover claim
     fn process_leftover_claim(
+        program: &ProgramInfo<T::BlockNumber>,
         address: claims::EthereumAddress,
         reward: BalanceOf<T>)
     {
         // For leftover claims, the default option is applied
+        let option = match program.default_reward_option() {
+            Some(option) => option,
+            None => return,
+        };
 
         // 1. "Payout" reward into claim
         // Create a pair of imbalances
@@ -334,33 +663,39 @@ impl<T: Config> Module<T> {
         drop(credit);
 
         // 2. Add vesting schedule (if needed)
+        let period = Self::period_as_balance(option.vesting_period);
+        if period.is_zero() {
+            return
+        }
         // Compute vesting parameters
-        let option: RewardOption = Default::default();
         let principal = <claims::Claims<T>>::get(&address).unwrap_or_default();
-        let desired_lock = (option.principal_lock() * principal) + reward;
+        let desired_lock = (option.principal_lock * principal) + reward;
         // If account has any vesting schedule, adjust lock
         let lock = Self::compute_claims_vesting_lock(
             &address,
             desired_lock,
+            program.enactment_block,
         );
 
         if !lock.is_zero() {
-            let per_block = lock.clone() / option.vesting_period().into();
-            <claims::Vesting<T>>::append(&address, (lock, per_block, T::EnactmentBlock::get()));
+            let per_block = lock.clone() / period;
+            <claims::Vesting<T>>::append(&address, (lock, per_block, program.enactment_block));
         }
     }
 
-    /// Process rewards
-    fn process_rewards() {
-        <Accounts<T>>::drain().for_each(|(account, info)| {
-            Self::process_account(account, info)
+    /// Process rewards of up to `limit` accounts, returning the number processed
+    fn process_rewards(program: ProgramId, info: &ProgramInfo<T::BlockNumber>, limit: u32) -> u32 {
+        <Accounts<T>>::drain_prefix(program).take(limit as usize).fold(0, |count, (account, user)| {
+            Self::process_account(info, account, user);
+            count + 1
         })
     }
 
-    /// Process claims
-    fn process_claims() {
-        <claims::Rewards<T>>::drain().for_each(|(address, reward)| {
-            Self::process_leftover_claim(address, reward)
+    /// Process up to `limit` leftover claims, returning the number processed
+    fn process_claims(info: &ProgramInfo<T::BlockNumber>, limit: u32) -> u32 {
+        <claims::Rewards<T>>::drain().take(limit as usize).fold(0, |count, (address, reward)| {
+            Self::process_leftover_claim(info, address, reward);
+            count + 1
         })
     }
 }
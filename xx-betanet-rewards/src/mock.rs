@@ -111,6 +111,8 @@ impl pallet_vesting::Config for Test {
 
 parameter_types!{
     pub Prefix: &'static [u8] = b"Pay RUSTs to the TEST account:";
+    pub ChainName: &'static [u8] = b"TEST";
+    pub const MaxStatementLength: u32 = 1024;
 }
 
 ord_parameter_types!{
@@ -121,8 +123,11 @@ impl claims::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type VestingSchedule = Vesting;
     type Prefix = Prefix;
+    type ChainName = ChainName;
     type MoveClaimOrigin = EnsureSignedBy<Six, AccountId>;
+    type MaxStatementLength = MaxStatementLength;
     type RewardHandler = XXBetanetRewards;
+    type UnclaimedHandler = ();
     type WeightInfo = claims::weights::TestWeightInfo;
 }
 
@@ -176,9 +181,24 @@ impl RewardMock {
     }
 }
 
+parameter_types! {
+	pub const ClaimsProgram: ProgramId = BETANET_PROGRAM;
+	pub const MaxOptions: u32 = 10;
+	pub static EnactmentBatchSize: u32 = 100;
+}
+
+/// Betanet program reward options
+pub const NO_VESTING: OptionId = 0;
+pub const VESTING_1_MONTH: OptionId = 1;
+pub const VESTING_3_MONTH: OptionId = 2;
+pub const VESTING_6_MONTH: OptionId = 3;
+pub const VESTING_9_MONTH: OptionId = 4;
+
 impl xx_betanet_rewards::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type EnactmentBlock = BetanetStakingRewardsBlock;
+    type ClaimsProgram = ClaimsProgram;
+    type MaxOptions = MaxOptions;
+    type EnactmentBatchSize = EnactmentBatchSize;
     type Reward = RewardMock;
     type WeightInfo = ();
 }
@@ -278,6 +298,11 @@ impl ExtBuilder {
         self
     }
 
+    pub fn with_enactment_batch_size(self, size: u32) -> Self {
+        EnactmentBatchSize::set(size);
+        self
+    }
+
     pub fn build(self) -> sp_io::TestExternalities {
         sp_tracing::try_init_simple();
         let mut storage = frame_system::GenesisConfig::default()
@@ -324,42 +349,22 @@ impl ExtBuilder {
                     (eth(&bob()), vec![(ClaimBalance::get(), ClaimVestingPerBlock::get(), 0)]),
                     (eth(&dave()), vec![(ClaimBalance::get(), ClaimVestingPerBlock::get(), 0)]),
                 ],
+                statements: vec![],
             }.assimilate_storage(&mut storage).unwrap();
         }
 
         xx_betanet_rewards::GenesisConfig::<Test> {
-            accounts: vec![
-                (10, UserInfo::<Balance> {
-                    principal: RewardBalance::get(),
-                    reward: RewardAmount::get(),
-                    option: RewardOption::Vesting6Month,
-                }),
-                (20, UserInfo::<Balance> {
-                    principal: RewardBalance::get(),
-                    reward: RewardAmount::get(),
-                    option: RewardOption::Vesting6Month,
-                }),
-                (30, UserInfo::<Balance> {
-                    principal: RewardBalance::get(),
-                    reward: RewardAmount::get(),
-                    option: RewardOption::Vesting6Month,
-                }),
-                (40, UserInfo::<Balance> {
-                    principal: RewardBalance::get(),
-                    reward: RewardAmount::get(),
-                    option: RewardOption::Vesting6Month,
-                }),
-                (50, UserInfo::<Balance> {
-                    principal: RewardBalance::get(),
-                    reward: RewardAmount::get(),
-                    option: RewardOption::Vesting6Month,
-                }),
-                (60, UserInfo::<Balance> {
+            programs: vec![
+                (BETANET_PROGRAM, betanet_program(BetanetStakingRewardsBlock::get(), ProgramStatus::Proposed)),
+            ],
+            next_program_id: 1,
+            accounts: [10, 20, 30, 40, 50, 60].iter().map(|account| {
+                (BETANET_PROGRAM, *account, UserInfo::<Balance> {
                     principal: RewardBalance::get(),
                     reward: RewardAmount::get(),
-                    option: RewardOption::Vesting6Month,
-                }),
-            ],
+                    option: VESTING_6_MONTH,
+                })
+            }).collect(),
         }.assimilate_storage(&mut storage).unwrap();
 
         let ext = sp_io::TestExternalities::from(storage);
@@ -397,12 +402,13 @@ pub(crate) fn xx_betanet_rewards_events() -> Vec<xx_betanet_rewards::Event<Test>
         .collect()
 }
 
-pub(crate) fn confirm_reward_result(who: &AccountId, option: RewardOption, claim: bool) -> Balance {
+pub(crate) fn confirm_reward_result(who: &AccountId, option: OptionId, claim: bool) -> Balance {
+    let option = betanet_options::<BlockNumber>()[option as usize].clone();
     // Compute reward
     let reward = if claim {
-        (option.extra_rewards() * ClaimRewardAmount::get()) + (option.rewards() * ClaimRewardAmount::get())
+        (option.extra_rewards * ClaimRewardAmount::get()) + (option.rewards * ClaimRewardAmount::get())
     } else {
-        (option.extra_rewards() * RewardAmount::get()) + (option.rewards() * RewardAmount::get())
+        (option.extra_rewards * RewardAmount::get()) + (option.rewards * RewardAmount::get())
     };
 
     // Check balance
@@ -417,10 +423,10 @@ pub(crate) fn confirm_reward_result(who: &AccountId, option: RewardOption, claim
     );
 
     // Check the sum of vesting schedules lock at least the required amount
-    match option {
-        RewardOption::NoVesting => (),
+    match option.vesting_period {
+        0 => (),
         _ => {
-            let min_locked = (option.principal_lock() * expected_balance) + reward;
+            let min_locked = (option.principal_lock * expected_balance) + reward;
             let locked = Vesting::vesting(who).unwrap().iter().fold(0u128, |acc, x| {
                 acc + x.locked_at::<ConvertInto>(BetanetStakingRewardsBlock::get())
             });
@@ -434,12 +440,12 @@ pub(crate) fn confirm_reward_result(who: &AccountId, option: RewardOption, claim
 
 pub(crate) fn confirm_claim_rewards_added(who: &AccountId) {
     assert_eq!(
-        <Accounts<Test>>::get(who),
-        UserInfo {
+        <Accounts<Test>>::get(BETANET_PROGRAM, who),
+        Some(UserInfo {
             principal: ClaimBalance::get(),
             reward: ClaimRewardAmount::get(),
-            option: RewardOption::Vesting6Month,
-        }
+            option: BETANET_DEFAULT_OPTION,
+        })
     );
 }
 
@@ -453,8 +459,8 @@ pub(crate) fn confirm_leftover_claim_rewards_added(who: &EthereumAddress, vestin
 
     // Check claim vesting schedule was changed to include reward amount
     if vesting {
-        let option = RewardOption::default();
-        let min_locked = (option.principal_lock() * ClaimBalance::get()) + ClaimRewardAmount::get();
+        let option = betanet_options::<BlockNumber>()[BETANET_DEFAULT_OPTION as usize].clone();
+        let min_locked = (option.principal_lock * ClaimBalance::get()) + ClaimRewardAmount::get();
         let locked = <claims::Vesting<Test>>::get(who).unwrap().iter().fold(0u128, |acc, x| {
             let vs = pallet_vesting::VestingInfo::new(x.0, x.1, x.2);
             acc + vs.locked_at::<ConvertInto>(BetanetStakingRewardsBlock::get())
@@ -10,19 +10,19 @@ fn approval_origin_must_be_root() {
         .build_and_execute(|| {
             // Signed origin fails
             assert_noop!(
-				XXBetanetRewards::approve(RuntimeOrigin::signed(1)),
+				XXBetanetRewards::approve(RuntimeOrigin::signed(1), BETANET_PROGRAM),
 				BadOrigin
 			);
 
             // Root origin works
             assert_ok!(
-				XXBetanetRewards::approve(RuntimeOrigin::root())
+				XXBetanetRewards::approve(RuntimeOrigin::root(), BETANET_PROGRAM)
 			);
 
             // Check events
             assert_eq!(
                 xx_betanet_rewards_events(),
-                vec![RawEvent::ProgramApproved]
+                vec![RawEvent::ProgramApproved(BETANET_PROGRAM)]
             );
         })
 }
@@ -33,19 +33,19 @@ fn set_option_origin_must_be_in_accounts() {
         .build_and_execute(|| {
             // Account 10 can select option
             assert_ok!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(10), RewardOption::NoVesting)
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(10), BETANET_PROGRAM, NO_VESTING)
 			);
 
             // Account 1 can't
             assert_noop!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(1), RewardOption::NoVesting),
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(1), BETANET_PROGRAM, NO_VESTING),
 				Error::<Test>::NoRewards
 			);
 
             // Check events
             assert_eq!(
                 xx_betanet_rewards_events(),
-                vec![RawEvent::OptionSelected(10, RewardOption::NoVesting)]
+                vec![RawEvent::OptionSelected(BETANET_PROGRAM, 10, NO_VESTING)]
             );
         })
 }
@@ -56,13 +56,13 @@ fn nothing_happens_if_program_not_accepted() {
         .build_and_execute(|| {
             // Account 10 can select option
             assert_ok!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(10), RewardOption::Vesting9Month)
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(10), BETANET_PROGRAM, VESTING_9_MONTH)
 			);
 
             // Check events
             assert_eq!(
                 xx_betanet_rewards_events(),
-                vec![RawEvent::OptionSelected(10, RewardOption::Vesting9Month)]
+                vec![RawEvent::OptionSelected(BETANET_PROGRAM, 10, VESTING_9_MONTH)]
             );
 
             // Keep original issuance and claim total
@@ -102,19 +102,19 @@ fn all_functions_are_noop_after_enactment_block() {
 
             // Confirm accounts can't select options anymore
             assert_noop!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(10), RewardOption::NoVesting),
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(10), BETANET_PROGRAM, NO_VESTING),
 				Error::<Test>::EnactmentBlockHasPassed
 			);
 
             // Confirm root can't approve program anymore
             assert_noop!(
-				XXBetanetRewards::approve(RuntimeOrigin::root()),
+				XXBetanetRewards::approve(RuntimeOrigin::root(), BETANET_PROGRAM),
 				Error::<Test>::EnactmentBlockHasPassed
 			);
 
             // Issue a claim that had potential rewards, and confirm account is not added to Accounts map
             assert_ok!(Claims::claim(RuntimeOrigin::none(), 70, sig::<Test>(&alice(), &70u64.encode(), &[][..])));
-            assert_eq!(<Accounts<Test>>::contains_key(&70), false);
+            assert_eq!(<Accounts<Test>>::contains_key(BETANET_PROGRAM, &70), false);
         })
 }
 
@@ -124,34 +124,34 @@ fn rewards_are_correct_for_genesis_accounts_no_vesting() {
         .build_and_execute(|| {
             // Account 10 selects option 1: No Vesting
             assert_ok!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(10), RewardOption::NoVesting)
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(10), BETANET_PROGRAM, NO_VESTING)
 			);
 
             // Account 20 selects option 2: 1 month vest
             assert_ok!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(20), RewardOption::Vesting1Month)
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(20), BETANET_PROGRAM, VESTING_1_MONTH)
 			);
 
             // Account 30 selects option 3: 3 month vest
             assert_ok!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(30), RewardOption::Vesting3Month)
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(30), BETANET_PROGRAM, VESTING_3_MONTH)
 			);
 
             // Account 40 selects option 4: 6 month vest
             assert_ok!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(40), RewardOption::Vesting6Month)
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(40), BETANET_PROGRAM, VESTING_6_MONTH)
 			);
 
             // Account 50 selects option 5: 9 month vest
             assert_ok!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(50), RewardOption::Vesting9Month)
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(50), BETANET_PROGRAM, VESTING_9_MONTH)
 			);
 
             // Account 60 doesn't select option, defaults to 4: 6 month vest
 
             // Approve program
             assert_ok!(
-				XXBetanetRewards::approve(RuntimeOrigin::root())
+				XXBetanetRewards::approve(RuntimeOrigin::root(), BETANET_PROGRAM)
 			);
 
             // Keep original issuance
@@ -164,24 +164,24 @@ fn rewards_are_correct_for_genesis_accounts_no_vesting() {
             assert_eq!(
                 xx_betanet_rewards_events(),
                 vec![
-                    RawEvent::OptionSelected(10, RewardOption::NoVesting),
-                    RawEvent::OptionSelected(20, RewardOption::Vesting1Month),
-                    RawEvent::OptionSelected(30, RewardOption::Vesting3Month),
-                    RawEvent::OptionSelected(40, RewardOption::Vesting6Month),
-                    RawEvent::OptionSelected(50, RewardOption::Vesting9Month),
-                    RawEvent::ProgramApproved,
-                    RawEvent::ProgramEnacted
+                    RawEvent::OptionSelected(BETANET_PROGRAM, 10, NO_VESTING),
+                    RawEvent::OptionSelected(BETANET_PROGRAM, 20, VESTING_1_MONTH),
+                    RawEvent::OptionSelected(BETANET_PROGRAM, 30, VESTING_3_MONTH),
+                    RawEvent::OptionSelected(BETANET_PROGRAM, 40, VESTING_6_MONTH),
+                    RawEvent::OptionSelected(BETANET_PROGRAM, 50, VESTING_9_MONTH),
+                    RawEvent::ProgramApproved(BETANET_PROGRAM),
+                    RawEvent::ProgramEnacted(BETANET_PROGRAM)
                 ]
             );
 
             // Confirm rewards paid out correctly and vesting schedules added
             let mut rewards_paid = 0u128;
-            rewards_paid += confirm_reward_result(&10, RewardOption::NoVesting, false);
-            rewards_paid += confirm_reward_result(&20, RewardOption::Vesting1Month, false);
-            rewards_paid += confirm_reward_result(&30, RewardOption::Vesting3Month, false);
-            rewards_paid += confirm_reward_result(&40, RewardOption::Vesting6Month, false);
-            rewards_paid += confirm_reward_result(&50, RewardOption::Vesting9Month, false);
-            rewards_paid += confirm_reward_result(&60, RewardOption::Vesting6Month, false);
+            rewards_paid += confirm_reward_result(&10, NO_VESTING, false);
+            rewards_paid += confirm_reward_result(&20, VESTING_1_MONTH, false);
+            rewards_paid += confirm_reward_result(&30, VESTING_3_MONTH, false);
+            rewards_paid += confirm_reward_result(&40, VESTING_6_MONTH, false);
+            rewards_paid += confirm_reward_result(&50, VESTING_9_MONTH, false);
+            rewards_paid += confirm_reward_result(&60, VESTING_6_MONTH, false);
 
             // Confirm total rewards were paid from reward pool
             assert_eq!(
@@ -204,34 +204,34 @@ fn rewards_are_correct_for_genesis_accounts_with_vesting() {
         .build_and_execute(|| {
             // Account 10 selects option 1: No Vesting
             assert_ok!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(10), RewardOption::NoVesting)
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(10), BETANET_PROGRAM, NO_VESTING)
 			);
 
             // Account 20 selects option 2: 1 month vest
             assert_ok!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(20), RewardOption::Vesting1Month)
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(20), BETANET_PROGRAM, VESTING_1_MONTH)
 			);
 
             // Account 30 selects option 3: 3 month vest
             assert_ok!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(30), RewardOption::Vesting3Month)
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(30), BETANET_PROGRAM, VESTING_3_MONTH)
 			);
 
             // Account 40 selects option 4: 6 month vest
             assert_ok!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(40), RewardOption::Vesting6Month)
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(40), BETANET_PROGRAM, VESTING_6_MONTH)
 			);
 
             // Account 50 selects option 5: 9 month vest
             assert_ok!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(50), RewardOption::Vesting9Month)
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(50), BETANET_PROGRAM, VESTING_9_MONTH)
 			);
 
             // Account 60 doesn't select option, defaults to 4: 6 month vest
 
             // Approve program
             assert_ok!(
-				XXBetanetRewards::approve(RuntimeOrigin::root())
+				XXBetanetRewards::approve(RuntimeOrigin::root(), BETANET_PROGRAM)
 			);
 
             // Keep original issuance
@@ -244,24 +244,24 @@ fn rewards_are_correct_for_genesis_accounts_with_vesting() {
             assert_eq!(
                 xx_betanet_rewards_events(),
                 vec![
-                    RawEvent::OptionSelected(10, RewardOption::NoVesting),
-                    RawEvent::OptionSelected(20, RewardOption::Vesting1Month),
-                    RawEvent::OptionSelected(30, RewardOption::Vesting3Month),
-                    RawEvent::OptionSelected(40, RewardOption::Vesting6Month),
-                    RawEvent::OptionSelected(50, RewardOption::Vesting9Month),
-                    RawEvent::ProgramApproved,
-                    RawEvent::ProgramEnacted
+                    RawEvent::OptionSelected(BETANET_PROGRAM, 10, NO_VESTING),
+                    RawEvent::OptionSelected(BETANET_PROGRAM, 20, VESTING_1_MONTH),
+                    RawEvent::OptionSelected(BETANET_PROGRAM, 30, VESTING_3_MONTH),
+                    RawEvent::OptionSelected(BETANET_PROGRAM, 40, VESTING_6_MONTH),
+                    RawEvent::OptionSelected(BETANET_PROGRAM, 50, VESTING_9_MONTH),
+                    RawEvent::ProgramApproved(BETANET_PROGRAM),
+                    RawEvent::ProgramEnacted(BETANET_PROGRAM)
                 ]
             );
 
             // Confirm rewards paid out correctly and vesting schedules added
             let mut rewards_paid = 0u128;
-            rewards_paid += confirm_reward_result(&10, RewardOption::NoVesting, false);
-            rewards_paid += confirm_reward_result(&20, RewardOption::Vesting1Month, false);
-            rewards_paid += confirm_reward_result(&30, RewardOption::Vesting3Month, false);
-            rewards_paid += confirm_reward_result(&40, RewardOption::Vesting6Month, false);
-            rewards_paid += confirm_reward_result(&50, RewardOption::Vesting9Month, false);
-            rewards_paid += confirm_reward_result(&60, RewardOption::Vesting6Month, false);
+            rewards_paid += confirm_reward_result(&10, NO_VESTING, false);
+            rewards_paid += confirm_reward_result(&20, VESTING_1_MONTH, false);
+            rewards_paid += confirm_reward_result(&30, VESTING_3_MONTH, false);
+            rewards_paid += confirm_reward_result(&40, VESTING_6_MONTH, false);
+            rewards_paid += confirm_reward_result(&50, VESTING_9_MONTH, false);
+            rewards_paid += confirm_reward_result(&60, VESTING_6_MONTH, false);
 
             // Confirm total rewards were paid from reward pool
             assert_eq!(
@@ -298,22 +298,22 @@ fn rewards_are_correct_for_claims() {
             assert_ok!(Claims::claim(RuntimeOrigin::none(), 90, sig::<Test>(&eve(), &90u64.encode(), &[][..])));
 
             // Confirm no rewards added to betanet rewards pallet
-            assert_eq!(<Accounts<Test>>::contains_key(&90), false);
+            assert_eq!(<Accounts<Test>>::contains_key(BETANET_PROGRAM, &90), false);
 
             // Confirm Alice and Bob can choose options
             assert_ok!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(70), RewardOption::Vesting1Month)
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(70), BETANET_PROGRAM, VESTING_1_MONTH)
 			);
 
             assert_ok!(
-				XXBetanetRewards::select_option(RuntimeOrigin::signed(80), RewardOption::Vesting3Month)
+				XXBetanetRewards::select_option(RuntimeOrigin::signed(80), BETANET_PROGRAM, VESTING_3_MONTH)
 			);
 
             // Accounts 10, 20, 30, 40, 50 and 60 don't select option, defaults to 4: 6 month vest
 
             // Approve program
             assert_ok!(
-				XXBetanetRewards::approve(RuntimeOrigin::root())
+				XXBetanetRewards::approve(RuntimeOrigin::root(), BETANET_PROGRAM)
 			);
 
             // Keep original issuance and claim total
@@ -327,23 +327,23 @@ fn rewards_are_correct_for_claims() {
             assert_eq!(
                 xx_betanet_rewards_events(),
                 vec![
-                    RawEvent::OptionSelected(70, RewardOption::Vesting1Month),
-                    RawEvent::OptionSelected(80, RewardOption::Vesting3Month),
-                    RawEvent::ProgramApproved,
-                    RawEvent::ProgramEnacted
+                    RawEvent::OptionSelected(BETANET_PROGRAM, 70, VESTING_1_MONTH),
+                    RawEvent::OptionSelected(BETANET_PROGRAM, 80, VESTING_3_MONTH),
+                    RawEvent::ProgramApproved(BETANET_PROGRAM),
+                    RawEvent::ProgramEnacted(BETANET_PROGRAM)
                 ]
             );
 
             // Confirm rewards paid out correctly and vesting schedules added
             let mut rewards_paid = 0u128;
-            rewards_paid += confirm_reward_result(&10, RewardOption::Vesting6Month, false);
-            rewards_paid += confirm_reward_result(&20, RewardOption::Vesting6Month, false);
-            rewards_paid += confirm_reward_result(&30, RewardOption::Vesting6Month, false);
-            rewards_paid += confirm_reward_result(&40, RewardOption::Vesting6Month, false);
-            rewards_paid += confirm_reward_result(&50, RewardOption::Vesting6Month, false);
-            rewards_paid += confirm_reward_result(&60, RewardOption::Vesting6Month, false);
-            rewards_paid += confirm_reward_result(&70, RewardOption::Vesting1Month, true);
-            rewards_paid += confirm_reward_result(&80, RewardOption::Vesting3Month, true);
+            rewards_paid += confirm_reward_result(&10, VESTING_6_MONTH, false);
+            rewards_paid += confirm_reward_result(&20, VESTING_6_MONTH, false);
+            rewards_paid += confirm_reward_result(&30, VESTING_6_MONTH, false);
+            rewards_paid += confirm_reward_result(&40, VESTING_6_MONTH, false);
+            rewards_paid += confirm_reward_result(&50, VESTING_6_MONTH, false);
+            rewards_paid += confirm_reward_result(&60, VESTING_6_MONTH, false);
+            rewards_paid += confirm_reward_result(&70, VESTING_1_MONTH, true);
+            rewards_paid += confirm_reward_result(&80, VESTING_3_MONTH, true);
 
             // Confirm leftover claims got rewards
             let mut leftover_rewards_paid = 0u128;
@@ -373,6 +373,349 @@ fn rewards_are_correct_for_claims() {
 
             // Issue one of the leftover claims, and confirm account is not added to Accounts map
             assert_ok!(Claims::claim(RuntimeOrigin::none(), 100, sig::<Test>(&charlie(), &100u64.encode(), &[][..])));
-            assert_eq!(<Accounts<Test>>::contains_key(&100), false);
+            assert_eq!(<Accounts<Test>>::contains_key(BETANET_PROGRAM, &100), false);
+        })
+}
+
+fn custom_options() -> Vec<RewardOption<BlockNumber>> {
+    vec![
+        // No vesting, pays 10% rewards
+        RewardOption {
+            vesting_period: 0,
+            principal_lock: Perbill::zero(),
+            rewards: Perbill::from_percent(10),
+            extra_rewards: Perbill::zero(),
+        },
+        // 100 block vest for 50% principal, pays 50% rewards
+        RewardOption {
+            vesting_period: 100,
+            principal_lock: Perbill::from_percent(50),
+            rewards: Perbill::from_percent(50),
+            extra_rewards: Perbill::zero(),
+        },
+    ]
+}
+
+#[test]
+fn create_program_checks_parameters() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            // Origin must be root
+            assert_noop!(
+                XXBetanetRewards::create_program(RuntimeOrigin::signed(1), custom_options(), 1, 5, 20, 30),
+                BadOrigin
+            );
+            // Options table can't be empty or too big
+            assert_noop!(
+                XXBetanetRewards::create_program(RuntimeOrigin::root(), vec![], 0, 5, 20, 30),
+                Error::<Test>::InvalidOptionsTable
+            );
+            assert_noop!(
+                XXBetanetRewards::create_program(
+                    RuntimeOrigin::root(),
+                    vec![custom_options()[0].clone(); MaxOptions::get() as usize + 1],
+                    0, 5, 20, 30,
+                ),
+                Error::<Test>::InvalidOptionsTable
+            );
+            // Default option must exist
+            assert_noop!(
+                XXBetanetRewards::create_program(RuntimeOrigin::root(), custom_options(), 2, 5, 20, 30),
+                Error::<Test>::InvalidOption
+            );
+            // Enrollment must end before enactment, which must be in the future
+            assert_noop!(
+                XXBetanetRewards::create_program(RuntimeOrigin::root(), custom_options(), 1, 5, 31, 30),
+                Error::<Test>::InvalidSchedule
+            );
+            assert_noop!(
+                XXBetanetRewards::create_program(RuntimeOrigin::root(), custom_options(), 1, 0, 0, 1),
+                Error::<Test>::InvalidSchedule
+            );
+
+            assert_ok!(
+                XXBetanetRewards::create_program(RuntimeOrigin::root(), custom_options(), 1, 5, 20, 30)
+            );
+            assert_eq!(
+                XXBetanetRewards::programs(1),
+                Some(ProgramInfo {
+                    options: custom_options(),
+                    default_option: 1,
+                    enrollment_start: 5,
+                    enrollment_end: 20,
+                    enactment_block: 30,
+                    status: ProgramStatus::Proposed,
+                })
+            );
+            assert_eq!(XXBetanetRewards::next_program_id(), 2);
+            assert_eq!(
+                xx_betanet_rewards_events(),
+                vec![RawEvent::ProgramCreated(1)]
+            );
+        })
+}
+
+#[test]
+fn select_option_only_during_enrollment_window() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_ok!(
+                XXBetanetRewards::create_program(RuntimeOrigin::root(), custom_options(), 1, 5, 20, 30)
+            );
+            assert_ok!(
+                XXBetanetRewards::add_accounts(RuntimeOrigin::root(), 1, vec![(1, 1000, 100)])
+            );
+            assert_eq!(
+                XXBetanetRewards::accounts(1, 1),
+                Some(UserInfo { principal: 1000, reward: 100, option: 1 })
+            );
+
+            // Unknown program
+            assert_noop!(
+                XXBetanetRewards::select_option(RuntimeOrigin::signed(1), 2, 0),
+                Error::<Test>::UnknownProgram
+            );
+            // Account not in program
+            assert_noop!(
+                XXBetanetRewards::select_option(RuntimeOrigin::signed(10), 1, 0),
+                Error::<Test>::NoRewards
+            );
+            // Enrollment not started
+            assert_noop!(
+                XXBetanetRewards::select_option(RuntimeOrigin::signed(1), 1, 0),
+                Error::<Test>::EnrollmentClosed
+            );
+
+            run_to_block(5);
+            // Invalid option
+            assert_noop!(
+                XXBetanetRewards::select_option(RuntimeOrigin::signed(1), 1, 2),
+                Error::<Test>::InvalidOption
+            );
+            assert_ok!(XXBetanetRewards::select_option(RuntimeOrigin::signed(1), 1, 0));
+            assert_eq!(XXBetanetRewards::accounts(1, 1).unwrap().option, 0);
+
+            // Enrollment ended
+            run_to_block(20);
+            assert_noop!(
+                XXBetanetRewards::select_option(RuntimeOrigin::signed(1), 1, 1),
+                Error::<Test>::EnrollmentClosed
+            );
+        })
+}
+
+#[test]
+fn approve_program_only_once() {
+    ExtBuilder::default()
+        .build_and_execute(|| {
+            assert_noop!(
+                XXBetanetRewards::approve(RuntimeOrigin::root(), 1),
+                Error::<Test>::UnknownProgram
+            );
+            assert_ok!(XXBetanetRewards::approve(RuntimeOrigin::root(), BETANET_PROGRAM));
+            assert_noop!(
+                XXBetanetRewards::approve(RuntimeOrigin::root(), BETANET_PROGRAM),
+                Error::<Test>::AlreadyApproved
+            );
+            assert_eq!(
+                XXBetanetRewards::enactments(BetanetStakingRewardsBlock::get()),
+                vec![BETANET_PROGRAM]
+            );
+        })
+}
+
+#[test]
+fn custom_program_is_enacted_independently() {
+    ExtBuilder::default()
+        .with_claims()
+        .build_and_execute(|| {
+            assert_ok!(
+                XXBetanetRewards::create_program(RuntimeOrigin::root(), custom_options(), 1, 1, 15, 20)
+            );
+            assert_ok!(
+                XXBetanetRewards::add_accounts(
+                    RuntimeOrigin::root(),
+                    1,
+                    vec![(10, RewardBalance::get(), RewardAmount::get()), (20, RewardBalance::get(), RewardAmount::get())],
+                )
+            );
+            assert_ok!(XXBetanetRewards::select_option(RuntimeOrigin::signed(10), 1, 0));
+            assert_ok!(XXBetanetRewards::approve(RuntimeOrigin::root(), 1));
+
+            // Keep original claim total
+            let original_claim_total = <claims::Total<Test>>::get();
+
+            // Betanet program is not approved, so nothing happens at its enactment block
+            run_to_block(BetanetStakingRewardsBlock::get());
+            assert_eq!(mock::RewardMock::total(), 0);
+
+            // Custom program enacted at its own enactment block
+            run_to_block(20);
+            assert_eq!(
+                xx_betanet_rewards_events(),
+                vec![
+                    RawEvent::ProgramCreated(1),
+                    RawEvent::AccountsAdded(1, 2),
+                    RawEvent::OptionSelected(1, 10, 0),
+                    RawEvent::ProgramApproved(1),
+                    RawEvent::ProgramEnacted(1),
+                ]
+            );
+            assert_eq!(XXBetanetRewards::programs(1).unwrap().status, ProgramStatus::Enacted);
+
+            // Account 10 gets 10% rewards without vesting
+            let reward_10 = Perbill::from_percent(10) * RewardAmount::get();
+            assert_eq!(Balances::free_balance(&10), RewardBalance::get() + reward_10);
+            assert_eq!(Vesting::vesting(&10), None);
+
+            // Account 20 gets 50% rewards, with 50% principal and rewards vesting from block 20
+            let reward_20 = Perbill::from_percent(50) * RewardAmount::get();
+            assert_eq!(Balances::free_balance(&20), RewardBalance::get() + reward_20);
+            let lock = Perbill::from_percent(50) * RewardBalance::get() + reward_20;
+            assert_eq!(
+                Vesting::vesting(&20).unwrap().to_vec(),
+                vec![pallet_vesting::VestingInfo::new(lock, lock / 100, 20)]
+            );
+
+            // Rewards paid and program accounts removed
+            assert_eq!(mock::RewardMock::total(), reward_10 + reward_20);
+            assert_eq!(<Accounts<Test>>::iter_prefix(1).count(), 0);
+
+            // Betanet program accounts and leftover claims are untouched
+            assert_eq!(<Accounts<Test>>::iter_prefix(BETANET_PROGRAM).count(), 6);
+            assert_eq!(<claims::Total<Test>>::get(), original_claim_total);
+        })
+}
+
+fn enact_betanet_program(batch_size: u32) -> Vec<(AccountId, Balance, Vec<pallet_vesting::VestingInfo<Balance, BlockNumber>>)> {
+    let mut ext = ExtBuilder::default()
+        .with_claims()
+        .with_vesting()
+        .with_enactment_batch_size(batch_size)
+        .build();
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Claims::claim(RuntimeOrigin::none(), 70, sig::<Test>(&alice(), &70u64.encode(), &[][..])));
+        assert_ok!(XXBetanetRewards::select_option(RuntimeOrigin::signed(10), BETANET_PROGRAM, NO_VESTING));
+        assert_ok!(XXBetanetRewards::select_option(RuntimeOrigin::signed(20), BETANET_PROGRAM, VESTING_3_MONTH));
+        assert_ok!(XXBetanetRewards::approve(RuntimeOrigin::root(), BETANET_PROGRAM));
+        run_to_block(BetanetStakingRewardsBlock::get() + 20);
+        assert_eq!(XXBetanetRewards::programs(BETANET_PROGRAM).unwrap().status, ProgramStatus::Enacted);
+        [10, 20, 30, 40, 50, 60, 70].iter().map(|who| {
+            (*who, Balances::free_balance(who), Vesting::vesting(who).map(|v| v.to_vec()).unwrap_or_default())
+        }).collect()
+    })
+}
+
+#[test]
+fn enactment_is_spread_over_several_blocks() {
+    ExtBuilder::default()
+        .with_claims()
+        .with_enactment_batch_size(3)
+        .build_and_execute(|| {
+            // Alice claims coins into account 70
+            assert_ok!(Claims::claim(RuntimeOrigin::none(), 70, sig::<Test>(&alice(), &70u64.encode(), &[][..])));
+
+            // Approve program
+            assert_ok!(
+				XXBetanetRewards::approve(RuntimeOrigin::root(), BETANET_PROGRAM)
+			);
+
+            // Keep original claim total
+            let original_claim_total = <claims::Total<Test>>::get();
+
+            // Go to enactment block, only 3 accounts are processed
+            let block = BetanetStakingRewardsBlock::get();
+            run_to_block(block);
+            assert_eq!(XXBetanetRewards::programs(BETANET_PROGRAM).unwrap().status, ProgramStatus::Enacting);
+            assert_eq!(XXBetanetRewards::enactment_queue(), vec![BETANET_PROGRAM]);
+            assert_eq!(
+                XXBetanetRewards::enactment_progress(BETANET_PROGRAM),
+                Some(EnactmentState { phase: EnactmentPhase::Accounts, accounts: 3, claims: 0 })
+            );
+            assert_eq!(<Accounts<Test>>::iter_prefix(BETANET_PROGRAM).count(), 4);
+
+            // 3 more accounts are processed
+            run_to_block(block + 1);
+            assert_eq!(<Accounts<Test>>::iter_prefix(BETANET_PROGRAM).count(), 1);
+
+            // Last account and first 2 leftover claims are processed
+            run_to_block(block + 2);
+            assert_eq!(
+                XXBetanetRewards::enactment_progress(BETANET_PROGRAM),
+                Some(EnactmentState { phase: EnactmentPhase::Claims, accounts: 7, claims: 2 })
+            );
+
+            // Last leftover claim is processed and program is enacted
+            run_to_block(block + 3);
+            assert_eq!(XXBetanetRewards::programs(BETANET_PROGRAM).unwrap().status, ProgramStatus::Enacted);
+            assert_eq!(XXBetanetRewards::enactment_queue(), vec![]);
+            assert_eq!(XXBetanetRewards::enactment_progress(BETANET_PROGRAM), None);
+
+            // Check events
+            assert_eq!(
+                xx_betanet_rewards_events(),
+                vec![
+                    RawEvent::ProgramApproved(BETANET_PROGRAM),
+                    RawEvent::EnactmentProgress(BETANET_PROGRAM, 3, 0),
+                    RawEvent::EnactmentProgress(BETANET_PROGRAM, 6, 0),
+                    RawEvent::EnactmentProgress(BETANET_PROGRAM, 7, 2),
+                    RawEvent::ProgramEnacted(BETANET_PROGRAM),
+                ]
+            );
+
+            // Confirm rewards paid out correctly
+            let mut rewards_paid = 0u128;
+            rewards_paid += [10, 20, 30, 40, 50, 60].iter().fold(0, |total, who| {
+                total + confirm_reward_result(who, VESTING_6_MONTH, false)
+            });
+            rewards_paid += confirm_reward_result(&70, VESTING_6_MONTH, true);
+            let mut leftover_rewards_paid = 0u128;
+            leftover_rewards_paid += confirm_leftover_claim_rewards_added(&eth(&bob()), true);
+            leftover_rewards_paid += confirm_leftover_claim_rewards_added(&eth(&charlie()), false);
+            leftover_rewards_paid += confirm_leftover_claim_rewards_added(&eth(&dave()), true);
+            assert_eq!(
+                <claims::Total<Test>>::get(),
+                original_claim_total + leftover_rewards_paid,
+            );
+            assert_eq!(
+                mock::RewardMock::total(),
+                rewards_paid + leftover_rewards_paid,
+            );
+        })
+}
+
+#[test]
+fn enactment_in_batches_has_same_result() {
+    assert_eq!(enact_betanet_program(1), enact_betanet_program(100));
+}
+
+#[test]
+fn claim_during_enactment_is_paid_out() {
+    ExtBuilder::default()
+        .with_claims()
+        .with_enactment_batch_size(1)
+        .build_and_execute(|| {
+            // Approve program
+            assert_ok!(
+				XXBetanetRewards::approve(RuntimeOrigin::root(), BETANET_PROGRAM)
+			);
+
+            // Go to enactment block, only 1 account is processed
+            run_to_block(BetanetStakingRewardsBlock::get());
+            assert_eq!(XXBetanetRewards::programs(BETANET_PROGRAM).unwrap().status, ProgramStatus::Enacting);
+
+            // Alice claims coins into account 70 before her leftover claim is processed
+            assert_ok!(Claims::claim(RuntimeOrigin::none(), 70, sig::<Test>(&alice(), &70u64.encode(), &[][..])));
+
+            // Rewards are paid out directly with the default option
+            assert_eq!(<Accounts<Test>>::contains_key(BETANET_PROGRAM, &70), false);
+            let reward = confirm_reward_result(&70, BETANET_DEFAULT_OPTION, true);
+            assert_eq!(mock::RewardMock::total(), RewardAmount::get() + reward);
+
+            // Finish enactment
+            run_to_block(BetanetStakingRewardsBlock::get() + 20);
+            assert_eq!(XXBetanetRewards::programs(BETANET_PROGRAM).unwrap().status, ProgramStatus::Enacted);
+            confirm_reward_result(&70, BETANET_DEFAULT_OPTION, true);
         })
 }
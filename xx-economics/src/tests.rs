@@ -296,6 +296,93 @@ fn reward_remainders_will_split_pool_and_issuance_when_required() {
 		});
 }
 
+// nomination pool reward accounts
+
+// Reward account of a nomination pool, which is kept at the existential deposit by the pool
+const POOL_REWARD_ACCOUNT: AccountId = 100;
+
+#[test]
+fn pool_reward_account_payout_is_taken_from_rewards_pool() {
+	let initial_rewards_balance = 10000;
+	let payout = 1000;
+	ExtBuilder::default()
+		.with_rewards_balance(initial_rewards_balance)
+		.build_and_execute(|| {
+			Balances::make_free_balance_be(&POOL_REWARD_ACCOUNT, ExistentialDeposit::get());
+			let initial_issuance = Balances::total_issuance();
+
+			// Staking pays the pool reward account and hands the imbalance over
+			let imbalance = Balances::deposit_creating(&POOL_REWARD_ACCOUNT, payout);
+			XXEconomics::on_unbalanced(imbalance);
+
+			// the pool can pay out all of it to its members
+			assert_eq!(Balances::free_balance(POOL_REWARD_ACCOUNT) - ExistentialDeposit::get(), payout);
+			// taken from rewards balance, without further issuance
+			assert_eq!(XXEconomics::rewards_balance(), initial_rewards_balance - payout);
+			assert_eq!(Balances::total_issuance(), initial_issuance);
+
+			assert_eq!(
+				xx_economics_events(),
+				vec![
+					RawEvent::RewardFromPool(payout)
+				]
+			)
+		});
+}
+
+#[test]
+fn pool_reward_account_payout_is_minted_when_rewards_pool_depleted() {
+	let initial_rewards_balance = 300;
+	let payout = 1000;
+	ExtBuilder::default()
+		.with_rewards_balance(initial_rewards_balance)
+		.build_and_execute(|| {
+			Balances::make_free_balance_be(&POOL_REWARD_ACCOUNT, ExistentialDeposit::get());
+			let initial_issuance = Balances::total_issuance();
+
+			let imbalance = Balances::deposit_creating(&POOL_REWARD_ACCOUNT, payout);
+			XXEconomics::on_unbalanced(imbalance);
+
+			// the pool gets the full payout either way
+			assert_eq!(Balances::free_balance(POOL_REWARD_ACCOUNT) - ExistentialDeposit::get(), payout);
+			// rewards balance emptied and remainder issued
+			assert_eq!(XXEconomics::rewards_balance(), 0);
+			assert_eq!(Balances::total_issuance(), initial_issuance + (payout - initial_rewards_balance));
+
+			assert_eq!(
+				xx_economics_events(),
+				vec![
+					RawEvent::RewardFromPool(initial_rewards_balance),
+					RawEvent::RewardMinted(payout - initial_rewards_balance)
+				]
+			)
+		});
+}
+
+#[test]
+fn pool_reward_account_gets_payouts_below_existential_deposit() {
+	let initial_rewards_balance = 10000;
+	let payout = 5;
+	ExistentialDeposit::set(10);
+	ExtBuilder::default()
+		.with_rewards_balance(initial_rewards_balance)
+		.build_and_execute(|| {
+			Balances::make_free_balance_be(&POOL_REWARD_ACCOUNT, ExistentialDeposit::get());
+
+			// small payout to the pool reward account, which exists
+			let imbalance = Balances::deposit_creating(&POOL_REWARD_ACCOUNT, payout);
+			XXEconomics::on_unbalanced(imbalance);
+			assert_eq!(Balances::free_balance(POOL_REWARD_ACCOUNT), ExistentialDeposit::get() + payout);
+			assert_eq!(XXEconomics::rewards_balance(), initial_rewards_balance - payout);
+
+			// the same payout to a reaped account is dropped, and nothing is taken from the rewards pool
+			let imbalance = Balances::deposit_creating(&(POOL_REWARD_ACCOUNT + 1), payout);
+			XXEconomics::on_unbalanced(imbalance);
+			assert_eq!(Balances::free_balance(POOL_REWARD_ACCOUNT + 1), 0);
+			assert_eq!(XXEconomics::rewards_balance(), initial_rewards_balance - payout);
+		});
+}
+
 // era payout
 
 fn test_interest_curve() -> Vec<IdealInterestPoint<BlockNumber>> {
@@ -0,0 +1,26 @@
+//! Runtime API definition for the xx public pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+pub use xx_public::{DistributionCheck, DistributionSource, TransferData};
+
+sp_api::decl_runtime_apis! {
+    pub trait XXPublicApi<AccountId, Balance, BlockNumber, Hash> where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+    {
+        /// Check a distribution batch before it is submitted
+        ///
+        /// Reports if it's a duplicate, if there are enough funds, and which recipients
+        /// would have their vesting schedules merged or would fail the distribution
+        fn check_distribution(
+            source: DistributionSource,
+            data: Vec<TransferData<AccountId, Balance, BlockNumber>>,
+        ) -> DistributionCheck<AccountId, Balance, Hash>;
+    }
+}
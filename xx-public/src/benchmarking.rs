@@ -21,6 +21,20 @@ fn set_sale_manager<T: Config>(account: T::AccountId) {
 
 const MAX_DISTRIBUTIONS: u32 = 100;
 const EXPECTED_SCHEDULES: u32 = 4;
+const MAX_CLAIMS: u32 = 1000;
+
+fn commit_claims<T: Config>(root: T::Hash, total: BalanceOf<T>) {
+    let pool = XXPublic::<T>::testnet_account_id();
+    <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::make_free_balance_be(&pool, total * 2u32.into());
+    let manager = account_from_index::<T>(42);
+    set_testnet_manager::<T>(manager.clone());
+    XXPublic::<T>::testnet_commit_claims(
+        RawOrigin::Signed(manager).into(),
+        root,
+        total,
+        10u32.into(),
+    ).ok();
+}
 
 benchmarks!{
     set_testnet_manager_account {
@@ -81,6 +95,75 @@ benchmarks!{
         }
 
     }: _(RawOrigin::Signed(manager), distribution)
+
+    testnet_commit_claims {
+        let total = <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::minimum_balance() * 100u32.into();
+        let pool = XXPublic::<T>::testnet_account_id();
+        <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::make_free_balance_be(&pool, total);
+        let manager = account_from_index::<T>(42);
+        set_testnet_manager::<T>(manager.clone());
+        let root = T::Hashing::hash_of(&0u32);
+    }: _(RawOrigin::Signed(manager), root, total, 10u32.into())
+    verify {
+        assert!(Claims::<T>::contains_key(&root));
+    }
+
+    sale_commit_claims {
+        let total = <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::minimum_balance() * 100u32.into();
+        let pool = XXPublic::<T>::sale_account_id();
+        <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::make_free_balance_be(&pool, total);
+        let manager = account_from_index::<T>(43);
+        set_sale_manager::<T>(manager.clone());
+        let root = T::Hashing::hash_of(&0u32);
+    }: _(RawOrigin::Signed(manager), root, total, 10u32.into())
+    verify {
+        assert!(Claims::<T>::contains_key(&root));
+    }
+
+    claim {
+        let p in 0 .. T::MaxClaimProofLength::get();
+        let s in 0 .. EXPECTED_SCHEDULES;
+        let amount = <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::minimum_balance() * 25u32.into();
+        let vest = <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::minimum_balance() * 1u32.into();
+        let block = T::BlockNumber::zero();
+        let mut scheds = Vec::<(BalanceOf<T>, BalanceOf<T>, T::BlockNumber)>::new();
+        for i in 0 .. s {
+            scheds.push((vest.clone(), vest.clone(), block.clone()))
+        }
+        let data = TransferData::<T::AccountId, BalanceOf<T>, T::BlockNumber> {
+            destination: account_from_index::<T>(0),
+            amount: amount.clone(),
+            schedules: Some(scheds),
+        };
+        // Any list of siblings is a valid proof for the root it computes
+        let proof: Vec<T::Hash> = (0 .. p).map(|i| T::Hashing::hash_of(&i)).collect();
+        let leaf = XXPublic::<T>::claim_leaf(&data);
+        let root = XXPublic::<T>::claim_root(leaf, &proof);
+        commit_claims::<T>(root, amount);
+    }: _(RawOrigin::None, root, data, proof)
+    verify {
+        assert!(Claimed::<T>::get(&root, &leaf));
+    }
+
+    sweep_claims {
+        let n in 0 .. MAX_CLAIMS;
+        let total = <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::minimum_balance() * 100u32.into();
+        let root = T::Hashing::hash_of(&0u32);
+        commit_claims::<T>(root, total);
+        Claims::<T>::mutate(&root, |info| {
+            if let Some(info) = info {
+                info.claims = n;
+            }
+        });
+        for i in 0 .. n {
+            Claimed::<T>::insert(&root, T::Hashing::hash_of(&i), true);
+        }
+        frame_system::Pallet::<T>::set_block_number(11u32.into());
+        let caller = account_from_index::<T>(1);
+    }: _(RawOrigin::Signed(caller), root, n)
+    verify {
+        assert!(!Claims::<T>::contains_key(&root));
+    }
 }
 
 impl_benchmark_test_suite!(
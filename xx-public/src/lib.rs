@@ -16,12 +16,18 @@ use frame_support::traits::{
 };
 use frame_support::{
     decl_event, decl_module, decl_error, decl_storage, ensure,
-    PalletId, dispatch::{DispatchResult, DispatchClass, Pays},
+    PalletId, dispatch::{DispatchResult, DispatchError, DispatchClass, Pays},
+    storage::{with_transaction, TransactionOutcome},
 };
+use pallet_vesting::VestingInfo;
 use sp_runtime::{
-    traits::{Zero, AccountIdConversion}, RuntimeDebug
+    traits::{Zero, One, Saturating, AccountIdConversion, Convert, Hash}, RuntimeDebug,
+    transaction_validity::{
+        TransactionLongevity, TransactionValidity, ValidTransaction, InvalidTransaction,
+        TransactionSource,
+    },
 };
-use frame_system::{ensure_root, ensure_signed};
+use frame_system::{ensure_root, ensure_signed, ensure_none};
 use codec::{Encode, Decode, HasCompact};
 use sp_std::prelude::*;
 pub use weights::WeightInfo;
@@ -41,17 +47,140 @@ pub struct TransferData<AccountId, Balance: HasCompact, Block> {
     pub schedules: Option<Vec<(Balance, Balance, Block)>>,
 }
 
+/// Distribution info contains information about a completed distribution
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+pub struct DistributionInfo<AccountId, Balance: HasCompact, Block> {
+    /// Account the funds were distributed from
+    pub source: AccountId,
+    /// Number of recipients
+    pub recipients: u32,
+    /// Total amount distributed
+    #[codec(compact)]
+    pub total: Balance,
+    /// Block in which the distribution was done
+    pub block: Block,
+}
+
+/// Claims info contains information about a committed Merkle root of transfers
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+pub struct ClaimsInfo<AccountId, Balance: HasCompact, Block> {
+    /// Account the funds are claimed from
+    pub source: AccountId,
+    /// Total amount that can be claimed
+    #[codec(compact)]
+    pub total: Balance,
+    /// Amount claimed so far
+    #[codec(compact)]
+    pub claimed: Balance,
+    /// Number of claims done so far
+    pub claims: u32,
+    /// Block after which the claims can no longer be done
+    pub expiry: Block,
+}
+
+/// Vesting policy defines what to do when not all vesting schedules
+/// of a transfer can be added to the recipient
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+pub enum VestingPolicy {
+    /// Fail the whole distribution or claim
+    Fail,
+    /// Merge all the schedules of the transfer into a single schedule,
+    /// following the semantics of `pallet_vesting::merge_schedules`.
+    /// If the recipient has no free slot for it, it is merged into the last
+    /// schedule this pallet added to the recipient, and fails if there is none.
+    Merge,
+}
+
+/// Source account of a distribution
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+pub enum DistributionSource {
+    /// Testnet account
+    Testnet,
+    /// Sale account
+    Sale,
+}
+
+/// Result of checking a distribution before it is submitted
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+pub struct DistributionCheck<AccountId, Balance, Hash> {
+    /// ID of the distribution
+    pub id: Hash,
+    /// This distribution was already done
+    pub duplicate: bool,
+    /// Total amount to distribute
+    pub total: Balance,
+    /// Funds available in the source account
+    pub available: Balance,
+    /// Recipients whose vesting schedules would be merged
+    pub merged: Vec<AccountId>,
+    /// Recipients whose vesting schedules can't be added, failing the distribution
+    pub failed: Vec<AccountId>,
+}
+
+/// Custom validity errors used when validating claims.
+#[repr(u8)]
+pub enum ValidityError {
+    /// The Merkle root is unknown.
+    UnknownClaimsRoot = 0,
+    /// The claims for this root have expired.
+    ClaimsExpired = 1,
+    /// This transfer has already been claimed.
+    AlreadyClaimed = 2,
+    /// The Merkle proof is invalid.
+    InvalidProof = 3,
+    /// The claim exceeds the committed total.
+    ClaimExceedsTotal = 4,
+    /// The Merkle proof is longer than allowed.
+    ProofTooLong = 5,
+}
+
+impl From<ValidityError> for u8 {
+    fn from(err: ValidityError) -> Self {
+        err as u8
+    }
+}
+
+/// Tag prepended to each leaf of a claims Merkle tree before hashing
+pub const CLAIM_LEAF_TAG: &[u8] = b"xx-public/claim-leaf";
+/// Tag prepended to each internal node of a claims Merkle tree before hashing
+pub const CLAIM_NODE_TAG: &[u8] = b"xx-public/claim-node";
+
 pub trait PublicAccountsHandler<AccountId> {
     fn accounts() -> Vec<AccountId>;
 }
 
+/// A vesting mechanism that exposes the existing vesting schedules of an account
+pub trait VestingSchedules<AccountId>: VestingSchedule<AccountId> {
+    /// Get the vesting schedules of the given account, in order
+    fn vesting_schedules(
+        who: &AccountId,
+    ) -> Vec<VestingInfo<<Self::Currency as Currency<AccountId>>::Balance, Self::Moment>>;
+}
+
+impl<T: pallet_vesting::Config> VestingSchedules<T::AccountId> for pallet_vesting::Pallet<T> {
+    fn vesting_schedules(
+        who: &T::AccountId,
+    ) -> Vec<VestingInfo<pallet_vesting::BalanceOf<T>, T::BlockNumber>> {
+        Self::vesting(who).map(|schedules| schedules.into_inner()).unwrap_or_default()
+    }
+}
+
 pub trait Config: frame_system::Config {
     /// The Event type.
-    type RuntimeEvent: From<Event>
+    type RuntimeEvent: From<Event<Self>>
         + Into<<Self as frame_system::Config>::RuntimeEvent>;
 
     /// The Vesting mechanism.
-    type VestingSchedule: VestingSchedule<Self::AccountId, Moment=Self::BlockNumber>;
+    type VestingSchedule: VestingSchedules<Self::AccountId, Moment=Self::BlockNumber>;
+
+    /// Convert the block number into a balance, used when merging vesting schedules
+    type BlockNumberToBalance: Convert<Self::BlockNumber, BalanceOf<Self>>;
+
+    /// What to do when not all vesting schedules of a transfer can be added
+    type VestingPolicy: Get<VestingPolicy>;
+
+    /// Maximum length of the Merkle proof of a claim
+    type MaxClaimProofLength: Get<u32>;
 
     /// An ID used to derive the Testnet account
     type TestnetId: Get<PalletId>;
@@ -72,6 +201,21 @@ decl_storage! {
         pub TestnetManager get(fn testnet_manager): Option<T::AccountId>;
         /// Sale Manager account
         pub SaleManager get(fn sale_manager): Option<T::AccountId>;
+        /// Completed distributions, keyed by distribution ID
+        pub Distributions get(fn distributions): map hasher(identity)
+            T::Hash => Option<DistributionInfo<T::AccountId, BalanceOf<T>, T::BlockNumber>>;
+        /// Committed Merkle roots of transfers that can be claimed, keyed by root
+        pub Claims get(fn claims): map hasher(identity)
+            T::Hash => Option<ClaimsInfo<T::AccountId, BalanceOf<T>, T::BlockNumber>>;
+        /// Leaves that have been claimed from a given Merkle root
+        pub Claimed get(fn claimed): double_map hasher(identity) T::Hash,
+            hasher(identity) T::Hash => bool;
+        /// Funds of each source account committed to claims and not yet claimed
+        pub CommittedFunds get(fn committed_funds): map hasher(twox_64_concat)
+            T::AccountId => BalanceOf<T>;
+        /// Vesting schedules added by this pallet to each account, which later merges can extend
+        pub AddedVestingSchedules get(fn added_vesting_schedules): map hasher(twox_64_concat)
+            T::AccountId => Vec<VestingInfo<BalanceOf<T>, T::BlockNumber>>;
     }
 	add_extra_genesis {
         config(testnet_manager): Option<T::AccountId>;
@@ -97,12 +241,31 @@ decl_storage! {
 }
 
 decl_event! {
-    pub enum Event
+    pub enum Event<T> where
+        Balance = BalanceOf<T>,
+        <T as frame_system::Config>::AccountId,
+        <T as frame_system::Config>::Hash,
+        <T as frame_system::Config>::BlockNumber,
     {
         /// Testnet Manager updated
         TestnetManagerUpdated,
         /// Sale Manager updated
         SaleManagerUpdated,
+        /// Funds distributed to a recipient
+        /// \[distribution_id, recipient, amount, schedules_added, schedules_requested\]
+        Distributed(Hash, AccountId, Balance, u32, u32),
+        /// Distribution completed
+        /// \[distribution_id, source, recipients, total\]
+        DistributionCompleted(Hash, AccountId, u32, Balance),
+        /// Vesting schedules of a recipient didn't fit and were merged into one
+        /// \[distribution_id, recipient, schedules_requested\]
+        VestingSchedulesMerged(Hash, AccountId, u32),
+        /// Merkle root of claims committed
+        /// \[root, source, total, expiry\]
+        ClaimsCommitted(Hash, AccountId, Balance, BlockNumber),
+        /// Expired claims swept, unclaimed funds are released in the source account
+        /// \[root, unclaimed\]
+        ClaimsSwept(Hash, Balance),
     }
 }
 
@@ -114,6 +277,28 @@ decl_error! {
         MustBeSaleManager,
         /// Not enough funds to do distribution
         NotEnoughFunds,
+        /// This distribution was already done
+        DuplicateDistribution,
+        /// Expiry must be in the future
+        InvalidExpiry,
+        /// The Merkle root is unknown
+        UnknownClaimsRoot,
+        /// The claims for this root have expired
+        ClaimsExpired,
+        /// The claims for this root have not expired yet
+        ClaimsNotExpired,
+        /// This transfer has already been claimed
+        AlreadyClaimed,
+        /// The Merkle proof is invalid
+        InvalidProof,
+        /// The Merkle proof is longer than allowed
+        ProofTooLong,
+        /// The claim exceeds the committed total
+        ClaimExceedsTotal,
+        /// The number of claims witness is too low
+        InvalidClaimsWitness,
+        /// The vesting schedules of a transfer can't be added to the recipient
+        TooManyVestingSchedules,
 	}
 }
 
@@ -121,6 +306,7 @@ decl_module! {
 	pub struct Module<T: Config> for enum Call where origin: T::RuntimeOrigin {
 	    const TestnetAccount: T::AccountId = T::TestnetId::get().into_account_truncating();
 	    const SaleAccount: T::AccountId = T::SaleId::get().into_account_truncating();
+	    const MaxClaimProofLength: u32 = T::MaxClaimProofLength::get();
 
         type Error = Error<T>;
 
@@ -136,7 +322,7 @@ decl_module! {
         pub fn set_testnet_manager_account(origin, who: T::AccountId) {
             Self::ensure_admin(origin)?;
             <TestnetManager<T>>::put(who);
-            Self::deposit_event(Event::TestnetManagerUpdated);
+            Self::deposit_event(RawEvent::TestnetManagerUpdated);
         }
 
         /// Set the Sale manager account
@@ -147,13 +333,14 @@ decl_module! {
         pub fn set_sale_manager_account(origin, who: T::AccountId) {
             Self::ensure_admin(origin)?;
             <SaleManager<T>>::put(who);
-            Self::deposit_event(Event::SaleManagerUpdated);
+            Self::deposit_event(RawEvent::SaleManagerUpdated);
         }
 
         //----------------    MANAGERS    ----------------//
         /// Do a tesnet distribution
         ///
         /// `data` is a vector of TransferData
+        /// The same distribution can't be done twice, see `distribution_id`
         /// The dispatch origin must be `TestnetManager`
         ///
         #[weight = (
@@ -172,6 +359,7 @@ decl_module! {
         /// Do a sale distribution
         ///
         /// `data` is a vector of TransferData
+        /// The same distribution can't be done twice, see `distribution_id`
         /// The dispatch origin must be `SaleManager`
         ///
         #[weight = (
@@ -186,6 +374,88 @@ decl_module! {
             ensure!(Self::is_sale_manager(who), Error::<T>::MustBeSaleManager);
             Self::do_sale_distribution(data)?
         }
+
+        /// Commit a Merkle root of testnet transfers to be claimed
+        ///
+        /// The leaves of the tree are the hashes of each TransferData, see `claim_leaf`
+        /// `total` is the sum of all transfer amounts, which is reserved for the claims
+        /// Claims can be done until the `expiry` block
+        /// The dispatch origin must be `TestnetManager`
+        ///
+        #[weight = (
+			<T as Config>::WeightInfo::testnet_commit_claims(),
+			DispatchClass::Operational,
+			Pays::No
+		)]
+        pub fn testnet_commit_claims(origin,
+            root: T::Hash,
+            #[compact] total: BalanceOf<T>,
+            expiry: T::BlockNumber,
+        ) {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_testnet_manager(who), Error::<T>::MustBeTestnetManager);
+            Self::do_commit_claims(Self::testnet_account_id(), root, total, expiry)?
+        }
+
+        /// Commit a Merkle root of sale transfers to be claimed
+        ///
+        /// The leaves of the tree are the hashes of each TransferData, see `claim_leaf`
+        /// `total` is the sum of all transfer amounts, which is reserved for the claims
+        /// Claims can be done until the `expiry` block
+        /// The dispatch origin must be `SaleManager`
+        ///
+        #[weight = (
+			<T as Config>::WeightInfo::sale_commit_claims(),
+			DispatchClass::Operational,
+			Pays::No
+		)]
+        pub fn sale_commit_claims(origin,
+            root: T::Hash,
+            #[compact] total: BalanceOf<T>,
+            expiry: T::BlockNumber,
+        ) {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_sale_manager(who), Error::<T>::MustBeSaleManager);
+            Self::do_commit_claims(Self::sale_account_id(), root, total, expiry)?
+        }
+
+        //----------------   RECIPIENTS   ----------------//
+        /// Claim a transfer from a committed Merkle root
+        ///
+        /// `proof` is the list of sibling hashes from the leaf up to the root,
+        /// and can be at most `MaxClaimProofLength` long
+        /// Funds are transferred to `data.destination`, so anyone can submit the claim
+        /// If the vesting schedules don't fit and the `VestingPolicy` is to fail, nothing changes,
+        /// so the claim can be retried before expiry once the recipient frees a vesting schedule
+        /// slot, e.g. with `merge_schedules` from `pallet_vesting`. Funds still unclaimed at
+        /// expiry are released in the source account by `sweep_claims`
+        /// The dispatch origin must be None, this is validated by `ValidateUnsigned`
+        ///
+        #[weight = <T as Config>::WeightInfo::claim(
+            proof.len() as u32,
+            data.schedules.as_ref().map_or(0, |s| s.len() as u32),
+        )]
+        pub fn claim(origin,
+            root: T::Hash,
+            data: TransferData<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+            proof: Vec<T::Hash>,
+        ) {
+            ensure_none(origin)?;
+            Self::do_claim(root, data, proof)?
+        }
+
+        /// Sweep expired claims
+        ///
+        /// The unclaimed funds are released in the source account,
+        /// and the claims are recorded as a completed distribution.
+        /// `claims` must be at least the number of claims done for this root
+        /// Can be called by anyone after expiry
+        ///
+        #[weight = <T as Config>::WeightInfo::sweep_claims(*claims)]
+        pub fn sweep_claims(origin, root: T::Hash, #[compact] claims: u32) {
+            ensure_signed(origin)?;
+            Self::do_sweep_claims(root, claims)?
+        }
 	}
 }
 
@@ -200,6 +470,38 @@ impl<T: Config> Module<T> {
         T::SaleId::get().into_account_truncating()
     }
 
+    /// Get the ID of a distribution from the given account
+    ///
+    /// The ID is the hash of the source account and the full distribution data,
+    /// so submitting the same distribution twice results in the same ID
+    pub fn distribution_id(
+        account: &T::AccountId,
+        data: &Vec<TransferData<T::AccountId, BalanceOf<T>, T::BlockNumber>>,
+    ) -> T::Hash {
+        T::Hashing::hash_of(&(account, data))
+    }
+
+    /// Get the Merkle tree leaf of a claimable transfer
+    ///
+    /// Leaves are tagged with `CLAIM_LEAF_TAG`, so an internal node can't be presented as a leaf
+    pub fn claim_leaf(data: &TransferData<T::AccountId, BalanceOf<T>, T::BlockNumber>) -> T::Hash {
+        T::Hashing::hash_of(&(CLAIM_LEAF_TAG, data))
+    }
+
+    /// Compute the Merkle root from a leaf and its proof
+    ///
+    /// Pairs of nodes are sorted before hashing, so the proof doesn't need to
+    /// include the position of each sibling. Internal nodes are tagged with `CLAIM_NODE_TAG`
+    pub fn claim_root(leaf: T::Hash, proof: &[T::Hash]) -> T::Hash {
+        proof.iter().fold(leaf, |node, sibling| {
+            if node <= *sibling {
+                T::Hashing::hash_of(&(CLAIM_NODE_TAG, node, sibling))
+            } else {
+                T::Hashing::hash_of(&(CLAIM_NODE_TAG, sibling, node))
+            }
+        })
+    }
+
     /// Check if origin is admin
     fn ensure_admin(o: T::RuntimeOrigin) -> DispatchResult {
         <T as Config>::AdminOrigin::try_origin(o)
@@ -245,39 +547,401 @@ impl<T: Config> Module<T> {
         account: T::AccountId,
         data: Vec<TransferData<T::AccountId, BalanceOf<T>, T::BlockNumber>>,
     ) -> DispatchResult {
+        // Exit early if this distribution was already done
+        let id = Self::distribution_id(&account, &data);
+        ensure!(!<Distributions<T>>::contains_key(&id), Error::<T>::DuplicateDistribution);
         // Exit early if not enough funds to do distribution
-        let available = <CurrencyOf<T>>::free_balance(&account);
+        let available = Self::available_funds(&account);
         let total = data.iter().fold(Zero::zero(), |acc, x| {
             acc + x.amount
         });
         ensure!(available >= total, Error::<T>::NotEnoughFunds);
         // Do distribution
-        data.iter().try_for_each(|d| -> DispatchResult {
-            <CurrencyOf<T>>::transfer(
-                &account,
-                &d.destination,
-                d.amount,
-                AllowDeath,
+        data.iter().try_for_each(|d| Self::do_transfer(&account, id, d))?;
+        // Record distribution
+        let recipients = data.len() as u32;
+        <Distributions<T>>::insert(&id, DistributionInfo {
+            source: account.clone(),
+            recipients,
+            total,
+            block: <frame_system::Pallet<T>>::block_number(),
+        });
+        Self::deposit_event(RawEvent::DistributionCompleted(id, account, recipients, total));
+        Ok(())
+    }
+
+    /// Do a single transfer of a distribution
+    fn do_transfer(
+        account: &T::AccountId,
+        id: T::Hash,
+        d: &TransferData<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+    ) -> DispatchResult {
+        <CurrencyOf<T>>::transfer(
+            account,
+            &d.destination,
+            d.amount,
+            AllowDeath,
+        )?;
+        let mut requested = 0u32;
+        let mut added = 0u32;
+        if let Some(vs) = &d.schedules {
+            requested = vs.len() as u32;
+            let (schedules_added, merged) = Self::add_vesting_schedules(&d.destination, vs)?;
+            added = schedules_added;
+            if merged {
+                Self::deposit_event(
+                    RawEvent::VestingSchedulesMerged(id, d.destination.clone(), requested)
+                );
+            }
+        }
+        Self::deposit_event(
+            RawEvent::Distributed(id, d.destination.clone(), d.amount, added, requested)
+        );
+        Ok(())
+    }
+
+    /// Add the vesting schedules of a transfer to the recipient
+    ///
+    /// Returns the number of schedules added, and whether schedules were merged.
+    /// This can fail if the recipient doesn't have enough free vesting schedule slots.
+    /// In that case, the `VestingPolicy` is applied: either fail, or merge all schedules into one.
+    /// The merged schedule is added in a free slot, or merged into the last schedule
+    /// this pallet added to the recipient if there are none left.
+    /// Schedules added by anything else are never merged, so if none of the recipient's
+    /// schedules were added by this pallet the transfer fails with `TooManyVestingSchedules`.
+    fn add_vesting_schedules(
+        who: &T::AccountId,
+        schedules: &Vec<(BalanceOf<T>, BalanceOf<T>, T::BlockNumber)>,
+    ) -> Result<(u32, bool), DispatchError> {
+        // 1. Try to add all schedules, rolling back if any of them fails
+        let all_added = with_transaction(|| {
+            match schedules.iter().try_for_each(|v| {
+                T::VestingSchedule::add_vesting_schedule(who, v.0, v.1, v.2)
+            }) {
+                Ok(()) => TransactionOutcome::Commit(Ok(())),
+                Err(e) => TransactionOutcome::Rollback(Err(e)),
+            }
+        });
+        if all_added.is_ok() {
+            Self::record_vesting_schedules(
+                who,
+                None,
+                schedules.iter().map(|v| VestingInfo::new(v.0, v.1, v.2)).collect(),
+            );
+            return Ok((schedules.len() as u32, false))
+        }
+        // 2. Apply vesting policy
+        if T::VestingPolicy::get() == VestingPolicy::Fail {
+            Err(Error::<T>::TooManyVestingSchedules)?
+        }
+        let merged = match Self::merge_vesting_schedules(schedules) {
+            Some(merged) => merged,
+            // All schedules already ended, so nothing to lock
+            None => return Ok((0, false)),
+        };
+        // 3. Add the merged schedule in a free slot, if there is one
+        if T::VestingSchedule::can_add_vesting_schedule(
+            who,
+            merged.locked(),
+            merged.per_block(),
+            merged.starting_block(),
+        ).is_ok() {
+            T::VestingSchedule::add_vesting_schedule(
+                who,
+                merged.locked(),
+                merged.per_block(),
+                merged.starting_block(),
             )?;
-            if let Some(vs) = &d.schedules {
-                vs.iter().for_each( |v| {
-                    // This can fail if we try to add more vesting schedules
-                    // than the Vesting pallet limit.
-                    // The caller is responsible for ensuring the limit is respected.
-                    // This function can only be called by the privileged manager accounts,
-                    // so this is fine.
-                    // Regardless, in the case that too many schedules are used,
-                    // by ignoring the return value, we ensure the function never
-                    // fails, but the extra vesting schedules are ignored.
-                    let _ = T::VestingSchedule::add_vesting_schedule(
-                        &d.destination,
-                        v.0,
-                        v.1,
-                        v.2,
-                    );
-                });
+            Self::record_vesting_schedules(who, None, vec![merged]);
+            return Ok((1, true))
+        }
+        // 4. Otherwise merge it into the last schedule this pallet added to the recipient
+        let existing = T::VestingSchedule::vesting_schedules(who);
+        let added = Self::added_vesting_schedules(who);
+        let index = existing.iter()
+            .rposition(|schedule| added.contains(schedule))
+            .ok_or(Error::<T>::TooManyVestingSchedules)?;
+        let now = <frame_system::Pallet<T>>::block_number();
+        let replaced = existing[index];
+        let merged = Self::merge_vesting_info(now, replaced, merged);
+        T::VestingSchedule::remove_vesting_schedule(who, index as u32)?;
+        if let Some(merged) = merged {
+            T::VestingSchedule::add_vesting_schedule(
+                who,
+                merged.locked(),
+                merged.per_block(),
+                merged.starting_block(),
+            ).map_err(|_| Error::<T>::TooManyVestingSchedules)?;
+        }
+        Self::record_vesting_schedules(who, Some(replaced), merged.into_iter().collect());
+        Ok((0, true))
+    }
+
+    /// Update the vesting schedules this pallet added to an account
+    ///
+    /// Adds the new schedules, drops the replaced one, and prunes any schedule the account
+    /// no longer has, so the list never grows beyond the maximum number of vesting schedules.
+    fn record_vesting_schedules(
+        who: &T::AccountId,
+        replaced: Option<VestingInfo<BalanceOf<T>, T::BlockNumber>>,
+        new: Vec<VestingInfo<BalanceOf<T>, T::BlockNumber>>,
+    ) {
+        let existing = T::VestingSchedule::vesting_schedules(who);
+        let mut added = Self::added_vesting_schedules(who);
+        if let Some(pos) = replaced.and_then(|r| added.iter().position(|s| s == &r)) {
+            added.remove(pos);
+        }
+        added.extend(new);
+        // Keep one entry per existing schedule, since the same schedule can be added twice
+        let mut remaining = existing;
+        added.retain(|schedule| match remaining.iter().position(|s| s == schedule) {
+            Some(pos) => {
+                remaining.remove(pos);
+                true
+            },
+            None => false,
+        });
+        if added.is_empty() {
+            <AddedVestingSchedules<T>>::remove(who);
+        } else {
+            <AddedVestingSchedules<T>>::insert(who, added);
+        }
+    }
+
+    /// Merge a list of vesting schedules into a single one
+    ///
+    /// Returns `None` if all schedules have already ended
+    fn merge_vesting_schedules(
+        schedules: &Vec<(BalanceOf<T>, BalanceOf<T>, T::BlockNumber)>,
+    ) -> Option<VestingInfo<BalanceOf<T>, T::BlockNumber>> {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let now_as_balance = T::BlockNumberToBalance::convert(now);
+        schedules.iter()
+            .map(|v| VestingInfo::new(v.0, v.1, v.2))
+            .fold(None, |acc, schedule| match acc {
+                Some(merged) => Self::merge_vesting_info(now, merged, schedule),
+                None if schedule.ending_block_as_balance::<T::BlockNumberToBalance>() > now_as_balance => {
+                    Some(schedule)
+                },
+                None => None,
+            })
+    }
+
+    /// Merge two vesting schedules
+    ///
+    /// This follows `pallet_vesting`, where the merged schedule locks the remaining amount
+    /// of both schedules, starts at the latest starting block and ends at the latest ending block.
+    fn merge_vesting_info(
+        now: T::BlockNumber,
+        schedule1: VestingInfo<BalanceOf<T>, T::BlockNumber>,
+        schedule2: VestingInfo<BalanceOf<T>, T::BlockNumber>,
+    ) -> Option<VestingInfo<BalanceOf<T>, T::BlockNumber>> {
+        let schedule1_ending_block = schedule1.ending_block_as_balance::<T::BlockNumberToBalance>();
+        let schedule2_ending_block = schedule2.ending_block_as_balance::<T::BlockNumberToBalance>();
+        let now_as_balance = T::BlockNumberToBalance::convert(now);
+
+        // Check if one or both schedules have ended
+        match (schedule1_ending_block <= now_as_balance, schedule2_ending_block <= now_as_balance) {
+            (true, true) => return None,
+            (true, false) => return Some(schedule2),
+            (false, true) => return Some(schedule1),
+            _ => {},
+        }
+
+        let locked = schedule1
+            .locked_at::<T::BlockNumberToBalance>(now)
+            .saturating_add(schedule2.locked_at::<T::BlockNumberToBalance>(now));
+        let ending_block = schedule1_ending_block.max(schedule2_ending_block);
+        let starting_block = now.max(schedule1.starting_block()).max(schedule2.starting_block());
+        let per_block = {
+            let duration = ending_block
+                .saturating_sub(T::BlockNumberToBalance::convert(starting_block))
+                .max(One::one());
+            (locked / duration).max(One::one())
+        };
+
+        Some(VestingInfo::new(locked, per_block, starting_block))
+    }
+
+    /// Check a distribution before it is submitted
+    ///
+    /// All changes to storage are reverted, this is meant to be used by the runtime API
+    pub fn check_distribution(
+        source: DistributionSource,
+        data: Vec<TransferData<T::AccountId, BalanceOf<T>, T::BlockNumber>>,
+    ) -> DistributionCheck<T::AccountId, BalanceOf<T>, T::Hash> {
+        let account = match source {
+            DistributionSource::Testnet => Self::testnet_account_id(),
+            DistributionSource::Sale => Self::sale_account_id(),
+        };
+        let id = Self::distribution_id(&account, &data);
+        let mut check = DistributionCheck {
+            id,
+            duplicate: <Distributions<T>>::contains_key(&id),
+            total: data.iter().fold(Zero::zero(), |acc: BalanceOf<T>, x| acc.saturating_add(x.amount)),
+            available: Self::available_funds(&account),
+            merged: Vec::new(),
+            failed: Vec::new(),
+        };
+        // Add the vesting schedules of each recipient in order, since the same
+        // recipient can appear more than once, and revert everything at the end
+        let _ = with_transaction(|| {
+            data.iter().for_each(|d| {
+                if let Some(vs) = &d.schedules {
+                    match Self::add_vesting_schedules(&d.destination, vs) {
+                        Ok((_, true)) => check.merged.push(d.destination.clone()),
+                        Ok(_) => {},
+                        Err(_) => check.failed.push(d.destination.clone()),
+                    }
+                }
+            });
+            TransactionOutcome::Rollback(Ok::<(), DispatchError>(()))
+        });
+        check
+    }
+
+    /// Commit a Merkle root of claims
+    fn do_commit_claims(
+        account: T::AccountId,
+        root: T::Hash,
+        total: BalanceOf<T>,
+        expiry: T::BlockNumber,
+    ) -> DispatchResult {
+        // 1. Check root wasn't used before
+        ensure!(
+            !<Claims<T>>::contains_key(&root) && !<Distributions<T>>::contains_key(&root),
+            Error::<T>::DuplicateDistribution
+        );
+        // 2. Check expiry is in the future
+        ensure!(expiry > <frame_system::Pallet<T>>::block_number(), Error::<T>::InvalidExpiry);
+        // 3. Check there are enough funds not already committed to other claims
+        ensure!(Self::available_funds(&account) >= total, Error::<T>::NotEnoughFunds);
+        // 4. Commit funds and store claims info
+        <CommittedFunds<T>>::mutate(&account, |committed| *committed = committed.saturating_add(total));
+        <Claims<T>>::insert(&root, ClaimsInfo {
+            source: account.clone(),
+            total,
+            claimed: Zero::zero(),
+            claims: 0,
+            expiry,
+        });
+        Self::deposit_event(RawEvent::ClaimsCommitted(root, account, total, expiry));
+        Ok(())
+    }
+
+    /// Validate a claim, returning the claims info
+    fn validate_claim(
+        root: &T::Hash,
+        data: &TransferData<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+        proof: &[T::Hash],
+    ) -> Result<ClaimsInfo<T::AccountId, BalanceOf<T>, T::BlockNumber>, ValidityError> {
+        ensure!(
+            proof.len() <= T::MaxClaimProofLength::get() as usize,
+            ValidityError::ProofTooLong
+        );
+        let info = <Claims<T>>::get(root).ok_or(ValidityError::UnknownClaimsRoot)?;
+        ensure!(
+            <frame_system::Pallet<T>>::block_number() <= info.expiry,
+            ValidityError::ClaimsExpired
+        );
+        let leaf = Self::claim_leaf(data);
+        ensure!(!<Claimed<T>>::get(root, &leaf), ValidityError::AlreadyClaimed);
+        ensure!(Self::claim_root(leaf, proof) == *root, ValidityError::InvalidProof);
+        ensure!(
+            info.claimed.saturating_add(data.amount) <= info.total,
+            ValidityError::ClaimExceedsTotal
+        );
+        Ok(info)
+    }
+
+    /// Do a claim
+    fn do_claim(
+        root: T::Hash,
+        data: TransferData<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+        proof: Vec<T::Hash>,
+    ) -> DispatchResult {
+        // 1. Validate claim
+        let mut info = Self::validate_claim(&root, &data, &proof).map_err(|e| match e {
+            ValidityError::UnknownClaimsRoot => Error::<T>::UnknownClaimsRoot,
+            ValidityError::ClaimsExpired => Error::<T>::ClaimsExpired,
+            ValidityError::AlreadyClaimed => Error::<T>::AlreadyClaimed,
+            ValidityError::InvalidProof => Error::<T>::InvalidProof,
+            ValidityError::ClaimExceedsTotal => Error::<T>::ClaimExceedsTotal,
+            ValidityError::ProofTooLong => Error::<T>::ProofTooLong,
+        })?;
+        // 2. Release committed funds and do transfer
+        <CommittedFunds<T>>::mutate(&info.source, |committed| {
+            *committed = committed.saturating_sub(data.amount)
+        });
+        Self::do_transfer(&info.source, root, &data)?;
+        // 3. Update claims info
+        info.claimed = info.claimed.saturating_add(data.amount);
+        info.claims = info.claims.saturating_add(1);
+        <Claims<T>>::insert(&root, info);
+        <Claimed<T>>::insert(&root, Self::claim_leaf(&data), true);
+        Ok(())
+    }
+
+    /// Sweep expired claims
+    fn do_sweep_claims(root: T::Hash, claims: u32) -> DispatchResult {
+        // 1. Check claims have expired
+        let info = <Claims<T>>::get(&root).ok_or(Error::<T>::UnknownClaimsRoot)?;
+        let now = <frame_system::Pallet<T>>::block_number();
+        ensure!(now > info.expiry, Error::<T>::ClaimsNotExpired);
+        ensure!(claims >= info.claims, Error::<T>::InvalidClaimsWitness);
+        // 2. Release unclaimed funds
+        let unclaimed = info.total.saturating_sub(info.claimed);
+        <CommittedFunds<T>>::mutate_exists(&info.source, |maybe_committed| {
+            let committed = maybe_committed.unwrap_or_default().saturating_sub(unclaimed);
+            *maybe_committed = if committed.is_zero() { None } else { Some(committed) };
+        });
+        // 3. Remove claims, recording them as a completed distribution.
+        // This prevents the same root from being committed again
+        let _ = <Claimed<T>>::clear_prefix(&root, info.claims, None);
+        <Claims<T>>::remove(&root);
+        <Distributions<T>>::insert(&root, DistributionInfo {
+            source: info.source.clone(),
+            recipients: info.claims,
+            total: info.claimed,
+            block: now,
+        });
+        Self::deposit_event(RawEvent::ClaimsSwept(root, unclaimed));
+        Self::deposit_event(
+            RawEvent::DistributionCompleted(root, info.source, info.claims, info.claimed)
+        );
+        Ok(())
+    }
+
+    /// Get the free balance of an account that isn't committed to claims
+    fn available_funds(account: &T::AccountId) -> BalanceOf<T> {
+        <CurrencyOf<T>>::free_balance(account).saturating_sub(<CommittedFunds<T>>::get(account))
+    }
+}
+
+impl<T: Config> sp_runtime::traits::ValidateUnsigned for Module<T> {
+    type Call = Call<T>;
+
+    fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+        const PRIORITY: u64 = 100;
+
+        // <weight>
+        // The weight of this logic is included in the `claim` dispatchable.
+        // </weight>
+        let (root, data) = match call {
+            Call::claim { root, data, proof } => {
+                Self::validate_claim(root, data, proof)
+                    .map_err(|e| InvalidTransaction::Custom(e.into()))?;
+                (root, data)
             }
-            Ok(().into())
+            _ => return Err(InvalidTransaction::Call.into()),
+        };
+
+        Ok(ValidTransaction {
+            priority: PRIORITY,
+            requires: vec![],
+            provides: vec![("xx_public_claim", root, Self::claim_leaf(data)).encode()],
+            longevity: TransactionLongevity::max_value(),
+            propagate: true,
         })
     }
 }
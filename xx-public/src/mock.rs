@@ -31,7 +31,7 @@ frame_support::construct_runtime!(
         System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
         Vesting: pallet_vesting::{Pallet, Call, Storage, Config<T>, Event<T>},
-        XXPublic: xx_public::{Pallet, Call, Storage, Config<T>, Event},
+        XXPublic: xx_public::{Pallet, Call, Storage, Config<T>, Event<T>, ValidateUnsigned},
     }
 );
 
@@ -109,11 +109,19 @@ ord_parameter_types! {
     pub const AdminAccount: AccountId = 99;
 }
 
+parameter_types! {
+    pub static PublicVestingPolicy: VestingPolicy = VestingPolicy::Merge;
+    pub const MaxClaimProofLength: u32 = 4;
+}
+
 pub type TestAdminOrigin = EnsureSignedBy<AdminAccount, AccountId>;
 
 impl xx_public::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type VestingSchedule = Vesting;
+    type BlockNumberToBalance = ConvertInto;
+    type VestingPolicy = PublicVestingPolicy;
+    type MaxClaimProofLength = MaxClaimProofLength;
     type TestnetId = TestnetId;
     type SaleId = SaleId;
     // Admin is technical committee unanimity
@@ -164,6 +172,11 @@ impl ExtBuilder {
         self
     }
 
+    pub fn with_vesting_policy(self, policy: VestingPolicy) -> Self {
+        PublicVestingPolicy::set(policy);
+        self
+    }
+
     pub fn build(self) -> sp_io::TestExternalities {
         sp_tracing::try_init_simple();
         let mut storage = frame_system::GenesisConfig::default()
@@ -217,7 +230,7 @@ pub(crate) fn run_to_block(n: BlockNumber) {
     }
 }
 
-pub(crate) fn xx_public_events() -> Vec<xx_public::Event> {
+pub(crate) fn xx_public_events() -> Vec<xx_public::Event<Test>> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -230,3 +243,25 @@ pub(crate) fn xx_public_events() -> Vec<xx_public::Event> {
         })
         .collect()
 }
+
+/// Build a Merkle tree of transfers, returning the root and the proof of each transfer
+///
+/// The number of transfers must be a power of two
+pub(crate) fn claims_tree(
+    data: &Vec<TransferData<AccountId, Balance, BlockNumber>>,
+) -> (H256, Vec<Vec<H256>>) {
+    let mut level: Vec<H256> = data.iter().map(|d| XXPublic::claim_leaf(d)).collect();
+    let mut proofs: Vec<Vec<H256>> = vec![vec![]; data.len()];
+    let mut positions: Vec<usize> = (0..data.len()).collect();
+    while level.len() > 1 {
+        for (proof, pos) in proofs.iter_mut().zip(positions.iter_mut()) {
+            proof.push(level[*pos ^ 1]);
+            *pos /= 2;
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| XXPublic::claim_root(pair[0], &[pair[1]]))
+            .collect();
+    }
+    (level[0], proofs)
+}
@@ -5,6 +5,7 @@ use mock::*;
 use frame_support::{assert_noop, assert_ok};
 use sp_runtime::{
     traits::BadOrigin,
+    testing::H256,
 };
 
 // set_testnet_manager_account
@@ -33,7 +34,7 @@ fn set_testnet_manager_account_called_by_admin_works() {
             );
             assert_eq!(
                 xx_public_events(),
-                vec![super::Event::TestnetManagerUpdated]
+                vec![RawEvent::TestnetManagerUpdated]
             );
         })
 }
@@ -64,7 +65,7 @@ fn set_sale_manager_account_called_by_admin_works() {
             );
             assert_eq!(
                 xx_public_events(),
-                vec![super::Event::SaleManagerUpdated]
+                vec![RawEvent::SaleManagerUpdated]
             );
         })
 }
@@ -315,8 +316,9 @@ fn distribute_to_account_with_existing_vesting() {
             // Check coins distributed
             assert_eq!(Balances::total_balance(&12), 300);
             assert_eq!(Balances::total_balance(&13), 300);
-            // Check only first vesting schedule added to account 12
-            assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&12), Some(198));
+            // Check vesting schedules merged into one for account 12
+            // Merged schedule locks 99 + 100, starting at block 100 and ending at block 101
+            assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&12), Some(298));
             // Check vesting schedule added to account 13
             assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&13), Some(199));
 
@@ -324,7 +326,7 @@ fn distribute_to_account_with_existing_vesting() {
             run_to_block(50);
 
             // Check vesting schedules updated
-            assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&12), Some(100));
+            assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&12), Some(249));
             assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&13), Some(150));
 
             // Advance to block 101
@@ -335,3 +337,787 @@ fn distribute_to_account_with_existing_vesting() {
             assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&13), Some(0));
         })
 }
+
+// distribution records and events
+
+#[test]
+fn distribution_is_recorded_and_emits_events() {
+    ExtBuilder::default()
+        .with_testnet_balance(1000)
+        .build_and_execute(|| {
+            let data = vec![
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 10,
+                    amount: 200,
+                    schedules: Some(vec![(100, 1, 0)])
+                },
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 11,
+                    amount: 100,
+                    schedules: None
+                },
+            ];
+            let id = XXPublic::distribution_id(&XXPublic::testnet_account_id(), &data);
+            assert_ok!(
+                XXPublic::testnet_distribute(RuntimeOrigin::signed(42), data)
+            );
+            // Check distribution recorded
+            assert_eq!(
+                XXPublic::distributions(id),
+                Some(DistributionInfo {
+                    source: XXPublic::testnet_account_id(),
+                    recipients: 2,
+                    total: 300,
+                    block: 1,
+                })
+            );
+            // Check events
+            assert_eq!(
+                xx_public_events(),
+                vec![
+                    RawEvent::Distributed(id, 10, 200, 1, 1),
+                    RawEvent::Distributed(id, 11, 100, 0, 0),
+                    RawEvent::DistributionCompleted(id, XXPublic::testnet_account_id(), 2, 300),
+                ]
+            );
+        })
+}
+
+#[test]
+fn duplicate_distribution_fails() {
+    ExtBuilder::default()
+        .with_testnet_balance(1000)
+        .with_sale_balance(1000)
+        .build_and_execute(|| {
+            let data = vec![
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 10,
+                    amount: 100,
+                    schedules: None
+                },
+            ];
+            assert_ok!(
+                XXPublic::testnet_distribute(RuntimeOrigin::signed(42), data.clone())
+            );
+            // Resubmitting the same distribution fails
+            assert_noop!(
+                XXPublic::testnet_distribute(RuntimeOrigin::signed(42), data.clone()),
+                Error::<Test>::DuplicateDistribution
+            );
+            // Same data from the sale account is a different distribution
+            assert_ok!(
+                XXPublic::sale_distribute(RuntimeOrigin::signed(43), data.clone())
+            );
+            assert_noop!(
+                XXPublic::sale_distribute(RuntimeOrigin::signed(43), data),
+                Error::<Test>::DuplicateDistribution
+            );
+            // Check coins only distributed once from each account
+            assert_eq!(Balances::total_balance(&10), 200);
+        })
+}
+
+#[test]
+fn distribution_event_reports_vesting_schedules_added() {
+    ExtBuilder::default()
+        .with_sale_balance(1000)
+        .with_vesting()
+        .build_and_execute(|| {
+            let data = vec![
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 12,
+                    amount: 200,
+                    schedules: Some(vec![(100, 1, 0), (100, 100, 100)])
+                },
+            ];
+            let id = XXPublic::distribution_id(&XXPublic::sale_account_id(), &data);
+            assert_ok!(
+                XXPublic::sale_distribute(RuntimeOrigin::signed(43), data)
+            );
+            // Account 12 already has one vesting schedule, so both are merged into one
+            assert_eq!(
+                xx_public_events(),
+                vec![
+                    RawEvent::VestingSchedulesMerged(id, 12, 2),
+                    RawEvent::Distributed(id, 12, 200, 1, 2),
+                    RawEvent::DistributionCompleted(id, XXPublic::sale_account_id(), 1, 200),
+                ]
+            );
+        })
+}
+
+#[test]
+fn distribution_fails_atomically_with_fail_vesting_policy() {
+    ExtBuilder::default()
+        .with_sale_balance(1000)
+        .with_vesting()
+        .with_vesting_policy(VestingPolicy::Fail)
+        .build_and_execute(|| {
+            let data = vec![
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 13,
+                    amount: 200,
+                    schedules: Some(vec![(100, 100, 100)])
+                },
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 12,
+                    amount: 200,
+                    schedules: Some(vec![(100, 1, 0), (100, 100, 100)])
+                },
+            ];
+            assert_noop!(
+                XXPublic::sale_distribute(RuntimeOrigin::signed(43), data.clone()),
+                Error::<Test>::TooManyVestingSchedules
+            );
+            // A single schedule fits
+            assert_ok!(
+                XXPublic::sale_distribute(RuntimeOrigin::signed(43), vec![data[0].clone()])
+            );
+            assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&13), Some(199));
+        })
+}
+
+#[test]
+fn merged_vesting_schedules_skip_ended_schedules() {
+    ExtBuilder::default()
+        .with_sale_balance(1000)
+        .with_vesting()
+        .build_and_execute(|| {
+            run_to_block(20);
+            let data = vec![
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 12,
+                    amount: 200,
+                    schedules: Some(vec![(100, 10, 0), (100, 1, 50), (60, 1, 50)])
+                },
+            ];
+            assert_ok!(
+                XXPublic::sale_distribute(RuntimeOrigin::signed(43), data)
+            );
+            // First schedule already ended, so only the other two are merged
+            // Merged schedule locks 160 from block 50 to 210
+            // Existing schedule has 80 locked
+            assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&12), Some(240));
+            run_to_block(100);
+            assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&12), Some(110));
+        })
+}
+
+#[test]
+fn merged_vesting_schedules_merge_into_existing_schedule_without_free_slots() {
+    ExtBuilder::default()
+        .with_sale_balance(1000)
+        .with_vesting()
+        .build_and_execute(|| {
+            let data = vec![
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 13,
+                    amount: 200,
+                    schedules: Some(vec![(100, 100, 100)])
+                },
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 13,
+                    amount: 100,
+                    schedules: Some(vec![(50, 50, 100)])
+                },
+            ];
+            let id = XXPublic::distribution_id(&XXPublic::sale_account_id(), &data);
+            assert_ok!(
+                XXPublic::sale_distribute(RuntimeOrigin::signed(43), data)
+            );
+            // Account 13 is at the maximum number of schedules after the first transfer,
+            // so the second schedule is merged into the last one
+            // Merged schedule locks 100 + 50, starting at block 100 and ending at block 101
+            assert_eq!(Vesting::vesting(&13).unwrap().len(), 2);
+            assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&13), Some(249));
+            assert_eq!(
+                XXPublic::added_vesting_schedules(&13),
+                vec![pallet_vesting::VestingInfo::new(150, 150, 100)]
+            );
+            assert_eq!(
+                xx_public_events(),
+                vec![
+                    RawEvent::Distributed(id, 13, 200, 1, 1),
+                    RawEvent::VestingSchedulesMerged(id, 13, 1),
+                    RawEvent::Distributed(id, 13, 100, 0, 1),
+                    RawEvent::DistributionCompleted(id, XXPublic::sale_account_id(), 2, 300),
+                ]
+            );
+
+            run_to_block(50);
+            assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&13), Some(200));
+            run_to_block(101);
+            assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&13), Some(0));
+        })
+}
+
+#[test]
+fn merged_vesting_schedules_never_merge_into_schedules_from_other_sources() {
+    ExtBuilder::default()
+        .with_sale_balance(1000)
+        .with_vesting()
+        .build_and_execute(|| {
+            // Fill the last free slot of account 12 with a vested transfer
+            let schedule = pallet_vesting::VestingInfo::new(50, 1, 10);
+            assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(43), 12, schedule));
+            let schedules = Vesting::vesting(&12).unwrap();
+            assert_eq!(schedules.len(), 2);
+
+            let data = vec![
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 12,
+                    amount: 200,
+                    schedules: Some(vec![(100, 100, 100)])
+                },
+            ];
+            // None of the schedules of account 12 were added by this pallet, so nothing is merged
+            assert_noop!(
+                XXPublic::sale_distribute(RuntimeOrigin::signed(43), data),
+                Error::<Test>::TooManyVestingSchedules
+            );
+            assert_eq!(Vesting::vesting(&12).unwrap(), schedules);
+            assert!(XXPublic::added_vesting_schedules(&12).is_empty());
+        })
+}
+
+#[test]
+fn ended_vesting_schedules_are_not_reported_as_merged() {
+    ExtBuilder::default()
+        .with_sale_balance(1000)
+        .with_vesting()
+        .build_and_execute(|| {
+            run_to_block(20);
+            let data = vec![
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 12,
+                    amount: 200,
+                    schedules: Some(vec![(100, 100, 100)])
+                },
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 12,
+                    amount: 100,
+                    schedules: Some(vec![(100, 10, 0)])
+                },
+            ];
+            let id = XXPublic::distribution_id(&XXPublic::sale_account_id(), &data);
+            assert_ok!(
+                XXPublic::sale_distribute(RuntimeOrigin::signed(43), data)
+            );
+            // Account 12 has no free slots for the second schedule, but it already ended,
+            // so nothing is locked or merged
+            assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&12), Some(180));
+            assert_eq!(
+                xx_public_events(),
+                vec![
+                    RawEvent::Distributed(id, 12, 200, 1, 1),
+                    RawEvent::Distributed(id, 12, 100, 0, 1),
+                    RawEvent::DistributionCompleted(id, XXPublic::sale_account_id(), 2, 300),
+                ]
+            );
+        })
+}
+
+#[test]
+fn check_distribution_reports_vesting_policy_outcome() {
+    ExtBuilder::default()
+        .with_sale_balance(1000)
+        .with_vesting()
+        .build_and_execute(|| {
+            let data = vec![
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 12,
+                    amount: 200,
+                    schedules: Some(vec![(100, 1, 0), (100, 100, 100)])
+                },
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 13,
+                    amount: 200,
+                    schedules: Some(vec![(100, 100, 100)])
+                },
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 13,
+                    amount: 100,
+                    schedules: Some(vec![(50, 50, 100)])
+                },
+            ];
+            let id = XXPublic::distribution_id(&XXPublic::sale_account_id(), &data);
+            assert_eq!(
+                XXPublic::check_distribution(DistributionSource::Sale, data.clone()),
+                DistributionCheck {
+                    id,
+                    duplicate: false,
+                    total: 500,
+                    available: 1000,
+                    // Account 13 has no free slots left for the second transfer,
+                    // so it is merged into its last schedule
+                    merged: vec![12, 13],
+                    failed: vec![],
+                }
+            );
+            // Nothing changed in storage
+            assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&12), Some(99));
+            assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&13), Some(99));
+
+            // With the fail policy, account 12 and the second transfer to account 13 fail
+            PublicVestingPolicy::set(VestingPolicy::Fail);
+            assert_eq!(
+                XXPublic::check_distribution(DistributionSource::Sale, data.clone()).failed,
+                vec![12, 13]
+            );
+            PublicVestingPolicy::set(VestingPolicy::Merge);
+
+            // Duplicate is reported after the distribution is done
+            let data = data[..2].to_vec();
+            assert_ok!(
+                XXPublic::sale_distribute(RuntimeOrigin::signed(43), data.clone())
+            );
+            let check = XXPublic::check_distribution(DistributionSource::Sale, data);
+            assert!(check.duplicate);
+            assert_eq!(check.available, 600);
+        })
+}
+
+// claims
+
+fn claims_data() -> Vec<TransferData<AccountId, Balance, BlockNumber>> {
+    vec![
+        TransferData::<AccountId, Balance, BlockNumber> {
+            destination: 10,
+            amount: 200,
+            schedules: Some(vec![(100, 1, 0)])
+        },
+        TransferData::<AccountId, Balance, BlockNumber> {
+            destination: 11,
+            amount: 100,
+            schedules: None
+        },
+        TransferData::<AccountId, Balance, BlockNumber> {
+            destination: 12,
+            amount: 50,
+            schedules: None
+        },
+        TransferData::<AccountId, Balance, BlockNumber> {
+            destination: 13,
+            amount: 50,
+            schedules: None
+        },
+    ]
+}
+
+#[test]
+fn commit_claims_called_by_non_manager_fails() {
+    ExtBuilder::default()
+        .with_testnet_balance(1000)
+        .with_sale_balance(1000)
+        .build_and_execute(|| {
+            let (root, _) = claims_tree(&claims_data());
+            assert_noop!(
+                XXPublic::testnet_commit_claims(RuntimeOrigin::signed(43), root, 400, 10),
+                Error::<Test>::MustBeTestnetManager
+            );
+            assert_noop!(
+                XXPublic::sale_commit_claims(RuntimeOrigin::signed(42), root, 400, 10),
+                Error::<Test>::MustBeSaleManager
+            );
+        })
+}
+
+#[test]
+fn commit_claims_works() {
+    ExtBuilder::default()
+        .with_testnet_balance(1000)
+        .build_and_execute(|| {
+            let (root, _) = claims_tree(&claims_data());
+            assert_ok!(
+                XXPublic::testnet_commit_claims(RuntimeOrigin::signed(42), root, 400, 10)
+            );
+            assert_eq!(
+                XXPublic::claims(root),
+                Some(ClaimsInfo {
+                    source: XXPublic::testnet_account_id(),
+                    total: 400,
+                    claimed: 0,
+                    claims: 0,
+                    expiry: 10,
+                })
+            );
+            assert_eq!(XXPublic::committed_funds(XXPublic::testnet_account_id()), 400);
+            // Funds stay in the pool account
+            assert_eq!(Balances::free_balance(XXPublic::testnet_account_id()), 1000);
+            assert_eq!(
+                xx_public_events(),
+                vec![RawEvent::ClaimsCommitted(root, XXPublic::testnet_account_id(), 400, 10)]
+            );
+            // Same root can't be committed twice
+            assert_noop!(
+                XXPublic::testnet_commit_claims(RuntimeOrigin::signed(42), root, 400, 10),
+                Error::<Test>::DuplicateDistribution
+            );
+        })
+}
+
+#[test]
+fn commit_claims_fails_with_invalid_expiry() {
+    ExtBuilder::default()
+        .with_sale_balance(1000)
+        .build_and_execute(|| {
+            let (root, _) = claims_tree(&claims_data());
+            assert_noop!(
+                XXPublic::sale_commit_claims(RuntimeOrigin::signed(43), root, 400, 1),
+                Error::<Test>::InvalidExpiry
+            );
+        })
+}
+
+#[test]
+fn committed_funds_cant_be_distributed() {
+    ExtBuilder::default()
+        .with_sale_balance(500)
+        .build_and_execute(|| {
+            let (root, _) = claims_tree(&claims_data());
+            assert_ok!(
+                XXPublic::sale_commit_claims(RuntimeOrigin::signed(43), root, 400, 10)
+            );
+            // Only 100 coins are not committed
+            let data = vec![
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 20,
+                    amount: 101,
+                    schedules: None
+                },
+            ];
+            assert_noop!(
+                XXPublic::sale_distribute(RuntimeOrigin::signed(43), data),
+                Error::<Test>::NotEnoughFunds
+            );
+            let (other_root, _) = claims_tree(&vec![claims_data()[0].clone()]);
+            assert_noop!(
+                XXPublic::sale_commit_claims(RuntimeOrigin::signed(43), other_root, 101, 10),
+                Error::<Test>::NotEnoughFunds
+            );
+        })
+}
+
+#[test]
+fn claim_works() {
+    ExtBuilder::default()
+        .with_testnet_balance(1000)
+        .build_and_execute(|| {
+            let data = claims_data();
+            let (root, proofs) = claims_tree(&data);
+            assert_ok!(
+                XXPublic::testnet_commit_claims(RuntimeOrigin::signed(42), root, 400, 10)
+            );
+            assert_ok!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[0].clone(), proofs[0].clone())
+            );
+            assert_ok!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[3].clone(), proofs[3].clone())
+            );
+            // Check coins and vesting
+            assert_eq!(Balances::total_balance(&10), 200);
+            assert_eq!(<Test as Config>::VestingSchedule::vesting_balance(&10), Some(99));
+            assert_eq!(Balances::total_balance(&13), 150);
+            assert_eq!(Balances::free_balance(XXPublic::testnet_account_id()), 750);
+            assert_eq!(XXPublic::committed_funds(XXPublic::testnet_account_id()), 150);
+            assert!(XXPublic::claimed(root, XXPublic::claim_leaf(&data[0])));
+            assert!(!XXPublic::claimed(root, XXPublic::claim_leaf(&data[1])));
+            assert_eq!(XXPublic::claims(root).unwrap().claimed, 250);
+            assert_eq!(XXPublic::claims(root).unwrap().claims, 2);
+            // Check events
+            assert_eq!(
+                xx_public_events(),
+                vec![
+                    RawEvent::ClaimsCommitted(root, XXPublic::testnet_account_id(), 400, 10),
+                    RawEvent::Distributed(root, 10, 200, 1, 1),
+                    RawEvent::Distributed(root, 13, 50, 0, 0),
+                ]
+            );
+        })
+}
+
+#[test]
+fn claim_can_be_retried_after_freeing_vesting_slots_with_fail_vesting_policy() {
+    ExtBuilder::default()
+        .with_sale_balance(1000)
+        .with_vesting()
+        .with_vesting_policy(VestingPolicy::Fail)
+        .build_and_execute(|| {
+            // Fill the last free slot of account 12 with a vested transfer
+            let schedule = pallet_vesting::VestingInfo::new(50, 1, 10);
+            assert_ok!(Vesting::vested_transfer(RuntimeOrigin::signed(43), 12, schedule));
+            let data = vec![
+                TransferData::<AccountId, Balance, BlockNumber> {
+                    destination: 12,
+                    amount: 50,
+                    schedules: Some(vec![(50, 1, 0)])
+                },
+            ];
+            let (root, proofs) = claims_tree(&data);
+            assert_ok!(
+                XXPublic::sale_commit_claims(RuntimeOrigin::signed(43), root, 50, 10)
+            );
+            // Claim fails without changing anything, so funds stay committed
+            assert_noop!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[0].clone(), proofs[0].clone()),
+                Error::<Test>::TooManyVestingSchedules
+            );
+            assert_eq!(XXPublic::committed_funds(XXPublic::sale_account_id()), 50);
+
+            // Recipient merges its schedules to free a slot, and the claim can be retried
+            assert_ok!(Vesting::merge_schedules(RuntimeOrigin::signed(12), 0, 1));
+            assert_ok!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[0].clone(), proofs[0].clone())
+            );
+            assert_eq!(Vesting::vesting(&12).unwrap().len(), 2);
+            assert!(XXPublic::claimed(root, XXPublic::claim_leaf(&data[0])));
+            assert_eq!(XXPublic::committed_funds(XXPublic::sale_account_id()), 0);
+        })
+}
+
+#[test]
+fn claim_fails_when_invalid() {
+    ExtBuilder::default()
+        .with_testnet_balance(1000)
+        .build_and_execute(|| {
+            let data = claims_data();
+            let (root, proofs) = claims_tree(&data);
+            // Unknown root
+            assert_noop!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[0].clone(), proofs[0].clone()),
+                Error::<Test>::UnknownClaimsRoot
+            );
+            assert_ok!(
+                XXPublic::testnet_commit_claims(RuntimeOrigin::signed(42), root, 400, 10)
+            );
+            // Signed origin
+            assert_noop!(
+                XXPublic::claim(RuntimeOrigin::signed(10), root, data[0].clone(), proofs[0].clone()),
+                BadOrigin
+            );
+            // Wrong proof
+            assert_noop!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[0].clone(), proofs[1].clone()),
+                Error::<Test>::InvalidProof
+            );
+            // Tampered amount
+            let mut tampered = data[1].clone();
+            tampered.amount = 300;
+            assert_noop!(
+                XXPublic::claim(RuntimeOrigin::none(), root, tampered, proofs[1].clone()),
+                Error::<Test>::InvalidProof
+            );
+            // Double claim
+            assert_ok!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[1].clone(), proofs[1].clone())
+            );
+            assert_noop!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[1].clone(), proofs[1].clone()),
+                Error::<Test>::AlreadyClaimed
+            );
+            // Expired
+            run_to_block(11);
+            assert_noop!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[0].clone(), proofs[0].clone()),
+                Error::<Test>::ClaimsExpired
+            );
+        })
+}
+
+#[test]
+fn claim_fails_with_too_long_proof() {
+    use sp_runtime::traits::ValidateUnsigned;
+    let source = sp_runtime::transaction_validity::TransactionSource::External;
+
+    ExtBuilder::default()
+        .with_testnet_balance(1000)
+        .build_and_execute(|| {
+            let data = claims_data();
+            // A proof longer than the maximum, even if it computes the committed root
+            let proof: Vec<H256> = (0..MaxClaimProofLength::get() + 1)
+                .map(|i| H256::repeat_byte(i as u8))
+                .collect();
+            let root = XXPublic::claim_root(XXPublic::claim_leaf(&data[0]), &proof);
+            assert_ok!(
+                XXPublic::testnet_commit_claims(RuntimeOrigin::signed(42), root, 400, 10)
+            );
+            assert_eq!(
+                <XXPublic as ValidateUnsigned>::validate_unsigned(
+                    source,
+                    &Call::claim { root, data: data[0].clone(), proof: proof.clone() }
+                ),
+                InvalidTransaction::Custom(ValidityError::ProofTooLong.into()).into(),
+            );
+            assert_noop!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[0].clone(), proof),
+                Error::<Test>::ProofTooLong
+            );
+        })
+}
+
+#[test]
+fn claim_leaves_and_nodes_are_domain_separated() {
+    let data = claims_data();
+    let (a, b) = (XXPublic::claim_leaf(&data[0]), XXPublic::claim_leaf(&data[1]));
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let node = XXPublic::claim_root(a, &[b]);
+    // A transfer encoding to the same bytes as a pair of nodes doesn't hash to their parent
+    assert_ne!(
+        <Test as frame_system::Config>::Hashing::hash_of(&(CLAIM_LEAF_TAG, left, right)),
+        node
+    );
+    assert_eq!(
+        <Test as frame_system::Config>::Hashing::hash_of(&(CLAIM_NODE_TAG, left, right)),
+        node
+    );
+}
+
+#[test]
+fn claim_works_for_several_transfers_to_same_destination() {
+    ExtBuilder::default()
+        .with_testnet_balance(1000)
+        .build_and_execute(|| {
+            let mut data = claims_data();
+            data.truncate(2);
+            data[1].destination = 10;
+            let (root, proofs) = claims_tree(&data);
+            assert_ok!(
+                XXPublic::testnet_commit_claims(RuntimeOrigin::signed(42), root, 300, 10)
+            );
+            assert_ok!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[0].clone(), proofs[0].clone())
+            );
+            assert_ok!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[1].clone(), proofs[1].clone())
+            );
+            assert_eq!(Balances::total_balance(&10), 300);
+            assert!(XXPublic::claimed(root, XXPublic::claim_leaf(&data[0])));
+            assert!(XXPublic::claimed(root, XXPublic::claim_leaf(&data[1])));
+            assert_eq!(XXPublic::claims(root).unwrap().claims, 2);
+            // Each transfer can still only be claimed once
+            assert_noop!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[1].clone(), proofs[1].clone()),
+                Error::<Test>::AlreadyClaimed
+            );
+        })
+}
+
+#[test]
+fn claim_fails_when_exceeding_committed_total() {
+    ExtBuilder::default()
+        .with_testnet_balance(1000)
+        .build_and_execute(|| {
+            let data = claims_data();
+            let (root, proofs) = claims_tree(&data);
+            // Manager commits less than the sum of the tree
+            assert_ok!(
+                XXPublic::testnet_commit_claims(RuntimeOrigin::signed(42), root, 250, 10)
+            );
+            assert_ok!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[0].clone(), proofs[0].clone())
+            );
+            assert_noop!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[1].clone(), proofs[1].clone()),
+                Error::<Test>::ClaimExceedsTotal
+            );
+        })
+}
+
+#[test]
+fn validate_unsigned_claim_works() {
+    use sp_runtime::traits::ValidateUnsigned;
+    let source = sp_runtime::transaction_validity::TransactionSource::External;
+
+    ExtBuilder::default()
+        .with_testnet_balance(1000)
+        .build_and_execute(|| {
+            let data = claims_data();
+            let (root, proofs) = claims_tree(&data);
+            assert_ok!(
+                XXPublic::testnet_commit_claims(RuntimeOrigin::signed(42), root, 400, 10)
+            );
+            assert_eq!(
+                <XXPublic as ValidateUnsigned>::validate_unsigned(
+                    source,
+                    &Call::claim { root, data: data[0].clone(), proof: proofs[0].clone() }
+                ),
+                Ok(ValidTransaction {
+                    priority: 100,
+                    requires: vec![],
+                    provides: vec![("xx_public_claim", root, XXPublic::claim_leaf(&data[0])).encode()],
+                    longevity: TransactionLongevity::max_value(),
+                    propagate: true,
+                })
+            );
+            assert_eq!(
+                <XXPublic as ValidateUnsigned>::validate_unsigned(
+                    source,
+                    &Call::claim { root, data: data[0].clone(), proof: proofs[1].clone() }
+                ),
+                InvalidTransaction::Custom(ValidityError::InvalidProof.into()).into(),
+            );
+            assert_eq!(
+                <XXPublic as ValidateUnsigned>::validate_unsigned(
+                    source,
+                    &Call::testnet_distribute { data: vec![] }
+                ),
+                InvalidTransaction::Call.into(),
+            );
+        })
+}
+
+#[test]
+fn sweep_claims_works() {
+    ExtBuilder::default()
+        .with_sale_balance(1000)
+        .build_and_execute(|| {
+            let data = claims_data();
+            let (root, proofs) = claims_tree(&data);
+            assert_ok!(
+                XXPublic::sale_commit_claims(RuntimeOrigin::signed(43), root, 400, 10)
+            );
+            assert_ok!(
+                XXPublic::claim(RuntimeOrigin::none(), root, data[1].clone(), proofs[1].clone())
+            );
+            // Can't sweep before expiry
+            assert_noop!(
+                XXPublic::sweep_claims(RuntimeOrigin::signed(1), root, 1),
+                Error::<Test>::ClaimsNotExpired
+            );
+            run_to_block(11);
+            // Witness must cover the number of claims
+            assert_noop!(
+                XXPublic::sweep_claims(RuntimeOrigin::signed(1), root, 0),
+                Error::<Test>::InvalidClaimsWitness
+            );
+            assert_ok!(XXPublic::sweep_claims(RuntimeOrigin::signed(1), root, 1));
+            // Unclaimed funds stay in the pool account and are no longer committed
+            assert_eq!(Balances::free_balance(XXPublic::sale_account_id()), 900);
+            assert_eq!(XXPublic::committed_funds(XXPublic::sale_account_id()), 0);
+            assert_eq!(XXPublic::claims(root), None);
+            assert!(!XXPublic::claimed(root, XXPublic::claim_leaf(&data[1])));
+            assert_eq!(
+                XXPublic::distributions(root),
+                Some(DistributionInfo {
+                    source: XXPublic::sale_account_id(),
+                    recipients: 1,
+                    total: 100,
+                    block: 11,
+                })
+            );
+            assert_eq!(
+                xx_public_events(),
+                vec![
+                    RawEvent::ClaimsCommitted(root, XXPublic::sale_account_id(), 400, 10),
+                    RawEvent::Distributed(root, 11, 100, 0, 0),
+                    RawEvent::ClaimsSwept(root, 300),
+                    RawEvent::DistributionCompleted(root, XXPublic::sale_account_id(), 1, 100),
+                ]
+            );
+            // Swept root can't be committed again
+            assert_noop!(
+                XXPublic::sale_commit_claims(RuntimeOrigin::signed(43), root, 400, 20),
+                Error::<Test>::DuplicateDistribution
+            );
+        })
+}
@@ -15,13 +15,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for xx_public
+//! Weights for `xx_public`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2021-11-12, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("xxnetwork-dev"), DB CACHE: 128
+//! The weights of `set_testnet_manager_account`, `set_sale_manager_account`, `testnet_distribute` and
+//! `sale_distribute` were generated by the benchmark CLI on 2021-11-12. The distribution IDs and the
+//! tracking of committed funds and added vesting schedules added storage accesses to the distribute
+//! calls since. These were counted by hand, and 1.5 µs was added to the generated time for each, to
+//! account for encoding and hashing.
+//!
+//! The claims calls were added without running the benchmarks. Their weights are estimated the same
+//! way, from the generated call closest to them:
+//! - `testnet_commit_claims` and `sale_commit_claims` from the matching `set_*_manager_account`
+//! - `sweep_claims` from `set_sale_manager_account`, with 1.5 µs per swept claim
+//! - `claim` from the time per recipient of `sale_distribute`. Each proof node is charged 1.5 µs, for
+//!   hashing. Each vesting schedule is charged a third of the time per recipient, since the distribute
+//!   benchmarks give every recipient three schedules, which overestimates it.
+//!
+//! All weights should be replaced by the output of the command below once the benchmarks are run.
 
-// Executed Command:
+// Command:
 // target/release/xxnetwork-chain
 // benchmark
 // --chain=xxnetwork-dev
@@ -49,6 +61,10 @@ pub trait WeightInfo {
 	fn set_sale_manager_account() -> Weight;
 	fn testnet_distribute(n: u32, ) -> Weight;
 	fn sale_distribute(n: u32, ) -> Weight;
+	fn testnet_commit_claims() -> Weight;
+	fn sale_commit_claims() -> Weight;
+	fn claim(p: u32, s: u32, ) -> Weight;
+	fn sweep_claims(n: u32, ) -> Weight;
 }
 
 /// Weights for xx_public using the Substrate node and recommended hardware.
@@ -65,30 +81,79 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	// Storage: XXSale TestnetManager (r:1 w:0)
+	// Storage: XXSale Distributions (r:1 w:1)
+	// Storage: XXSale CommittedFunds (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Vesting Vesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
+	// Storage: XXSale AddedVestingSchedules (r:1 w:1)
 	fn testnet_distribute(n: u32, ) -> Weight {
-		Weight::from_ref_time(29_562_000 as u64)
-			// Standard Error: 207_000
-			.saturating_add(Weight::from_ref_time(156_832_000 as u64).saturating_mul(n as u64))
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
-			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+		Weight::from_ref_time(34_062_000 as u64)
+			.saturating_add(Weight::from_ref_time(159_832_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
 	}
 	// Storage: XXSale SaleManager (r:1 w:0)
+	// Storage: XXSale Distributions (r:1 w:1)
+	// Storage: XXSale CommittedFunds (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Vesting Vesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
+	// Storage: XXSale AddedVestingSchedules (r:1 w:1)
 	fn sale_distribute(n: u32, ) -> Weight {
-		Weight::from_ref_time(28_017_000 as u64)
-			// Standard Error: 121_000
-			.saturating_add(Weight::from_ref_time(156_326_000 as u64).saturating_mul(n as u64))
+		Weight::from_ref_time(32_517_000 as u64)
+			.saturating_add(Weight::from_ref_time(159_326_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: XXSale TestnetManager (r:1 w:0)
+	// Storage: XXSale Claims (r:1 w:1)
+	// Storage: XXSale Distributions (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: XXSale CommittedFunds (r:1 w:1)
+	fn testnet_commit_claims() -> Weight {
+		Weight::from_ref_time(32_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: XXSale SaleManager (r:1 w:0)
+	// Storage: XXSale Claims (r:1 w:1)
+	// Storage: XXSale Distributions (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: XXSale CommittedFunds (r:1 w:1)
+	fn sale_commit_claims() -> Weight {
+		Weight::from_ref_time(32_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: XXSale Claims (r:1 w:1)
+	// Storage: XXSale Claimed (r:1 w:1)
+	// Storage: XXSale CommittedFunds (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: XXSale AddedVestingSchedules (r:1 w:1)
+	fn claim(p: u32, s: u32, ) -> Weight {
+		Weight::from_ref_time(171_326_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_500_000 as u64).saturating_mul(p as u64))
+			.saturating_add(Weight::from_ref_time(52_109_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: XXSale Claims (r:1 w:1)
+	// Storage: XXSale CommittedFunds (r:1 w:1)
+	// Storage: XXSale Claimed (r:0 w:1)
+	// Storage: XXSale Distributions (r:0 w:1)
+	fn sweep_claims(n: u32, ) -> Weight {
+		Weight::from_ref_time(29_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_500_000 as u64).saturating_mul(n as u64))
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
-			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
 	}
 }
 
@@ -105,29 +170,78 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
 	// Storage: XXSale TestnetManager (r:1 w:0)
+	// Storage: XXSale Distributions (r:1 w:1)
+	// Storage: XXSale CommittedFunds (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Vesting Vesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
+	// Storage: XXSale AddedVestingSchedules (r:1 w:1)
 	fn testnet_distribute(n: u32, ) -> Weight {
-		Weight::from_ref_time(29_562_000 as u64)
-			// Standard Error: 207_000
-			.saturating_add(Weight::from_ref_time(156_832_000 as u64).saturating_mul(n as u64))
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
-			.saturating_add(RocksDbWeight::get().writes(1 as u64))
-			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+		Weight::from_ref_time(34_062_000 as u64)
+			.saturating_add(Weight::from_ref_time(159_832_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
 	}
 	// Storage: XXSale SaleManager (r:1 w:0)
+	// Storage: XXSale Distributions (r:1 w:1)
+	// Storage: XXSale CommittedFunds (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	// Storage: Vesting Vesting (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
+	// Storage: XXSale AddedVestingSchedules (r:1 w:1)
 	fn sale_distribute(n: u32, ) -> Weight {
-		Weight::from_ref_time(28_017_000 as u64)
-			// Standard Error: 121_000
-			.saturating_add(Weight::from_ref_time(156_326_000 as u64).saturating_mul(n as u64))
+		Weight::from_ref_time(32_517_000 as u64)
+			.saturating_add(Weight::from_ref_time(159_326_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: XXSale TestnetManager (r:1 w:0)
+	// Storage: XXSale Claims (r:1 w:1)
+	// Storage: XXSale Distributions (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: XXSale CommittedFunds (r:1 w:1)
+	fn testnet_commit_claims() -> Weight {
+		Weight::from_ref_time(32_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: XXSale SaleManager (r:1 w:0)
+	// Storage: XXSale Claims (r:1 w:1)
+	// Storage: XXSale Distributions (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: XXSale CommittedFunds (r:1 w:1)
+	fn sale_commit_claims() -> Weight {
+		Weight::from_ref_time(32_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: XXSale Claims (r:1 w:1)
+	// Storage: XXSale Claimed (r:1 w:1)
+	// Storage: XXSale CommittedFunds (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: XXSale AddedVestingSchedules (r:1 w:1)
+	fn claim(p: u32, s: u32, ) -> Weight {
+		Weight::from_ref_time(171_326_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_500_000 as u64).saturating_mul(p as u64))
+			.saturating_add(Weight::from_ref_time(52_109_000 as u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: XXSale Claims (r:1 w:1)
+	// Storage: XXSale CommittedFunds (r:1 w:1)
+	// Storage: XXSale Claimed (r:0 w:1)
+	// Storage: XXSale Distributions (r:0 w:1)
+	fn sweep_claims(n: u32, ) -> Weight {
+		Weight::from_ref_time(29_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_500_000 as u64).saturating_mul(n as u64))
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
-			.saturating_add(RocksDbWeight::get().writes(1 as u64))
-			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
 	}
 }
@@ -6,7 +6,7 @@ use crate::Module as XXCustody;
 
 use frame_benchmarking::{benchmarks, account, impl_benchmark_test_suite};
 use frame_system::RawOrigin;
-use sp_runtime::traits::Bounded;
+use sp_runtime::traits::{Bounded, Zero};
 
 const SEED: u32 = 0;
 
@@ -122,6 +122,37 @@ benchmarks!{
 
 	}: _(RawOrigin::Signed(team.clone()), proxy)
 
+	team_custody_set_proxy_type {
+		let team = team_member::<T>();
+		let proxy = account_from_index::<T>(11);
+		let proxy_type = T::CustodyProxy::get();
+
+		// allow the proxy type for team members
+		<TeamProxyTypes<T>>::insert(&proxy_type, ());
+
+		// allocate some balance to pay the lockup fee (this may not be required later)
+		let balance = <<T as pallet_staking::Config>::Currency as Currency<T::AccountId>>::Balance::max_value();
+		<<T as pallet_staking::Config>::Currency as Currency<T::AccountId>>::make_free_balance_be(&team, balance);
+
+		// run to the end of the custody period
+		frame_system::Pallet::<T>::set_block_number(T::CustodyDuration::get());
+
+	}: _(RawOrigin::Signed(team.clone()), proxy, proxy_type)
+
+	withdraw_rewards {
+		let team = team_member::<T>();
+
+		let info = XXCustody::<T>::team_accounts(team.clone()).unwrap();
+
+		// staking rewards accrue in the custody account
+		let rewards = <<T as pallet_staking::Config>::Currency as Currency<T::AccountId>>::minimum_balance() * 10u32.into();
+		let _ = <<T as pallet_staking::Config>::Currency as Currency<T::AccountId>>::deposit_creating(&info.custody, rewards);
+
+	}: _(RawOrigin::Signed(team.clone()))
+	verify {
+		assert!(XXCustody::<T>::team_accounts(team).unwrap().rewards > Zero::zero());
+	}
+
   //----------------    ADMIN     ----------------//
 
   // these are all simple add/remove operations
@@ -137,6 +168,14 @@ benchmarks!{
  	replace_team_member {
 
  	}: _(RawOrigin::Root, team_member::<T>(), account_from_index::<T>(6))
+
+ 	add_team_proxy_type {
+
+ 	}: _(RawOrigin::Root, T::CustodyProxy::get())
+
+ 	remove_team_proxy_type {
+ 		<TeamProxyTypes<T>>::insert(T::CustodyProxy::get(), ());
+ 	}: _(RawOrigin::Root, T::CustodyProxy::get())
 }
 
 
@@ -2,7 +2,7 @@ use super::{
     Config, BalanceOf, Module, Error, RawEvent,
     TeamAccounts, CustodyAccounts, TotalCustody
 };
-use sp_runtime::{Perbill, RuntimeDebug};
+use sp_runtime::{Perbill, RuntimeDebug, DispatchError};
 use sp_std::prelude::*;
 use sp_std::convert::TryFrom;
 use codec::{Encode, Decode, HasCompact};
@@ -30,6 +30,9 @@ pub struct CustodyInfo<AccountId, Balance: HasCompact> {
     pub custody: AccountId,
     /// Reserve account
     pub reserve: AccountId,
+    /// Staking rewards paid out (increases as rewards are withdrawn)
+    #[codec(compact)]
+    pub rewards: Balance,
 }
 
 impl<AccountId, Balance> CustodyInfo<AccountId, Balance> where
@@ -39,6 +42,16 @@ impl<AccountId, Balance> CustodyInfo<AccountId, Balance> where
     fn increase_vested(&mut self, amount: Balance) {
         self.vested += amount;
     }
+
+    /// Increase paid out rewards amount in self
+    fn increase_rewards(&mut self, amount: Balance) {
+        self.rewards += amount;
+    }
+
+    /// Principal still held under custody (allocation not yet vested)
+    pub fn principal(&self) -> Balance {
+        self.allocation.saturating_sub(self.vested)
+    }
 }
 
 /// Implement Custody sub module functions
@@ -117,6 +130,7 @@ impl<T: Config> Module<T> {
             vested:  Zero::zero(),
             custody: custody_account.clone(),
             reserve: reserve_account,
+            rewards: Zero::zero(),
         };
 
         // 5. Store custody info and custody account
@@ -130,19 +144,28 @@ impl<T: Config> Module<T> {
 
     /// Set the governance proxy of given custody account
     fn set_custody_governance_proxy(custody: &T::AccountId, proxy: T::AccountId) -> DispatchResult {
+        Self::set_custody_proxy(custody, proxy, T::CustodyProxy::get())
+    }
+
+    /// Set the proxy of given custody account, with the given proxy type
+    fn set_custody_proxy(
+        custody: &T::AccountId,
+        proxy: T::AccountId,
+        proxy_type: <T as pallet_proxy::Config>::ProxyType,
+    ) -> DispatchResult {
         // 1. Remove any proxies
         Self::remove_custody_proxies(custody);
         // 2. Set new proxy
         <pallet_proxy::Pallet<T>>::add_proxy_delegate(
             custody,
             proxy,
-            T::CustodyProxy::get(),
+            proxy_type,
             Zero::zero()
         )
     }
 
     /// Remove any proxies of given custody account, refunding team member's deposit if existing
-    /// NOTE: Any proxies are guaranteed to be only of Governance type
+    /// NOTE: Any proxies are guaranteed to be of Governance type, or one of the team proxy types
     fn remove_custody_proxies(custody: &T::AccountId) {
         // Can't call remove_proxies directly, so need to replicate code here
         let (_, old_deposit) = <pallet_proxy::Proxies::<T>>::take(custody);
@@ -180,9 +203,15 @@ impl<T: Config> Module<T> {
             }
             // 3.2. Remove any proxies on the custody account
             Self::remove_custody_proxies(&custody);
-            // 3.3. Payout remaining amount
+            // 3.3. Payout any accrued staking rewards
+            let mut info = info;
+            let rewards = Self::accrued_rewards(&info);
+            if !rewards.is_zero() {
+                Self::do_rewards_payout(&who, &mut info, rewards)?;
+            }
+            // 3.4. Payout remaining amount
             Self::do_payout(who.clone(), Zero::zero(), info, false)?;
-            // 3.4. Emmit custody done event
+            // 3.5. Emmit custody done event
             Self::deposit_event(RawEvent::CustodyDone(who));
             return Ok(())
         }
@@ -215,13 +244,7 @@ impl<T: Config> Module<T> {
         // Use Inspect trait here to get transferable balance of Custody account, and use keep
         // alive to limit transfers down to existential deposit until end of the custody period
         let custody = info.custody.clone();
-        let custody_transferable_balance =
-            <T as Config>::Currency::reducible_balance(&custody, keep_alive.clone());
-        // T::Currency and T::Inspect are both implemented by Balances pallet, so the
-        // balance type is the same. However, explicit conversion is needed here.
-        let custody_balance = <BalanceOf<T>>::try_from(
-                custody_transferable_balance.saturated_into::<u128>()
-            ).ok().unwrap_or(Zero::zero());
+        let custody_balance = Self::transferable_balance(&custody, keep_alive);
 
         // 2. Get reserve balance
         // Reserve account is never used in any Reservable or Lockable Currency operations
@@ -231,21 +254,24 @@ impl<T: Config> Module<T> {
         // In order to prevent this, use the Inspect trait here, and use keep alive to
         // limit transfers down to existential deposit until end of the custody period
         let reserve = info.reserve.clone();
-        let reserve_transferable_balance =
-            <T as Config>::Currency::reducible_balance(&reserve, keep_alive.clone());
-        // T::Currency and T::Inspect are both implemented by Balances pallet, so the
-        // balance type is the same. However, explicit conversion is needed here.
-        let reserve_balance = <BalanceOf<T>>::try_from(
-                reserve_transferable_balance.saturated_into::<u128>()
-            ).ok().unwrap_or(Zero::zero());
+        let reserve_balance = Self::transferable_balance(&reserve, keep_alive);
 
         // 3. Calculate amounts to withdraw from custody and reserve
+        // Before the custody period is done, only principal can be paid out. Any accrued
+        // rewards are left in the accounts for try_withdraw_rewards, which takes them
+        // from custody first, so the same order is used here to set them aside.
         // If custody period is done, transfer full amount from both accounts
-        // in order to not leave any inaccessible funds around
+        // in order to not leave any inaccessible funds around. Rewards have already been
+        // paid out separately at this point.
         let (withdraw_custody, withdraw_reserve) = if keep_alive {
-            let from_custody = amount.min(custody_balance);
+            let rewards = Self::accrued_rewards(&info);
+            let custody_rewards = rewards.min(custody_balance);
+            let custody_principal = custody_balance - custody_rewards;
+            let reserve_principal = reserve_balance.saturating_sub(rewards - custody_rewards);
+            let amount = amount.min(info.principal());
+            let from_custody = amount.min(custody_principal);
             let from_reserve = amount - from_custody;
-            (from_custody, from_reserve.min(reserve_balance))
+            (from_custody, from_reserve.min(reserve_principal))
         } else {
             (custody_balance, reserve_balance)
         };
@@ -325,6 +351,111 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Get the transferable balance of a custody or reserve account
+    fn transferable_balance(account: &T::AccountId, keep_alive: bool) -> BalanceOf<T> {
+        let transferable_balance =
+            <T as Config>::Currency::reducible_balance(account, keep_alive);
+        // T::Currency and T::Inspect are both implemented by Balances pallet, so the
+        // balance type is the same. However, explicit conversion is needed here.
+        <BalanceOf<T>>::try_from(
+            transferable_balance.saturated_into::<u128>()
+        ).ok().unwrap_or(Zero::zero())
+    }
+
+    /// Compute the staking rewards accrued in the custody and reserve accounts that can be withdrawn
+    ///
+    /// Any funds held above the principal still under custody are considered rewards.
+    /// Rewards that were bonded (for example with a `Staked` reward destination) can't be
+    /// transferred, so only the transferable balance of both accounts is paid out as rewards,
+    /// and bonded rewards become available once they are unbonded.
+    pub fn accrued_rewards(info: &CustodyInfo<T::AccountId, BalanceOf<T>>) -> BalanceOf<T> {
+        let custody = <<T as Config>::Currency as Currency<T::AccountId>>::total_balance(&info.custody);
+        let reserve = <<T as Config>::Currency as Currency<T::AccountId>>::total_balance(&info.reserve);
+        let rewards = custody.saturating_add(reserve).saturating_sub(info.principal());
+        let withdrawable = Self::transferable_balance(&info.custody, true)
+            .saturating_add(Self::transferable_balance(&info.reserve, true));
+        rewards.min(withdrawable)
+    }
+
+    /// Get the principal still under custody for the given team member
+    pub fn custody_principal(who: &T::AccountId) -> Option<BalanceOf<T>> {
+        <TeamAccounts<T>>::get(who).map(|info| info.principal())
+    }
+
+    /// Get the staking rewards available for withdrawal by the given team member
+    pub fn custody_rewards(who: &T::AccountId) -> Option<BalanceOf<T>> {
+        <TeamAccounts<T>>::get(who).map(|info| Self::accrued_rewards(&info))
+    }
+
+    /// Attempt to withdraw the staking rewards accrued by the given team member
+    pub fn try_withdraw_rewards(who: T::AccountId) -> DispatchResult {
+        // 1. Get custody info for team member
+        // (can't fail because team member existing is checked before)
+        let mut info = <TeamAccounts<T>>::get(&who).unwrap();
+
+        // 2. Compute accrued rewards
+        let rewards = Self::accrued_rewards(&info);
+        if rewards.is_zero() {
+            Err(Error::<T>::RewardsNotAvailable)?
+        }
+
+        // 3. Do rewards payout
+        let withdraw = Self::do_rewards_payout(&who, &mut info, rewards)?;
+
+        // 4. Error if no payout is possible
+        if withdraw.is_zero() {
+            Err(Error::<T>::PayoutFailedInsufficientFunds)?
+        }
+
+        // 5. Insert updated custody info
+        <TeamAccounts<T>>::insert(&who, info);
+        Ok(())
+    }
+
+    /// Do a rewards payout, returning the amount withdrawn
+    ///
+    /// Rewards are taken from the custody account first, and then from the reserve.
+    /// Both accounts are kept alive, and the principal under custody is not affected,
+    /// so the total amount under custody doesn't change.
+    fn do_rewards_payout(
+        who: &T::AccountId,
+        info: &mut CustodyInfo<T::AccountId, BalanceOf<T>>,
+        amount: BalanceOf<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        // 1. Calculate amounts to withdraw from custody and reserve
+        let custody_balance = Self::transferable_balance(&info.custody, true);
+        let reserve_balance = Self::transferable_balance(&info.reserve, true);
+        let withdraw_custody = amount.min(custody_balance);
+        let withdraw_reserve = (amount - withdraw_custody).min(reserve_balance);
+
+        // 2. Make transfer from custody, if possible
+        if !withdraw_custody.is_zero() {
+            <T as Config>::Currency::transfer(
+                &info.custody,
+                who,
+                withdraw_custody,
+                AllowDeath
+            )?;
+            Self::deposit_event(RawEvent::RewardsFromCustody(who.clone(), withdraw_custody));
+        }
+
+        // 3. Make transfer from reserve, if possible
+        if !withdraw_reserve.is_zero() {
+            <T as Config>::Currency::transfer(
+                &info.reserve,
+                who,
+                withdraw_reserve,
+                AllowDeath
+            )?;
+            Self::deposit_event(RawEvent::RewardsFromReserve(who.clone(), withdraw_reserve));
+        }
+
+        // 4. Update custody info
+        let withdraw = withdraw_custody + withdraw_reserve;
+        info.increase_rewards(withdraw);
+        Ok(withdraw)
+    }
+
     /// Check if the custody period is active, return error if not
     fn check_custody() -> DispatchResult {
         // Get the block number from the FRAME System module.
@@ -427,6 +558,28 @@ impl<T: Config> Module<T> {
         Self::set_custody_governance_proxy(&info.custody, proxy)
     }
 
+    /// Attempt to set a proxy of a team member's own custody account, with the given proxy type
+    pub fn try_team_custody_set_proxy_type(
+        who: T::AccountId,
+        proxy: T::AccountId,
+        proxy_type: <T as pallet_proxy::Config>::ProxyType,
+    ) -> DispatchResult {
+        // 1. Return error if governance custody is not done
+        Self::check_governance_custody(false)?;
+
+        // 2. Return error if proxy type is not allowed for team members
+        if !Self::is_team_proxy_type(&proxy_type) {
+            Err(Error::<T>::ProxyTypeNotAllowed)?
+        }
+
+        // 3. Get team member custody account
+        // (can't fail because team member existing is checked before)
+        let info = <TeamAccounts<T>>::get(&who).unwrap();
+
+        // 4. Set new proxy (removes any previous existing ones)
+        Self::set_custody_proxy(&info.custody, proxy, proxy_type)
+    }
+
     /// Update a team member account
     pub fn update_team_member(who: T::AccountId, new: T::AccountId) {
         // 1. Take info from team accounts
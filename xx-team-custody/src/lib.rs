@@ -1,6 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::traits::{Currency, Get, EnsureOrigin, fungible::Inspect};
+use frame_support::traits::{Contains, Currency, Get, EnsureOrigin, fungible::Inspect};
 use frame_support::{
     decl_event, decl_error, decl_module, decl_storage, dispatch::DispatchResult, ensure,
 };
@@ -45,6 +45,9 @@ pub trait Config: frame_system::Config + pallet_proxy::Config + pallet_staking::
     /// The getter for the proxy type to use for custody accounts
     type CustodyProxy: Get<<Self as pallet_proxy::Config>::ProxyType>;
 
+    /// The proxy types that can move funds out of a custody account, which can't be allowed for team members
+    type TransferProxyTypes: Contains<<Self as pallet_proxy::Config>::ProxyType>;
+
     /// Convert the block number into a balance.
     type BlockNumberToBalance: Convert<Self::BlockNumber, BalanceOf<Self>>;
 
@@ -73,6 +76,10 @@ decl_storage! {
 
         /// Total amount under custody
         pub TotalCustody get(fn total_custody): BalanceOf<T>;
+
+        /// Proxy types team members can choose for their custody account, after governance custody
+        pub TeamProxyTypes get(fn team_proxy_types): map hasher(twox_64_concat)
+            <T as pallet_proxy::Config>::ProxyType => ();
     }
 	add_extra_genesis {
 	    config(team_allocations): Vec<(T::AccountId, BalanceOf<T>)>;
@@ -89,6 +96,7 @@ decl_event! {
     pub enum Event<T> where
         Balance = BalanceOf<T>,
         <T as frame_system::Config>::AccountId,
+        ProxyType = <T as pallet_proxy::Config>::ProxyType,
     {
 
         //----------------   CUSTODY    ----------------//
@@ -99,6 +107,10 @@ decl_event! {
         PayoutFromReserve(AccountId, Balance),
         /// Custody finished for the given team account
         CustodyDone(AccountId),
+        /// Team staking rewards were given from custody
+        RewardsFromCustody(AccountId, Balance),
+        /// Team staking rewards were given from reserve
+        RewardsFromReserve(AccountId, Balance),
 
         //----------------    ADMIN     ----------------//
 
@@ -108,6 +120,10 @@ decl_event! {
         CustodianRemoved(AccountId),
         /// Team member updated
         TeamMemberUpdated(AccountId, AccountId),
+        /// Proxy type allowed for team members
+        TeamProxyTypeAdded(ProxyType),
+        /// Proxy type no longer allowed for team members
+        TeamProxyTypeRemoved(ProxyType),
     }
 }
 
@@ -131,6 +147,12 @@ decl_error! {
         GovernanceCustodyPeriodEnded,
         /// This team member account already exists
         TeamMemberExists,
+        /// No staking rewards accrued in custody
+        RewardsNotAvailable,
+        /// This proxy type is not allowed for team members
+        ProxyTypeNotAllowed,
+        /// This proxy type can move custody funds, so it can't be allowed for team members
+        ProxyTypeCanTransfer,
 	}
 }
 
@@ -238,6 +260,36 @@ decl_module! {
             Self::try_team_custody_set_proxy(who, proxy)?;
         }
 
+        /// Withdraw the staking rewards accrued in the team member's custody and reserve accounts
+        ///
+        /// Any funds held above the principal still under custody are considered rewards.
+        /// These can be withdrawn at any time, independently of the vesting schedule.
+        /// The function is callable by team members only.
+        ///
+        #[weight = <T as Config>::WeightInfo::withdraw_rewards()]
+        pub fn withdraw_rewards(origin) {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_team_member(&who), Error::<T>::InvalidTeamMember);
+            Self::try_withdraw_rewards(who)?;
+        }
+
+        /// Allow the team member to set a proxy of their own custody account, with a chosen proxy type
+        ///
+        /// The proxy type must be one of the admin approved team proxy types.
+        ///
+        /// During the Governance Custody period, the function is not callable.
+        /// After the Governance Custody ends, the function is callable by team members only.
+        ///
+        #[weight = <T as Config>::WeightInfo::team_custody_set_proxy_type()]
+        pub fn team_custody_set_proxy_type(origin,
+            proxy: T::AccountId,
+            proxy_type: <T as pallet_proxy::Config>::ProxyType,
+        ) {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_team_member(&who), Error::<T>::InvalidTeamMember);
+            Self::try_team_custody_set_proxy_type(who, proxy, proxy_type)?;
+        }
+
         //----------------    ADMIN     ----------------//
 
         /// Add a custodian account
@@ -274,6 +326,33 @@ decl_module! {
             Self::update_team_member(who.clone(), new.clone());
             Self::deposit_event(RawEvent::TeamMemberUpdated(who, new));
         }
+
+        /// Allow a proxy type to be chosen by team members for their custody account
+        ///
+        /// Proxy types that can move custody funds can't be allowed.
+        ///
+        /// The dispatch origin must be AdminOrigin.
+        ///
+        #[weight = <T as Config>::WeightInfo::add_team_proxy_type()]
+        pub fn add_team_proxy_type(origin, proxy_type: <T as pallet_proxy::Config>::ProxyType) {
+            Self::ensure_admin(origin)?;
+            ensure!(!T::TransferProxyTypes::contains(&proxy_type), Error::<T>::ProxyTypeCanTransfer);
+            <TeamProxyTypes<T>>::insert(&proxy_type, ());
+            Self::deposit_event(RawEvent::TeamProxyTypeAdded(proxy_type));
+        }
+
+        /// Remove a proxy type from the ones allowed for team members
+        ///
+        /// Existing proxies of this type are not removed.
+        ///
+        /// The dispatch origin must be AdminOrigin.
+        ///
+        #[weight = <T as Config>::WeightInfo::remove_team_proxy_type()]
+        pub fn remove_team_proxy_type(origin, proxy_type: <T as pallet_proxy::Config>::ProxyType) {
+            Self::ensure_admin(origin)?;
+            <TeamProxyTypes<T>>::remove(&proxy_type);
+            Self::deposit_event(RawEvent::TeamProxyTypeRemoved(proxy_type));
+        }
 	}
 }
 
@@ -293,6 +372,11 @@ impl<T: Config> Module<T> {
         <Custodians<T>>::contains_key(who)
     }
 
+    /// Check if given proxy type is allowed for team members
+    fn is_team_proxy_type(proxy_type: &<T as pallet_proxy::Config>::ProxyType) -> bool {
+        <TeamProxyTypes<T>>::contains_key(proxy_type)
+    }
+
     /// Check if origin is admin
     fn ensure_admin(o: T::RuntimeOrigin) -> DispatchResult {
         <T as Config>::AdminOrigin::try_origin(o)
@@ -27,7 +27,7 @@ use frame_election_provider_support::{onchain, SequentialPhragmen};
 use frame_support::{
     parameter_types,
     traits::{
-        Currency, FindAuthor, Imbalance, OnFinalize, OnInitialize, OnUnbalanced,
+        Contains, Currency, FindAuthor, Imbalance, OnFinalize, OnInitialize, OnUnbalanced,
         OneSessionHandler, InstanceFilter, LockIdentifier, EqualPrivilegeOnly, ConstU32, ConstU128
     },
     weights::{Weight, constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND}},
@@ -387,6 +387,15 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			),
         }
     }
+    fn is_superset(&self, o: &Self) -> bool {
+        match (self, o) {
+            (x, y) if x == y => true,
+            (ProxyType::Any, _) => true,
+            (_, ProxyType::Any) => false,
+            (ProxyType::NonTransfer, _) => true,
+            _ => false,
+        }
+    }
 }
 
 impl pallet_proxy::Config for Test {
@@ -455,6 +464,13 @@ parameter_types! {
     pub const CustodyProxy: ProxyType = ProxyType::Voting;
 }
 
+pub struct TransferProxyTypes;
+impl Contains<ProxyType> for TransferProxyTypes {
+    fn contains(proxy_type: &ProxyType) -> bool {
+        matches!(proxy_type, ProxyType::Any | ProxyType::NonTransfer)
+    }
+}
+
 parameter_types! {
 	pub const CandidacyBond: Balance = 100;
 	pub const VotingBondBase: Balance = 1;
@@ -494,6 +510,7 @@ impl xx_team_custody::Config for Test {
     type CustodyDuration = CustodyDuration;
     type GovernanceCustodyDuration = GovernanceCustodyDuration;
     type CustodyProxy = CustodyProxy;
+    type TransferProxyTypes = TransferProxyTypes;
     type BlockNumberToBalance = ConvertInto;
     type AdminOrigin = EnsureRoot<AccountId>;
     type WeightInfo = weights::SubstrateWeight<Self>;
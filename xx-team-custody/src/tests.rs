@@ -632,6 +632,272 @@ fn can_payout_if_custody_account_deducted() {
         });
 }
 
+//////////////////////
+// withdraw_rewards //
+//////////////////////
+
+#[test]
+fn withdraw_rewards_fails_if_caller_is_not_team_member() {
+    let caller = 1;
+    ExtBuilder::default().build_and_execute(|| {
+        assert_noop!(
+            XXCustody::withdraw_rewards(RuntimeOrigin::signed(caller)),
+            Error::<Test>::InvalidTeamMember
+        );
+    });
+}
+
+#[test]
+fn withdraw_rewards_fails_if_no_rewards_accrued() {
+    let payee = 2;
+    let allocation = 1000;
+    ExtBuilder::default()
+        .with_team_allocations(&[(payee, allocation)])
+        .build_and_execute(|| {
+            assert_eq!(XXCustody::custody_rewards(&payee), Some(0));
+            assert_noop!(
+                XXCustody::withdraw_rewards(RuntimeOrigin::signed(payee)),
+                Error::<Test>::RewardsNotAvailable
+            );
+        });
+}
+
+#[test]
+fn withdraw_rewards_pays_out_rewards_accrued_in_custody_account() {
+    let payee = 2;
+    let allocation = 1000;
+    let rewards = 50;
+
+    let reserve_allocation = reserve_ratio() * allocation;
+    let custody_allocation = allocation - reserve_allocation;
+
+    ExtBuilder::default()
+        .with_team_allocations(&[(payee, allocation)])
+        .build_and_execute(|| {
+            let info = XXCustody::team_accounts(payee).unwrap();
+
+            // staking rewards are paid into the custody account
+            Balances::make_free_balance_be(&info.custody, custody_allocation + rewards);
+            assert_eq!(XXCustody::custody_principal(&payee), Some(allocation));
+            assert_eq!(XXCustody::custody_rewards(&payee), Some(rewards));
+
+            // rewards can be withdrawn before any payout is available
+            assert_ok!(XXCustody::withdraw_rewards(RuntimeOrigin::signed(payee)));
+            assert_eq!(Balances::usable_balance(payee), rewards);
+            assert_eq!(Balances::usable_balance(info.custody), custody_allocation);
+
+            // principal is unchanged and rewards are tracked separately
+            let info = XXCustody::team_accounts(payee).unwrap();
+            assert_eq!(info.vested, 0);
+            assert_eq!(info.rewards, rewards);
+            assert_eq!(XXCustody::custody_principal(&payee), Some(allocation));
+            assert_eq!(XXCustody::custody_rewards(&payee), Some(0));
+            assert_eq!(XXCustody::total_custody(), allocation);
+
+            assert_eq!(
+                xx_team_custody_events(),
+                vec![RawEvent::RewardsFromCustody(payee, rewards)]
+            );
+
+            // can't withdraw rewards twice
+            assert_noop!(
+                XXCustody::withdraw_rewards(RuntimeOrigin::signed(payee)),
+                Error::<Test>::RewardsNotAvailable
+            );
+        });
+}
+
+#[test]
+fn withdraw_rewards_pays_out_from_reserve_if_custody_fully_staked() {
+    let custodian = 1;
+    let payee = 2;
+    let allocation = 1000;
+    let rewards = 50;
+
+    let reserve_allocation = reserve_ratio() * allocation;
+    let custody_allocation = allocation - reserve_allocation;
+
+    ExtBuilder::default()
+        .with_team_allocations(&[(payee, allocation)])
+        .with_custodians(&[custodian])
+        .build_and_execute(|| {
+            let info = XXCustody::team_accounts(payee).unwrap();
+
+            // the custodian bonds all custody coins
+            assert_ok!(XXCustody::custody_bond(
+                RuntimeOrigin::signed(custodian),
+                info.custody,
+                custodian,
+                custody_allocation
+            ));
+
+            // staking rewards are paid into the reserve account
+            Balances::make_free_balance_be(&info.reserve, reserve_allocation + rewards);
+            assert_eq!(XXCustody::custody_rewards(&payee), Some(rewards));
+
+            assert_ok!(XXCustody::withdraw_rewards(RuntimeOrigin::signed(payee)));
+            assert_eq!(Balances::usable_balance(payee), rewards);
+            assert_eq!(Balances::usable_balance(info.reserve), reserve_allocation);
+
+            assert_eq!(
+                xx_team_custody_events(),
+                vec![RawEvent::RewardsFromReserve(payee, rewards)]
+            );
+        });
+}
+
+#[test]
+fn withdraw_rewards_only_pays_out_transferable_rewards() {
+    let custodian = 1;
+    let payee = 2;
+    let allocation = 1000;
+
+    let reserve_allocation = reserve_ratio() * allocation;
+    let custody_allocation = allocation - reserve_allocation;
+    // more than can be transferred from the reserve
+    let rewards = reserve_allocation;
+
+    ExtBuilder::default()
+        .with_team_allocations(&[(payee, allocation)])
+        .with_custodians(&[custodian])
+        .build_and_execute(|| {
+            let info = XXCustody::team_accounts(payee).unwrap();
+
+            // staking rewards are paid into the custody account and bonded with the principal
+            Balances::make_free_balance_be(&info.custody, custody_allocation + rewards);
+            assert_ok!(XXCustody::custody_bond(
+                RuntimeOrigin::signed(custodian),
+                info.custody,
+                custodian,
+                custody_allocation + rewards
+            ));
+
+            // only the transferable part of the rewards is reported and paid out
+            let transferable = reserve_allocation - ExistentialDeposit::get();
+            assert_eq!(XXCustody::custody_rewards(&payee), Some(transferable));
+            assert_ok!(XXCustody::withdraw_rewards(RuntimeOrigin::signed(payee)));
+            assert_eq!(Balances::usable_balance(payee), transferable);
+            assert_eq!(XXCustody::custody_rewards(&payee), Some(0));
+        });
+}
+
+#[test]
+fn withdraw_rewards_does_not_affect_vesting_payouts() {
+    let caller = 1;
+    let payee = 2;
+    let allocation = 1000;
+    let rewards = 50;
+
+    let reserve_allocation = reserve_ratio() * allocation;
+    let custody_allocation = allocation - reserve_allocation;
+
+    ExtBuilder::default()
+        .with_team_allocations(&[(payee, allocation)])
+        .build_and_execute(|| {
+            let info = XXCustody::team_accounts(payee).unwrap();
+            Balances::make_free_balance_be(&info.custody, custody_allocation + rewards);
+            assert_ok!(XXCustody::withdraw_rewards(RuntimeOrigin::signed(payee)));
+
+            // payout after one payout frequency behaves the same as normal
+            run_to_block(PayoutFrequency::get());
+            assert_ok!(XXCustody::payout(RuntimeOrigin::signed(caller), payee));
+            let fraction = Perbill::from_rational(PayoutFrequency::get(), CustodyDuration::get());
+            let expected_payout = fraction * allocation;
+            assert_eq!(Balances::usable_balance(payee), rewards + expected_payout);
+            assert_eq!(XXCustody::total_custody(), allocation - expected_payout);
+
+            let info = XXCustody::team_accounts(payee).unwrap();
+            assert_eq!(info.vested, expected_payout);
+            assert_eq!(info.rewards, rewards);
+        });
+}
+
+#[test]
+fn payout_after_custody_period_ended_pays_out_rewards_separately() {
+    let caller = 1;
+    let payee = 2;
+    let allocation = 1000;
+    let rewards = 50;
+
+    let reserve_allocation = reserve_ratio() * allocation;
+    let custody_allocation = allocation - reserve_allocation;
+
+    ExtBuilder::default()
+        .with_team_allocations(&[(payee, allocation)])
+        .build_and_execute(|| {
+            let info = XXCustody::team_accounts(payee).unwrap();
+            Balances::make_free_balance_be(&info.custody, custody_allocation + rewards);
+
+            run_to_block(CustodyDuration::get() + 1);
+            assert_ok!(XXCustody::payout(RuntimeOrigin::signed(caller), payee));
+
+            assert_custody_ended(&[(payee, allocation, rewards, info.custody, info.reserve)]);
+
+            assert_eq!(
+                xx_team_custody_events(),
+                vec![
+                    RawEvent::RewardsFromCustody(payee, rewards),
+                    RawEvent::PayoutFromCustody(payee, custody_allocation),
+                    RawEvent::PayoutFromReserve(payee, reserve_allocation),
+                    RawEvent::CustodyDone(payee),
+                ]
+            );
+        });
+}
+
+#[test]
+fn payout_does_not_pay_out_accrued_rewards_as_principal() {
+    let custodian = 1;
+    let payee = 2;
+    let allocation = 1000;
+    let rewards = 50;
+
+    let reserve_allocation = reserve_ratio() * allocation;
+    let custody_allocation = allocation - reserve_allocation;
+
+    ExtBuilder::default()
+        .with_team_allocations(&[(payee, allocation)])
+        .with_custodians(&[custodian])
+        .build_and_execute(|| {
+            let info = XXCustody::team_accounts(payee).unwrap();
+
+            // the custodian bonds all custody coins
+            assert_ok!(XXCustody::custody_bond(
+                RuntimeOrigin::signed(custodian),
+                info.custody,
+                custodian,
+                custody_allocation
+            ));
+
+            // staking rewards are paid into the custody account, and are its only transferable funds
+            Balances::make_free_balance_be(&info.custody, custody_allocation + rewards);
+            assert_eq!(XXCustody::custody_rewards(&payee), Some(rewards));
+
+            // payout of two payout frequencies is bigger than the reserve
+            run_to_block(2 * PayoutFrequency::get());
+            assert_ok!(XXCustody::payout(RuntimeOrigin::signed(custodian), payee));
+
+            // only the reserve principal is paid out, rewards are left in custody
+            let expected_payout = reserve_allocation - ExistentialDeposit::get();
+            assert_eq!(Balances::usable_balance(payee), expected_payout);
+            assert_eq!(Balances::usable_balance(info.custody), rewards);
+            assert_eq!(
+                xx_team_custody_events(),
+                vec![RawEvent::PayoutFromReserve(payee, expected_payout)]
+            );
+
+            let info = XXCustody::team_accounts(payee).unwrap();
+            assert_eq!(info.vested, expected_payout);
+            assert_eq!(XXCustody::custody_principal(&payee), Some(allocation - expected_payout));
+            assert_eq!(XXCustody::custody_rewards(&payee), Some(rewards));
+
+            // rewards are still available for withdrawal
+            assert_ok!(XXCustody::withdraw_rewards(RuntimeOrigin::signed(payee)));
+            assert_eq!(Balances::usable_balance(payee), expected_payout + rewards);
+            assert_eq!(XXCustody::custody_rewards(&payee), Some(0));
+        });
+}
+
 //////////////////////////////////////////
 // custody_bond and custody_bond_extra  //
 //////////////////////////////////////////
@@ -1302,6 +1568,148 @@ fn team_custody_set_proxy_call_after_governance_period_set_twice() {
         });
 }
 
+//////////////////////////////////
+// team_custody_set_proxy_type  //
+//////////////////////////////////
+
+#[test]
+fn team_custody_set_proxy_type_call_for_non_team_member_fails() {
+    let not_team_member = 1;
+    let proxy = 2;
+
+    ExtBuilder::default().build_and_execute(|| {
+        assert_noop!(
+            XXCustody::team_custody_set_proxy_type(
+                RuntimeOrigin::signed(not_team_member),
+                proxy,
+                ProxyType::Staking,
+            ),
+            Error::<Test>::InvalidTeamMember
+        );
+    });
+}
+
+#[test]
+fn team_custody_set_proxy_type_call_before_governance_period_end_fails() {
+    let team_member = 1;
+    let proxy = 2;
+
+    ExtBuilder::default()
+        .with_team_allocations(&[(team_member, 10)])
+        .build_and_execute(|| {
+            assert_ok!(XXCustody::add_team_proxy_type(admin_origin(), ProxyType::Staking));
+            run_to_block(GovernanceCustodyDuration::get() - 1);
+            assert_noop!(
+                XXCustody::team_custody_set_proxy_type(
+                    RuntimeOrigin::signed(team_member),
+                    proxy,
+                    ProxyType::Staking,
+                ),
+                Error::<Test>::GovernanceCustodyActive
+            );
+        });
+}
+
+#[test]
+fn team_custody_set_proxy_type_fails_if_proxy_type_not_allowed() {
+    let team_member = 1;
+    let proxy = 2;
+
+    ExtBuilder::default()
+        .with_team_allocations(&[(team_member, 10)])
+        .build_and_execute(|| {
+            assert_ok!(XXCustody::add_team_proxy_type(admin_origin(), ProxyType::Voting));
+            run_to_block(GovernanceCustodyDuration::get() + 1);
+            assert_noop!(
+                XXCustody::team_custody_set_proxy_type(
+                    RuntimeOrigin::signed(team_member),
+                    proxy,
+                    ProxyType::Staking,
+                ),
+                Error::<Test>::ProxyTypeNotAllowed
+            );
+            // A proxy type that is a superset of an allowed one is not allowed either
+            assert_noop!(
+                XXCustody::team_custody_set_proxy_type(
+                    RuntimeOrigin::signed(team_member),
+                    proxy,
+                    ProxyType::Any,
+                ),
+                Error::<Test>::ProxyTypeNotAllowed
+            );
+        });
+}
+
+#[test]
+fn team_custody_set_proxy_type_call_after_governance_period() {
+    let team_member = 1;
+    let proxy = 2;
+
+    ExtBuilder::default()
+        .with_team_allocations(&[(team_member, 10)])
+        .with_initial_balances(&[(team_member, 5)])
+        .build_and_execute(|| {
+            let info = XXCustody::team_accounts(team_member).unwrap();
+            assert_ok!(XXCustody::add_team_proxy_type(admin_origin(), ProxyType::Staking));
+
+            run_to_block(GovernanceCustodyDuration::get() + 1);
+
+            assert_ok!(XXCustody::team_custody_set_proxy_type(
+                RuntimeOrigin::signed(team_member),
+                proxy,
+                ProxyType::Staking,
+            ));
+
+            // correct setting of proxy account with chosen type
+            assert_proxy_type(info.custody, proxy, ProxyType::Staking);
+        });
+}
+
+#[test]
+fn team_custody_set_proxy_type_replaces_previous_proxy() {
+    let team_member = 1;
+    let proxy = 2;
+
+    ExtBuilder::default()
+        .with_team_allocations(&[(team_member, 10)])
+        .with_initial_balances(&[(team_member, 5)])
+        .build_and_execute(|| {
+            let info = XXCustody::team_accounts(team_member).unwrap();
+            assert_ok!(XXCustody::add_team_proxy_type(admin_origin(), ProxyType::Governance));
+            assert_ok!(XXCustody::add_team_proxy_type(admin_origin(), ProxyType::Voting));
+
+            run_to_block(GovernanceCustodyDuration::get() + 1);
+
+            assert_ok!(XXCustody::team_custody_set_proxy_type(
+                RuntimeOrigin::signed(team_member),
+                proxy,
+                ProxyType::Governance,
+            ));
+            assert_proxy_type(info.custody, proxy, ProxyType::Governance);
+
+            // setting a new proxy replaces the previous one
+            assert_ok!(XXCustody::team_custody_set_proxy_type(
+                RuntimeOrigin::signed(team_member),
+                proxy,
+                ProxyType::Voting,
+            ));
+            assert_proxy_type(info.custody, proxy, ProxyType::Voting);
+        });
+}
+
+#[test]
+fn admin_cannot_allow_proxy_types_that_can_transfer() {
+    ExtBuilder::default().build_and_execute(|| {
+        for proxy_type in [ProxyType::Any, ProxyType::NonTransfer] {
+            assert_noop!(
+                XXCustody::add_team_proxy_type(admin_origin(), proxy_type),
+                Error::<Test>::ProxyTypeCanTransfer
+            );
+            assert!(!<TeamProxyTypes<Test>>::contains_key(proxy_type));
+        }
+    });
+}
+
 //////////////////////
 // admin functions  //
 //////////////////////
@@ -1331,6 +1739,36 @@ fn admin_add_remove_custodian() {
     });
 }
 
+// add/remove team proxy type
+
+#[test]
+fn admin_add_remove_team_proxy_type() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_ok!(XXCustody::add_team_proxy_type(admin_origin(), ProxyType::Staking));
+        assert!(<TeamProxyTypes<Test>>::contains_key(ProxyType::Staking),);
+        assert_ok!(XXCustody::remove_team_proxy_type(admin_origin(), ProxyType::Staking));
+        assert!(!<TeamProxyTypes<Test>>::contains_key(ProxyType::Staking),);
+
+        assert_eq!(
+            xx_team_custody_events(),
+            vec![
+                RawEvent::TeamProxyTypeAdded(ProxyType::Staking),
+                RawEvent::TeamProxyTypeRemoved(ProxyType::Staking),
+            ]
+        );
+    });
+}
+
+#[test]
+fn add_team_proxy_type_requires_admin_origin() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_noop!(
+            XXCustody::add_team_proxy_type(RuntimeOrigin::signed(1), ProxyType::Staking),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
 // replace team member
 
 #[test]
@@ -1392,12 +1830,16 @@ fn replace_team_member() {
 // helpers
 
 fn assert_proxy(account: AccountId, delegate: AccountId) {
+    assert_proxy_type(account, delegate, ProxyType::Voting);
+}
+
+fn assert_proxy_type(account: AccountId, delegate: AccountId, proxy_type: ProxyType) {
     assert_eq!(
         Proxy::proxies(account),
         (
             vec![ProxyDefinition {
                 delegate: delegate,
-                proxy_type: ProxyType::Voting,
+                proxy_type: proxy_type,
                 delay: 0
             }]
             .try_into()
@@ -15,13 +15,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for xx_team_custody
+//! Weights for `xx_team_custody`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2021-09-13, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("xxnetwork-dev"), DB CACHE: 128
+//! The weights of all calls but `withdraw_rewards`, `team_custody_set_proxy_type`,
+//! `add_team_proxy_type` and `remove_team_proxy_type` were generated by the benchmark CLI on 2021-09-13.
+//!
+//! These four calls were added without running the benchmarks. Their weights are estimated from the
+//! generated call closest to them, adding 1.5 µs for each storage access it doesn't make, to account
+//! for encoding and hashing, and removing as much for each one it makes but they don't:
+//! - `withdraw_rewards` from `payout`, which also updates the total custody
+//! - `team_custody_set_proxy_type` from `team_custody_set_proxy`, which doesn't check the proxy type
+//! - `add_team_proxy_type` and `remove_team_proxy_type` from `add_custodian` and `remove_custodian`
+//!
+//! All weights should be replaced by the output of the command below once the benchmarks are run.
 
-// Executed Command:
+// Command:
 // target/release/xxnetwork-chain
 // benchmark
 // --chain=xxnetwork-dev
@@ -51,9 +59,13 @@ pub trait WeightInfo {
 	fn custody_set_controller() -> Weight;
 	fn custody_set_proxy() -> Weight;
 	fn team_custody_set_proxy() -> Weight;
+	fn withdraw_rewards() -> Weight;
+	fn team_custody_set_proxy_type() -> Weight;
 	fn add_custodian() -> Weight;
 	fn remove_custodian() -> Weight;
 	fn replace_team_member() -> Weight;
+	fn add_team_proxy_type() -> Weight;
+	fn remove_team_proxy_type() -> Weight;
 }
 
 /// Weights for xx_team_custody using the Substrate node and recommended hardware.
@@ -117,6 +129,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
+	// Storage: XXCustody TeamAccounts (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	fn withdraw_rewards() -> Weight {
+		Weight::from_ref_time(124_699_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: XXCustody TeamProxyTypes (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: XXCustody TeamAccounts (r:1 w:1)
+	fn team_custody_set_proxy_type() -> Weight {
+		Weight::from_ref_time(90_882_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
 	// Storage: XXCustody Custodians (r:0 w:1)
 	fn add_custodian() -> Weight {
 		Weight::from_ref_time(20_959_000 as u64)
@@ -133,6 +161,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
+	// Storage: XXCustody TeamProxyTypes (r:0 w:1)
+	fn add_team_proxy_type() -> Weight {
+		Weight::from_ref_time(20_959_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: XXCustody TeamProxyTypes (r:0 w:1)
+	fn remove_team_proxy_type() -> Weight {
+		Weight::from_ref_time(21_083_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -195,6 +233,22 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4 as u64))
 			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
+	// Storage: XXCustody TeamAccounts (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	fn withdraw_rewards() -> Weight {
+		Weight::from_ref_time(124_699_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: XXCustody TeamProxyTypes (r:1 w:0)
+	// Storage: Proxy Proxies (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: XXCustody TeamAccounts (r:1 w:1)
+	fn team_custody_set_proxy_type() -> Weight {
+		Weight::from_ref_time(90_882_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
 	// Storage: XXCustody Custodians (r:0 w:1)
 	fn add_custodian() -> Weight {
 		Weight::from_ref_time(20_959_000 as u64)
@@ -211,4 +265,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
+	// Storage: XXCustody TeamProxyTypes (r:0 w:1)
+	fn add_team_proxy_type() -> Weight {
+		Weight::from_ref_time(20_959_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: XXCustody TeamProxyTypes (r:0 w:1)
+	fn remove_team_proxy_type() -> Weight {
+		Weight::from_ref_time(21_083_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }